@@ -301,9 +301,12 @@ impl Manifest {
                 DEBUG.to_string(),
                 BuildConfig {
                     print_ir: false,
+                    print_typed_ast: false,
                     print_finalized_asm: false,
                     print_intermediate_asm: false,
                     silent: false,
+                    error_format: Default::default(),
+                    deny_warnings: false,
                 },
             );
         }
@@ -312,9 +315,12 @@ impl Manifest {
                 RELEASE.to_string(),
                 BuildConfig {
                     print_ir: false,
+                    print_typed_ast: false,
                     print_finalized_asm: false,
                     print_intermediate_asm: false,
                     silent: false,
+                    error_format: Default::default(),
+                    deny_warnings: false,
                 },
             );
         }
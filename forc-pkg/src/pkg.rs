@@ -5,7 +5,7 @@ use crate::{
 use anyhow::{anyhow, bail, Context, Error, Result};
 use forc_util::{
     find_file_name, git_checkouts_directory, kebab_to_snake_case, print_on_failure,
-    print_on_success, print_on_success_library, println_yellow_err,
+    print_on_success, print_on_success_library, println_yellow_err, ErrorFormat,
 };
 use fuels_types::JsonABI;
 use petgraph::{
@@ -170,9 +170,18 @@ pub struct BuildPlan {
 #[serde(rename_all = "kebab-case")]
 pub struct BuildConfig {
     pub print_ir: bool,
+    /// Print the type checked program with the inferred type of every expression.
+    #[serde(default)]
+    pub print_typed_ast: bool,
     pub print_finalized_asm: bool,
     pub print_intermediate_asm: bool,
     pub silent: bool,
+    /// The format in which errors and warnings are reported.
+    #[serde(default)]
+    pub error_format: ErrorFormat,
+    /// Fail the build if any warnings are produced.
+    #[serde(default)]
+    pub deny_warnings: bool,
 }
 
 /// Error returned upon failed parsing of `PinnedId::from_str`.
@@ -1276,7 +1285,9 @@ pub fn sway_build_config(
     )
     .print_finalized_asm(build_conf.print_finalized_asm)
     .print_intermediate_asm(build_conf.print_intermediate_asm)
-    .print_ir(build_conf.print_ir);
+    .print_ir(build_conf.print_ir)
+    .print_typed_ast(build_conf.print_typed_ast)
+    .deny_warnings(build_conf.deny_warnings);
     Ok(build_config)
 }
 
@@ -1344,12 +1355,13 @@ pub fn compile(
     let source = manifest.entry_string()?;
     let sway_build_config = sway_build_config(manifest.dir(), &entry_path, build_config)?;
     let silent_mode = build_config.silent;
+    let error_format = build_config.error_format;
 
     // First, compile to an AST. We'll update the namespace and check for JSON ABI output.
     let ast_res = sway_core::compile_to_ast(source, namespace, Some(&sway_build_config));
     match &ast_res {
         CompileAstResult::Failure { warnings, errors } => {
-            print_on_failure(silent_mode, warnings, errors);
+            print_on_failure(silent_mode, error_format, warnings, errors);
             bail!("Failed to compile {}", pkg.name);
         }
         CompileAstResult::Success {
@@ -1362,7 +1374,7 @@ pub fn compile(
                 // If we're compiling a library, we don't need to compile any further.
                 // Instead, we update the namespace with the library's top-level module.
                 TreeType::Library { .. } => {
-                    print_on_success_library(silent_mode, &pkg.name, warnings);
+                    print_on_success_library(silent_mode, error_format, &pkg.name, warnings);
                     let bytecode = vec![];
                     let lib_namespace = typed_program.root.namespace.clone();
                     let compiled = Compiled { json_abi, bytecode };
@@ -1375,7 +1387,13 @@ pub fn compile(
                     let bc_res = sway_core::asm_to_bytecode(asm_res, source_map);
                     match bc_res {
                         BytecodeCompilationResult::Success { bytes, warnings } => {
-                            print_on_success(silent_mode, &pkg.name, &warnings, &tree_type);
+                            print_on_success(
+                                silent_mode,
+                                error_format,
+                                &pkg.name,
+                                &warnings,
+                                &tree_type,
+                            );
                             let bytecode = bytes;
                             let compiled = Compiled { json_abi, bytecode };
                             Ok((compiled, None))
@@ -1384,7 +1402,7 @@ pub fn compile(
                             unreachable!("compilation of library program types is handled above")
                         }
                         BytecodeCompilationResult::Failure { errors, warnings } => {
-                            print_on_failure(silent_mode, &warnings, &errors);
+                            print_on_failure(silent_mode, error_format, &warnings, &errors);
                             bail!("Failed to compile {}", pkg.name);
                         }
                     }
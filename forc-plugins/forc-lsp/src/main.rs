@@ -2,7 +2,12 @@
 //!
 //! Once installed and available via `PATH`, can be executed via `forc lsp`.
 
+// Starting the server proves its session is `Sync`, which walks the typed namespace each document
+// keeps around.
+#![recursion_limit = "256"]
+
 use clap::Parser;
+use std::path::PathBuf;
 
 #[derive(Debug, Parser)]
 #[clap(
@@ -15,6 +20,19 @@ pub struct App {
     /// to parse.
     #[clap(long)]
     pub parsed_tokens_as_warnings: bool,
+    /// Stops waiting on any compilation which takes longer than the given number of milliseconds,
+    /// reporting an informational diagnostic instead. The compilation can't be interrupted, so it
+    /// finishes in the background and its stale result is discarded.
+    #[clap(long)]
+    pub compile_timeout_ms: Option<u64>,
+    /// Includes the path of each file in the progress reported to the client while compiling the
+    /// workspace.
+    #[clap(long)]
+    pub verbose_progress: bool,
+    /// Writes the tokens collected from each document to the given path as newline-delimited JSON
+    /// every time it's compiled. Useful for attaching to bug reports about highlighting.
+    #[clap(long)]
+    pub dump_tokens: Option<PathBuf>,
 }
 
 #[tokio::main]
@@ -22,6 +40,9 @@ async fn main() {
     let app = App::parse();
     let dbg = sway_lsp::utils::debug::DebugFlags {
         parsed_tokens_as_warnings: app.parsed_tokens_as_warnings,
+        compile_timeout_ms: app.compile_timeout_ms,
+        verbose_progress: app.verbose_progress,
+        dump_tokens: app.dump_tokens,
     };
     sway_lsp::start(dbg).await
 }
@@ -4,13 +4,17 @@ use annotate_snippets::{
     display_list::{DisplayList, FormatOptions},
     snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation},
 };
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
 use std::env;
 use std::ffi::OsStr;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::str;
-use sway_core::{error::LineCol, CompileError, CompileWarning, TreeType};
+use std::str::{self, FromStr};
+use sway_core::{
+    error::{Diagnostic, LineCol},
+    CompileError, CompileWarning, TreeType,
+};
 use sway_types::Spanned;
 use sway_utils::constants;
 use termcolor::{self, Color as TermColor, ColorChoice, ColorSpec, StandardStream, WriteColor};
@@ -20,6 +24,36 @@ pub mod restricted;
 
 pub const DEFAULT_OUTPUT_DIRECTORY: &str = "out";
 
+/// The format in which compiler errors and warnings are reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorFormat {
+    /// Annotated source snippets, intended to be read by a person.
+    Human,
+    /// One JSON object per line, intended to be parsed by other tools.
+    Json,
+}
+
+impl Default for ErrorFormat {
+    fn default() -> Self {
+        ErrorFormat::Human
+    }
+}
+
+impl FromStr for ErrorFormat {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "human" => Ok(ErrorFormat::Human),
+            "json" => Ok(ErrorFormat::Json),
+            _ => Err(anyhow!(
+                "unknown error format `{}`, expected `human` or `json`",
+                s
+            )),
+        }
+    }
+}
+
 /// Continually go up in the file tree until a specified file is found.
 #[allow(clippy::branches_sharing_code)]
 pub fn find_parent_dir_with_file(starter_path: &Path, file_name: &str) -> Option<PathBuf> {
@@ -132,6 +166,7 @@ pub fn git_checkouts_directory() -> PathBuf {
 
 pub fn print_on_success(
     silent_mode: bool,
+    error_format: ErrorFormat,
     proj_name: &str,
     warnings: &[CompileWarning],
     tree_type: &TreeType,
@@ -144,7 +179,7 @@ pub fn print_on_success(
     };
 
     if !silent_mode {
-        warnings.iter().for_each(format_warning);
+        print_diagnostics(error_format, warnings, &[]);
     }
 
     if warnings.is_empty() {
@@ -164,9 +199,14 @@ pub fn print_on_success(
     }
 }
 
-pub fn print_on_success_library(silent_mode: bool, proj_name: &str, warnings: &[CompileWarning]) {
+pub fn print_on_success_library(
+    silent_mode: bool,
+    error_format: ErrorFormat,
+    proj_name: &str,
+    warnings: &[CompileWarning],
+) {
     if !silent_mode {
-        warnings.iter().for_each(format_warning);
+        print_diagnostics(error_format, warnings, &[]);
     }
 
     if warnings.is_empty() {
@@ -185,12 +225,31 @@ pub fn print_on_success_library(silent_mode: bool, proj_name: &str, warnings: &[
     }
 }
 
-pub fn print_on_failure(silent_mode: bool, warnings: &[CompileWarning], errors: &[CompileError]) {
+pub fn print_on_failure(
+    silent_mode: bool,
+    error_format: ErrorFormat,
+    warnings: &[CompileWarning],
+    errors: &[CompileError],
+) {
     let e_len = errors.len();
 
     if !silent_mode {
-        warnings.iter().for_each(format_warning);
-        errors.iter().for_each(format_err);
+        print_diagnostics(error_format, warnings, errors);
+    }
+
+    // Every line of JSON output must be a diagnostic, so there's no summary to print.
+    if error_format == ErrorFormat::Json {
+        return;
+    }
+
+    if e_len == 0 && !warnings.is_empty() {
+        let w_len = warnings.len();
+        println_red_err(&format!(
+            "  Aborting due to {} denied {}.",
+            w_len,
+            if w_len > 1 { "warnings" } else { "warning" }
+        ));
+        return;
     }
 
     println_red_err(&format!(
@@ -262,6 +321,31 @@ fn println_with_color(txt: &str, color: TermColor, stream: StandardStream) {
     stream.reset().expect("internal printing error");
 }
 
+fn print_diagnostics(
+    error_format: ErrorFormat,
+    warnings: &[CompileWarning],
+    errors: &[CompileError],
+) {
+    match error_format {
+        ErrorFormat::Human => {
+            warnings.iter().for_each(format_warning);
+            errors.iter().for_each(format_err);
+        }
+        ErrorFormat::Json => {
+            let warnings = warnings.iter().map(Diagnostic::from);
+            let errors = errors.iter().map(Diagnostic::from);
+            for diagnostic in warnings.chain(errors) {
+                eprintln!("{}", diagnostic_to_json(&diagnostic));
+            }
+        }
+    }
+}
+
+/// Serialize a single diagnostic as a one-line JSON object.
+pub fn diagnostic_to_json(diagnostic: &Diagnostic) -> String {
+    serde_json::to_string(diagnostic).expect("diagnostics are always serializable")
+}
+
 fn format_err(err: &sway_core::CompileError) {
     let span = err.span();
     let input = span.input();
@@ -447,3 +531,30 @@ fn maybe_uwuify(raw: &str) -> String {
 fn maybe_uwuify(raw: &str) -> String {
     raw.to_string()
 }
+
+#[test]
+fn test_diagnostic_to_json() {
+    use std::sync::Arc;
+    use sway_types::{Ident, Span};
+
+    let src: Arc<str> = Arc::from("script;\n\nfn main() {\n    foo\n}\n");
+    let path = Arc::new(PathBuf::from("src/main.sw"));
+    let start = src.find("foo").unwrap();
+    let span = Span::new(src, start, start + 3, Some(path)).unwrap();
+    let error = CompileError::UnknownVariable {
+        var_name: Ident::new(span),
+    };
+    let json: serde_json::Value =
+        serde_json::from_str(&diagnostic_to_json(&Diagnostic::from(&error))).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "severity": "error",
+            "code": "UnknownVariable",
+            "message": "Variable \"foo\" does not exist in this scope.",
+            "path": "src/main.sw",
+            "start": { "line": 4, "col": 5 },
+            "end": { "line": 4, "col": 8 },
+        })
+    );
+}
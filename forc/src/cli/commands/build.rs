@@ -1,6 +1,8 @@
 use crate::ops::forc_build;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::Parser;
+use forc_util::ErrorFormat;
+use std::str::FromStr;
 
 /// Compile the current or target project.
 ///
@@ -22,6 +24,12 @@ pub struct Command {
     /// Whether to compile to bytecode (false) or to print out the generated IR (true).
     #[clap(long)]
     pub print_ir: bool,
+    /// Print an additional compiler representation of the program: `typed-ast`.
+    ///
+    /// `typed-ast` prints the type checked program as an indented tree, annotating each
+    /// expression with its inferred type.
+    #[clap(long)]
+    pub emit: Option<Emit>,
     /// If set, outputs a binary file representing the script bytes.
     #[clap(short = 'o')]
     pub binary_outfile: Option<String>,
@@ -35,6 +43,14 @@ pub struct Command {
     /// Silent mode. Don't output any warnings or errors to the command line.
     #[clap(long = "silent", short = 's')]
     pub silent_mode: bool,
+    /// The format in which errors and warnings are reported: `human` or `json`.
+    ///
+    /// With `json`, each diagnostic is written to stderr as a single-line JSON object.
+    #[clap(long, default_value = "human")]
+    pub error_format: ErrorFormat,
+    /// Treat warnings as errors, failing the build if any warnings are produced.
+    #[clap(long)]
+    pub deny_warnings: bool,
     /// The directory in which the sway compiler output artifacts are placed.
     ///
     /// By default, this is `<project-root>/out`.
@@ -59,6 +75,23 @@ pub struct Command {
     pub release: bool,
 }
 
+/// An additional compiler representation that may be printed during the build.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Emit {
+    /// The type checked program, with the inferred type of every expression.
+    TypedAst,
+}
+
+impl FromStr for Emit {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "typed-ast" => Ok(Emit::TypedAst),
+            _ => Err(anyhow!("unknown emit kind `{}`, expected `typed-ast`", s)),
+        }
+    }
+}
+
 pub(crate) fn exec(command: Command) -> Result<()> {
     forc_build::build(command)?;
     Ok(())
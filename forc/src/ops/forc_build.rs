@@ -1,7 +1,10 @@
-use crate::{cli::BuildCommand, utils::SWAY_GIT_TAG};
+use crate::{
+    cli::{BuildCommand, Emit},
+    utils::SWAY_GIT_TAG,
+};
 use anyhow::{anyhow, bail, Result};
 use forc_pkg::{self as pkg, lock, Lock, ManifestFile};
-use forc_util::{default_output_directory, lock_path};
+use forc_util::{default_output_directory, lock_path, ErrorFormat};
 use std::{
     fs::{self, File},
     path::{Path, PathBuf},
@@ -16,8 +19,11 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
         print_finalized_asm,
         print_intermediate_asm,
         print_ir,
+        emit,
         offline_mode: offline,
         silent_mode,
+        error_format,
+        deny_warnings,
         output_directory,
         minify_json_abi,
         locked,
@@ -52,15 +58,26 @@ pub fn build(command: BuildCommand) -> Result<pkg::Compiled> {
     let manifest = ManifestFile::from_dir(&this_dir, SWAY_GIT_TAG)?;
 
     // If any cli parameter is passed by the user it overrides the selected build profile.
+    let print_typed_ast = emit == Some(Emit::TypedAst);
     let mut config = &pkg::BuildConfig {
         print_ir,
+        print_typed_ast,
         print_finalized_asm,
         print_intermediate_asm,
         silent: silent_mode,
+        error_format,
+        deny_warnings,
     };
 
     // Check if any cli parameter is passed by the user if not fetch the build profile from manifest.
-    if !print_ir && !print_intermediate_asm && !print_finalized_asm && !silent_mode {
+    if !print_ir
+        && !print_typed_ast
+        && !print_intermediate_asm
+        && !print_finalized_asm
+        && !silent_mode
+        && error_format == ErrorFormat::Human
+        && !deny_warnings
+    {
         config = manifest
             .build_profile
             .as_ref()
@@ -44,10 +44,13 @@ pub async fn deploy(command: DeployCommand) -> Result<fuel_tx::ContractId> {
         print_finalized_asm,
         print_intermediate_asm,
         print_ir,
+        emit: None,
         binary_outfile,
         offline_mode,
         debug_outfile,
         silent_mode,
+        error_format: Default::default(),
+        deny_warnings: false,
         output_directory,
         minify_json_abi,
         locked,
@@ -30,10 +30,13 @@ pub async fn run(command: RunCommand) -> Result<Vec<fuel_tx::Receipt>> {
         print_finalized_asm: command.print_finalized_asm,
         print_intermediate_asm: command.print_intermediate_asm,
         print_ir: command.print_ir,
+        emit: None,
         binary_outfile: command.binary_outfile,
         debug_outfile: command.debug_outfile,
         offline_mode: false,
         silent_mode: command.silent_mode,
+        error_format: Default::default(),
+        deny_warnings: false,
         output_directory: command.output_directory,
         minify_json_abi: command.minify_json_abi,
         locked: command.locked,
@@ -2463,6 +2463,8 @@ mod tests {
                 print_intermediate_asm: false,
                 print_finalized_asm: false,
                 print_ir: true,
+                print_typed_ast: false,
+                deny_warnings: false,
             },
         );
 
@@ -9,6 +9,9 @@ pub struct BuildConfig {
     pub(crate) print_intermediate_asm: bool,
     pub(crate) print_finalized_asm: bool,
     pub(crate) print_ir: bool,
+    pub(crate) print_typed_ast: bool,
+    /// Whether compilation should fail if any warnings are produced.
+    pub(crate) deny_warnings: bool,
 }
 
 impl BuildConfig {
@@ -46,6 +49,8 @@ impl BuildConfig {
             print_intermediate_asm: false,
             print_finalized_asm: false,
             print_ir: false,
+            print_typed_ast: false,
+            deny_warnings: false,
         }
     }
 
@@ -70,6 +75,20 @@ impl BuildConfig {
         }
     }
 
+    pub fn print_typed_ast(self, a: bool) -> Self {
+        Self {
+            print_typed_ast: a,
+            ..self
+        }
+    }
+
+    pub fn deny_warnings(self, a: bool) -> Self {
+        Self {
+            deny_warnings: a,
+            ..self
+        }
+    }
+
     pub fn canonical_root_module(&self) -> Arc<PathBuf> {
         self.canonical_root_module.clone()
     }
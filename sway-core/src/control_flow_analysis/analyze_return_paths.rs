@@ -3,6 +3,7 @@
 
 use crate::{
     control_flow_analysis::*, error::*, parse_tree::*, semantic_analysis::*, type_engine::*,
+    types::DeterministicallyAborts,
 };
 use petgraph::prelude::NodeIndex;
 use sway_types::{ident::Ident, span::Span};
@@ -126,7 +127,9 @@ fn connect_node(
     let span = node.span.clone();
     match &node.content {
         TypedAstNodeContent::ReturnStatement(_)
-        | TypedAstNodeContent::ImplicitReturnExpression(_) => {
+        | TypedAstNodeContent::ImplicitReturnExpression(_)
+        | TypedAstNodeContent::Break(_)
+        | TypedAstNodeContent::Continue(_) => {
             let this_index = graph.add_node(node.into());
             for leaf_ix in leaves {
                 graph.add_edge(*leaf_ix, this_index, "".into());
@@ -137,20 +140,31 @@ fn connect_node(
             // An abridged version of the dead code analysis for a while loop
             // since we don't really care about what the loop body contains when detecting
             // divergent paths
+            let aborts = node.deterministically_aborts();
             let node = graph.add_node(node.into());
             for leaf in leaves {
                 graph.add_edge(*leaf, node, "while loop entry".into());
             }
-            NodeConnection::NextStep(vec![node])
+            // A loop which never exits, e.g. `while true {}`, needn't return a value.
+            if aborts {
+                NodeConnection::Return(node)
+            } else {
+                NodeConnection::NextStep(vec![node])
+            }
         }
         TypedAstNodeContent::Expression(TypedExpression { .. }) => {
+            let aborts = node.deterministically_aborts();
             let entry = graph.add_node(node.into());
             // insert organizational dominator node
             // connected to all current leaves
             for leaf in leaves {
                 graph.add_edge(*leaf, entry, "".into());
             }
-            NodeConnection::NextStep(vec![entry])
+            if aborts {
+                NodeConnection::Return(entry)
+            } else {
+                NodeConnection::NextStep(vec![entry])
+            }
         }
         TypedAstNodeContent::SideEffect => NodeConnection::NextStep(leaves.to_vec()),
         TypedAstNodeContent::Declaration(decl) => {
@@ -305,3 +319,45 @@ fn depth_first_insertion_code_block(
     }
     return_nodes
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_warnings;
+
+    #[test]
+    fn test_function_that_loops_forever_warns_about_return_type() {
+        let src = r#"
+        script;
+
+        fn spin() -> u64 {
+            while true {}
+        }
+
+        fn main() {}
+        "#;
+        let warnings = compile_warnings(src);
+        assert!(warnings.iter().any(|warning| matches!(
+            warning.warning_content,
+            Warning::FunctionNeverReturns { .. }
+        ) && warning.span.as_str() == "u64"));
+    }
+
+    #[test]
+    fn test_function_ending_in_return_does_not_warn_about_return_type() {
+        let src = r#"
+        script;
+
+        fn five() -> u64 {
+            return 5;
+        }
+
+        fn main() {}
+        "#;
+        let warnings = compile_warnings(src);
+        assert!(!warnings.iter().any(|warning| matches!(
+            warning.warning_content,
+            Warning::FunctionNeverReturns { .. }
+        )));
+    }
+}
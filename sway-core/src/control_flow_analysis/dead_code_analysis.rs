@@ -191,6 +191,27 @@ impl ControlFlowGraph {
                 })
                 .collect(),
         };
+
+        // test functions are run directly, so they are entry points in every tree type
+        let test_functions: Vec<_> = graph
+            .graph
+            .node_indices()
+            .filter(|i| {
+                matches!(
+                    graph.graph[*i],
+                    ControlFlowGraphNode::ProgramNode(TypedAstNode {
+                        content: TypedAstNodeContent::Declaration(
+                            TypedDeclaration::FunctionDeclaration(TypedFunctionDeclaration {
+                                is_test: true,
+                                ..
+                            })
+                        ),
+                        ..
+                    })
+                ) && !graph.entry_points.contains(i)
+            })
+            .collect();
+        graph.entry_points.extend(test_functions);
         Ok(())
     }
 }
@@ -256,6 +277,14 @@ fn connect_node(
             }
             (return_contents, None)
         }
+        TypedAstNodeContent::Break(_) | TypedAstNodeContent::Continue(_) => {
+            let this_index = graph.add_node(node.into());
+            for leaf_ix in leaves {
+                graph.add_edge(*leaf_ix, this_index, "".into());
+            }
+            // nothing after a `break` or `continue` in the same block is reachable.
+            (vec![], exit_node)
+        }
         TypedAstNodeContent::WhileLoop(TypedWhileLoop { body, .. }) => {
             // a while loop can loop back to the beginning,
             // or it can terminate.
@@ -811,6 +840,7 @@ fn connect_expression(
         StructExpression {
             struct_name,
             fields,
+            ..
         } => {
             let decl = match graph.namespace.find_struct_decl(struct_name.as_str()) {
                 Some(ix) => *ix,
@@ -1025,6 +1055,15 @@ fn connect_expression(
             tree_type,
             exp.span.clone(),
         ),
+        Cast { exp, .. } => connect_expression(
+            &exp.expression,
+            graph,
+            leaves,
+            exit_node,
+            "cast exp",
+            tree_type,
+            exp.span.clone(),
+        ),
     }
 }
 
@@ -1066,6 +1105,23 @@ fn connect_intrinsic_function(
             }
             vec![node]
         }
+        TypedIntrinsicFunctionKind::Revert { code } => {
+            let code_leaves = connect_expression(
+                &(*code).expression,
+                graph,
+                leaves,
+                exit_node,
+                "revert",
+                tree_type,
+                code.span.clone(),
+            )?;
+            let node = graph.add_node("revert".into());
+            for leaf in code_leaves {
+                graph.add_edge(leaf, node, "".into());
+            }
+            // like a return, nothing after a revert is reachable
+            vec![]
+        }
     };
     Ok(result)
 }
@@ -2,6 +2,7 @@ use {
     crate::{
         constants::{
             STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME,
+            TEST_ATTRIBUTE_NAME,
         },
         error::{err, ok, CompileError, CompileResult, CompileWarning},
         type_engine::{insert_type, AbiName, IntegerBits},
@@ -12,8 +13,8 @@ use {
         MethodName, ParseTree, Purity, Reassignment, ReassignmentTarget, ReturnStatement,
         Scrutinee, StorageDeclaration, StorageField, StructDeclaration, StructExpressionField,
         StructField, StructScrutineeField, Supertrait, TraitConstraint, TraitDeclaration, TraitFn,
-        TreeType, TypeArgument, TypeInfo, TypeParameter, UseStatement, VariableDeclaration,
-        Visibility, WhileLoop,
+        TreeType, TypeArgument, TypeInfo, TypeParameter, TypeParameterKind, UseStatement,
+        VariableDeclaration, Visibility, WhileLoop,
     },
     std::{
         collections::HashMap,
@@ -28,12 +29,12 @@ use {
         ty::TyTupleDescriptor,
         AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
         Dependency, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition,
-        IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConst, ItemEnum, ItemFn, ItemImpl,
-        ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemUse, LitInt, LitIntType, MatchBranchKind,
-        Module, ModuleKind, PathExpr, PathExprSegment, PathType, PathTypeSegment, Pattern,
-        PatternStructField, PubToken, QualifiedPathRoot, Statement, StatementLet, Traits, Ty,
-        TypeField, UseTree, WhereClause,
+        ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArg, GenericArgs, GenericParam,
+        GenericParams, IfCondition, IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConst,
+        ItemEnum, ItemFn, ItemImpl, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemUse, LitInt,
+        LitIntType, MatchBranchKind, Module, ModuleKind, PathExpr, PathExprSegment, PathType,
+        PathTypeSegment, Pattern, PatternStructField, PubToken, QualifiedPathRoot, Statement,
+        StatementLet, Traits, Ty, TypeField, UseTree, WhereClause,
     },
     sway_types::{Ident, Span, Spanned},
     thiserror::Error,
@@ -89,12 +90,14 @@ impl ErrorContext {
 
 #[derive(Error, Debug, Clone, PartialEq, Hash)]
 pub enum ConvertParseTreeError {
-    #[error("pub use imports are not supported")]
-    PubUseNotSupported { span: Span },
     #[error("return expressions are not allowed outside of blocks")]
     ReturnOutsideOfBlock { span: Span },
     #[error("while expressions are not allowed outside of blocks")]
     WhileOutsideOfBlock { span: Span },
+    #[error("break expressions are not allowed outside of blocks")]
+    BreakOutsideOfBlock { span: Span },
+    #[error("continue expressions are not allowed outside of blocks")]
+    ContinueOutsideOfBlock { span: Span },
     #[error("functions used in applications may not be arbitrary expressions")]
     FunctionArbitraryExpression { span: Span },
     #[error("generics are not supported here")]
@@ -111,6 +114,8 @@ pub enum ConvertParseTreeError {
     IsReferenceTypeOneGenericArg { span: Span },
     #[error("__size_of_val requires exactly one argument")]
     SizeOfValOneArg { span: Span },
+    #[error("__revert requires exactly one argument")]
+    RevertOneArg { span: Span },
     #[error("tuple index out of range")]
     TupleIndexOutOfRange { span: Span },
     #[error("shift-left expressions are not implemented")]
@@ -119,6 +124,8 @@ pub enum ConvertParseTreeError {
     ShrNotImplemented { span: Span },
     #[error("bitwise xor expressions are not implemented")]
     BitXorNotImplemented { span: Span },
+    #[error("negation expressions are not implemented")]
+    NegNotImplemented { span: Span },
     #[error("reassignment expressions outside of blocks are not implemented")]
     ReassignmentOutsideOfBlock { span: Span },
     #[error("integer literals in this position cannot have a type suffix")]
@@ -192,9 +199,10 @@ pub enum ConvertParseTreeError {
 impl Spanned for ConvertParseTreeError {
     fn span(&self) -> Span {
         match self {
-            ConvertParseTreeError::PubUseNotSupported { span } => span.clone(),
             ConvertParseTreeError::ReturnOutsideOfBlock { span } => span.clone(),
             ConvertParseTreeError::WhileOutsideOfBlock { span } => span.clone(),
+            ConvertParseTreeError::BreakOutsideOfBlock { span } => span.clone(),
+            ConvertParseTreeError::ContinueOutsideOfBlock { span } => span.clone(),
             ConvertParseTreeError::FunctionArbitraryExpression { span } => span.clone(),
             ConvertParseTreeError::GenericsNotSupportedHere { span } => span.clone(),
             ConvertParseTreeError::FullyQualifiedPathsNotSupportedHere { span } => span.clone(),
@@ -203,10 +211,12 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::IsReferenceTypeTooManyArgs { span } => span.clone(),
             ConvertParseTreeError::IsReferenceTypeOneGenericArg { span } => span.clone(),
             ConvertParseTreeError::SizeOfValOneArg { span } => span.clone(),
+            ConvertParseTreeError::RevertOneArg { span } => span.clone(),
             ConvertParseTreeError::TupleIndexOutOfRange { span } => span.clone(),
             ConvertParseTreeError::ShlNotImplemented { span } => span.clone(),
             ConvertParseTreeError::ShrNotImplemented { span } => span.clone(),
             ConvertParseTreeError::BitXorNotImplemented { span } => span.clone(),
+            ConvertParseTreeError::NegNotImplemented { span } => span.clone(),
             ConvertParseTreeError::ReassignmentOutsideOfBlock { span } => span.clone(),
             ConvertParseTreeError::IntTySuffixNotSupported { span } => span.clone(),
             ConvertParseTreeError::IntLiteralOutOfRange { span } => span.clone(),
@@ -297,7 +307,7 @@ fn item_to_ast_nodes(ec: &mut ErrorContext, item: Item) -> Result<Vec<AstNode>,
     let span = item.span();
     let contents = match item.value {
         ItemKind::Use(item_use) => {
-            let use_statements = item_use_to_use_statements(ec, item_use)?;
+            let use_statements = item_use_to_use_statements(item_use);
             use_statements
                 .into_iter()
                 .map(AstNodeContent::UseStatement)
@@ -405,38 +415,31 @@ fn item_attrs_to_map(attribute_list: &[AttributeDecl]) -> Result<AttributesMap,
     Ok(attrs_map)
 }
 
-fn item_use_to_use_statements(
-    ec: &mut ErrorContext,
-    item_use: ItemUse,
-) -> Result<Vec<UseStatement>, ErrorEmitted> {
-    if let Some(pub_token) = item_use.visibility {
-        let error = ConvertParseTreeError::PubUseNotSupported {
-            span: pub_token.span(),
-        };
-        return Err(ec.error(error));
-    }
+fn item_use_to_use_statements(item_use: ItemUse) -> Vec<UseStatement> {
     let mut ret = Vec::new();
     let mut prefix = Vec::new();
     use_tree_to_use_statements(
         item_use.tree,
         item_use.root_import.is_some(),
+        pub_token_opt_to_visibility(item_use.visibility),
         &mut prefix,
         &mut ret,
     );
     debug_assert!(prefix.is_empty());
-    Ok(ret)
+    ret
 }
 
 fn use_tree_to_use_statements(
     use_tree: UseTree,
     is_absolute: bool,
+    visibility: Visibility,
     path: &mut Vec<Ident>,
     ret: &mut Vec<UseStatement>,
 ) {
     match use_tree {
         UseTree::Group { imports } => {
             for use_tree in imports.into_inner() {
-                use_tree_to_use_statements(use_tree, is_absolute, path, ret);
+                use_tree_to_use_statements(use_tree, is_absolute, visibility, path, ret);
             }
         }
         UseTree::Name { name } => {
@@ -450,6 +453,7 @@ fn use_tree_to_use_statements(
                 import_type,
                 is_absolute,
                 alias: None,
+                visibility,
             });
         }
         UseTree::Rename { name, alias, .. } => {
@@ -463,6 +467,7 @@ fn use_tree_to_use_statements(
                 import_type,
                 is_absolute,
                 alias: Some(alias),
+                visibility,
             });
         }
         UseTree::Glob { .. } => {
@@ -471,11 +476,12 @@ fn use_tree_to_use_statements(
                 import_type: ImportType::Star,
                 is_absolute,
                 alias: None,
+                visibility,
             });
         }
         UseTree::Path { prefix, suffix, .. } => {
             path.push(prefix);
-            use_tree_to_use_statements(*suffix, is_absolute, path, ret);
+            use_tree_to_use_statements(*suffix, is_absolute, visibility, path, ret);
             path.pop().unwrap();
         }
     }
@@ -608,6 +614,7 @@ fn item_fn_to_function_declaration(
             item_fn.fn_signature.where_clause_opt,
         )?,
         return_type_span,
+        is_test: attributes.contains_key(TEST_ATTRIBUTE_NAME),
     })
 }
 
@@ -850,15 +857,25 @@ fn generic_params_opt_to_type_parameters(
             .parameters
             .into_inner()
             .into_iter()
-            .map(|ident| TypeParameter {
-                type_id: insert_type(TypeInfo::Custom {
-                    name: ident.clone(),
-                    type_arguments: Vec::new(),
-                }),
-                name_ident: ident,
-                trait_constraints: Vec::new(),
+            .map(|param| {
+                let (ident, kind) = match param {
+                    GenericParam::Type(ident) => (ident, TypeParameterKind::Type),
+                    GenericParam::Const { name, ty, .. } => {
+                        let type_id = insert_type(ty_to_type_info(ec, ty)?);
+                        (name, TypeParameterKind::Const(type_id))
+                    }
+                };
+                Ok(TypeParameter {
+                    type_id: insert_type(TypeInfo::Custom {
+                        name: ident.clone(),
+                        type_arguments: Vec::new(),
+                    }),
+                    name_ident: ident,
+                    trait_constraints: Vec::new(),
+                    kind,
+                })
             })
-            .collect::<Vec<_>>(),
+            .collect::<Result<Vec<_>, _>>()?,
         None => Vec::new(),
     };
 
@@ -885,6 +902,7 @@ fn generic_params_opt_to_type_parameters(
                     .iter()
                     .map(|call_path| TraitConstraint {
                         call_path: call_path.clone(),
+                        resolved_call_path: None,
                     }),
             );
     }
@@ -998,10 +1016,18 @@ fn ty_to_type_info(ec: &mut ErrorContext, ty: Ty) -> Result<TypeInfo, ErrorEmitt
         }
         Ty::Array(bracketed_ty_array_descriptor) => {
             let ty_array_descriptor = bracketed_ty_array_descriptor.into_inner();
-            TypeInfo::Array(
-                crate::type_engine::insert_type(ty_to_type_info(ec, *ty_array_descriptor.ty)?),
-                expr_to_usize(ec, *ty_array_descriptor.length)?,
-            )
+            let elem_type_id =
+                crate::type_engine::insert_type(ty_to_type_info(ec, *ty_array_descriptor.ty)?);
+            match *ty_array_descriptor.length {
+                // A length given by name refers to a const generic parameter.
+                Expr::Path(path_expr) => match path_expr.try_into_ident() {
+                    Ok(length) => TypeInfo::GenericLengthArray(elem_type_id, length),
+                    Err(path_expr) => {
+                        TypeInfo::Array(elem_type_id, expr_to_usize(ec, Expr::Path(path_expr))?)
+                    }
+                },
+                length => TypeInfo::Array(elem_type_id, expr_to_usize(ec, length)?),
+            }
         }
         Ty::Str { length, .. } => TypeInfo::Str(expr_to_u64(ec, *length.into_inner())?),
         Ty::Infer { .. } => TypeInfo::Unknown,
@@ -1116,12 +1142,24 @@ fn expr_to_ast_node(
                 span,
             }
         }
+        Expr::Break { label_opt, .. } => AstNode {
+            content: AstNodeContent::Break(label_opt.map(|label| label.name)),
+            span,
+        },
+        Expr::Continue { label_opt, .. } => AstNode {
+            content: AstNodeContent::Continue(label_opt.map(|label| label.name)),
+            span,
+        },
         Expr::While {
-            condition, block, ..
+            label_opt,
+            condition,
+            block,
+            ..
         } => AstNode {
             content: AstNodeContent::WhileLoop(WhileLoop {
                 condition: expr_to_expression(ec, *condition)?,
                 body: braced_code_block_contents_to_code_block(ec, block)?,
+                label: label_opt.map(|(label, _colon_token)| label.name),
             }),
             span,
         },
@@ -1257,6 +1295,18 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
             };
             return Err(ec.error(error));
         }
+        Expr::Break { break_token, .. } => {
+            let error = ConvertParseTreeError::BreakOutsideOfBlock {
+                span: break_token.span(),
+            };
+            return Err(ec.error(error));
+        }
+        Expr::Continue { continue_token, .. } => {
+            let error = ConvertParseTreeError::ContinueOutsideOfBlock {
+                span: continue_token.span(),
+            };
+            return Err(ec.error(error));
+        }
         Expr::If(if_expr) => if_expr_to_expression(ec, if_expr)?,
         Expr::Match {
             value, branches, ..
@@ -1340,6 +1390,24 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
                 prefix,
                 mut suffix,
             } = path_expr;
+            let root_opt = match root_opt {
+                Some((Some(qualified_path_root), _double_colon_token)) => {
+                    let arguments = args
+                        .into_inner()
+                        .into_iter()
+                        .map(|expr| expr_to_expression(ec, expr))
+                        .collect::<Result<_, _>>()?;
+                    return qualified_path_call_to_expression(
+                        ec,
+                        qualified_path_root.into_inner(),
+                        prefix,
+                        suffix,
+                        arguments,
+                        span,
+                    );
+                }
+                root_opt => root_opt,
+            };
             let is_absolute = path_root_opt_to_bool(ec, root_opt)?;
             let (prefixes, method_type_opt, suffix_path_expr) = match suffix.pop() {
                 Some((_double_colon_token, call_path_suffix)) => match suffix.pop() {
@@ -1453,8 +1521,8 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
                                 iter_to_array(generic_args.parameters.into_inner())
                             })
                         } {
-                            Some([ty]) => ty,
-                            None => {
+                            Some([GenericArg::Type(ty)]) => ty,
+                            _ => {
                                 let error = ConvertParseTreeError::SizeOfOneGenericArg { span };
                                 return Err(ec.error(error));
                             }
@@ -1499,8 +1567,8 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
                                 iter_to_array(generic_args.parameters.into_inner())
                             })
                         } {
-                            Some([ty]) => ty,
-                            None => {
+                            Some([GenericArg::Type(ty)]) => ty,
+                            _ => {
                                 let error =
                                     ConvertParseTreeError::IsReferenceTypeOneGenericArg { span };
                                 return Err(ec.error(error));
@@ -1531,6 +1599,26 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
                             kind: IntrinsicFunctionKind::SizeOfVal { exp },
                             span,
                         }
+                    } else if call_path.prefixes.is_empty()
+                        && !call_path.is_absolute
+                        && Intrinsic::try_from_str(call_path.suffix.as_str())
+                            == Some(Intrinsic::Revert)
+                    {
+                        if generics_opt.is_some() {
+                            let error = ConvertParseTreeError::GenericsNotSupportedHere { span };
+                            return Err(ec.error(error));
+                        }
+                        let code = match <[_; 1]>::try_from(arguments) {
+                            Ok([code]) => Box::new(code),
+                            Err(..) => {
+                                let error = ConvertParseTreeError::RevertOneArg { span };
+                                return Err(ec.error(error));
+                            }
+                        };
+                        Expression::IntrinsicFunction {
+                            kind: IntrinsicFunctionKind::Revert { code },
+                            span,
+                        }
                     } else {
                         let type_arguments = match generics_opt {
                             Some((_double_colon_token, generic_args)) => {
@@ -1646,6 +1734,21 @@ fn expr_to_expression(ec: &mut ErrorContext, expr: Expr) -> Result<Expression, E
             unary_op_call(ec, "deref", deref_token.span(), span, *expr)?
         }
         Expr::Not { bang_token, expr } => unary_op_call(ec, "not", bang_token.span(), span, *expr)?,
+        Expr::Neg { expr, .. } => {
+            // All integer types are unsigned, so a negated integer literal can never be valid.
+            let error: CompileError = match *expr {
+                Expr::Literal(sway_parse::Literal::Int(..)) => {
+                    CompileError::NegativeLiteralForUnsignedType { span }
+                }
+                _ => ConvertParseTreeError::NegNotImplemented { span }.into(),
+            };
+            return Err(ec.error(error));
+        }
+        Expr::Cast { expr, ty, .. } => Expression::Cast {
+            expr: Box::new(expr_to_expression(ec, *expr)?),
+            type_arg: ty_to_type_argument(ec, ty)?,
+            span,
+        },
         Expr::Mul {
             lhs,
             star_token,
@@ -2155,7 +2258,7 @@ fn if_expr_to_expression(
     let then_block_span = then_block.span();
     let then_block = Expression::CodeBlock {
         contents: braced_code_block_contents_to_code_block(ec, then_block)?,
-        span: then_block_span.clone(),
+        span: then_block_span,
     };
     let else_block = match else_opt {
         None => None,
@@ -2176,46 +2279,73 @@ fn if_expr_to_expression(
             r#else: else_block.map(Box::new),
             span,
         },
-        IfCondition::Let { lhs, rhs, .. } => {
-            let scrutinee = pattern_to_scrutinee(ec, *lhs)?;
-            let scrutinee_span = scrutinee.span();
-            let mut branches = vec![MatchBranch {
-                scrutinee,
-                result: then_block.clone(),
-                span: Span::join(scrutinee_span, then_block_span),
-            }];
-            branches.push(match else_block {
-                Some(else_block) => {
-                    let else_block_span = else_block.span();
-                    MatchBranch {
-                        scrutinee: Scrutinee::CatchAll {
-                            span: else_block_span.clone(),
-                        },
-                        result: else_block,
-                        span: else_block_span,
-                    }
-                }
-                None => {
-                    let else_block_span = then_block.span();
-                    MatchBranch {
-                        scrutinee: Scrutinee::CatchAll {
-                            span: else_block_span.clone(),
-                        },
-                        result: then_block,
-                        span: else_block_span,
-                    }
-                }
-            });
-            Expression::MatchExp {
-                value: Box::new(expr_to_expression(ec, *rhs)?),
-                branches,
-                span,
-            }
-        }
+        IfCondition::Let { lhs, rhs, .. } => Expression::IfLet {
+            scrutinee: pattern_to_scrutinee(ec, *lhs)?,
+            expr: Box::new(expr_to_expression(ec, *rhs)?),
+            then: Box::new(then_block),
+            r#else: else_block.map(Box::new),
+            span,
+        },
     };
     Ok(expression)
 }
 
+/// Converts a call through a qualified path root, such as `<Foo as Bar>::baz(..)`.
+fn qualified_path_call_to_expression(
+    ec: &mut ErrorContext,
+    qualified_path_root: QualifiedPathRoot,
+    method_segment: PathExprSegment,
+    suffix: Vec<(DoubleColonToken, PathExprSegment)>,
+    arguments: Vec<Expression>,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    if let Some((_double_colon_token, path_expr_segment)) = suffix.first() {
+        let error = ConvertParseTreeError::QualifiedPathRootsNotImplemented {
+            span: path_expr_segment.span(),
+        };
+        return Err(ec.error(error));
+    }
+    let (method_name, type_arguments) =
+        path_expr_segment_to_ident_or_type_argument(ec, method_segment)?;
+    if !type_arguments.is_empty() {
+        let error = ConvertParseTreeError::GenericsNotSupportedHere {
+            span: type_arguments
+                .iter()
+                .map(|type_argument| type_argument.span.clone())
+                .reduce(Span::join)
+                .unwrap(),
+        };
+        return Err(ec.error(error));
+    }
+    let QualifiedPathRoot { ty, as_trait } = qualified_path_root;
+    let type_name_span = ty.span();
+    let type_name = ty_to_type_info(ec, *ty)?;
+    let method_name = match as_trait {
+        Some((_as_token, path_type)) => MethodName::FromQualifiedPathRoot {
+            type_name,
+            type_name_span,
+            as_trait: path_type_to_call_path(ec, *path_type)?,
+            method_name,
+        },
+        None => MethodName::FromType {
+            call_path: CallPath {
+                prefixes: Vec::new(),
+                suffix: method_name,
+                is_absolute: false,
+            },
+            type_name,
+            type_name_span,
+        },
+    };
+    Ok(Expression::MethodApplication {
+        method_name,
+        contract_call_params: Vec::new(),
+        arguments,
+        type_arguments: Vec::new(),
+        span,
+    })
+}
+
 fn path_root_opt_to_bool(
     ec: &mut ErrorContext,
     root_opt: Option<(Option<AngleBrackets<QualifiedPathRoot>>, DoubleColonToken)>,
@@ -2526,6 +2656,10 @@ fn match_branch_to_match_branch(
     let span = match_branch.span();
     Ok(MatchBranch {
         scrutinee: pattern_to_scrutinee(ec, match_branch.pattern)?,
+        guard: match match_branch.guard_opt {
+            Some((_if_token, guard)) => Some(expr_to_expression(ec, *guard)?),
+            None => None,
+        },
         result: match match_branch.kind {
             MatchBranchKind::Block { block, .. } => {
                 let span = block.span();
@@ -2693,7 +2827,15 @@ fn generic_args_to_type_parameters(
         .parameters
         .into_inner()
         .into_iter()
-        .map(|x| ty_to_type_parameter(ec, x))
+        .map(|arg| match arg {
+            GenericArg::Type(ty) => ty_to_type_parameter(ec, ty),
+            GenericArg::Const(literal) => {
+                let error = ConvertParseTreeError::GenericsNotSupportedHere {
+                    span: literal.span(),
+                };
+                Err(ec.error(error))
+            }
+        })
         .collect()
 }
 
@@ -2792,6 +2934,7 @@ fn ty_to_type_parameter(ec: &mut ErrorContext, ty: Ty) -> Result<TypeParameter,
                 type_id: insert_type(TypeInfo::Unknown),
                 name_ident: underscore_token.into(),
                 trait_constraints: Default::default(),
+                kind: TypeParameterKind::Type,
             })
         }
         Ty::Tuple(..) => panic!("tuple types are not allowed in this position"),
@@ -2805,6 +2948,7 @@ fn ty_to_type_parameter(ec: &mut ErrorContext, ty: Ty) -> Result<TypeParameter,
         }),
         name_ident,
         trait_constraints: Vec::new(),
+        kind: TypeParameterKind::Type,
     })
 }
 
@@ -2927,9 +3071,15 @@ fn generic_args_to_type_arguments(
         .parameters
         .into_inner()
         .into_iter()
-        .map(|ty| {
-            let span = ty.span();
-            let type_id = insert_type(ty_to_type_info(ec, ty)?);
+        .map(|arg| {
+            let span = arg.span();
+            let type_info = match arg {
+                GenericArg::Type(ty) => ty_to_type_info(ec, ty)?,
+                GenericArg::Const(literal) => {
+                    TypeInfo::ConstGenericValue(expr_to_u64(ec, Expr::Literal(literal))?)
+                }
+            };
+            let type_id = insert_type(type_info);
             Ok(TypeArgument { type_id, span })
         })
         .collect()
@@ -2994,8 +3144,8 @@ fn path_type_to_type_info(
                         iter_to_array(generic_args.parameters.into_inner())
                     })
                 } {
-                    Some([ty]) => ty,
-                    None => {
+                    Some([GenericArg::Type(ty)]) => ty,
+                    _ => {
                         let error = ConvertParseTreeError::ContractCallerOneGenericArg { span };
                         return Err(ec.error(error));
                     }
@@ -3065,3 +3215,72 @@ where
     let ret = unsafe { ret.assume_init() };
     Some(ret)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use crate::test_helpers::compile_errors;
+
+    #[test]
+    fn test_negative_literal_for_unsigned_type() {
+        let src = r#"
+        script;
+        fn main() {
+            let y: u64 = -5;
+        }
+        "#;
+        let errors = parse(src.into(), None).errors;
+        assert!(
+            errors.iter().any(|error| matches!(
+                error,
+                CompileError::NegativeLiteralForUnsignedType { span } if span.as_str() == "-5"
+            )),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_negation_does_not_affect_subtraction() {
+        let src = r#"
+        script;
+        fn main() {
+            let a = 5;
+            let b = a - 5;
+            let c = a -5;
+            let d = -a;
+        }
+        "#;
+        let errors = parse(src.into(), None).errors;
+        // only `-a` is a negation, and negating arbitrary expressions isn't supported
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert!(matches!(
+            &errors[0],
+            CompileError::ConvertParseTree {
+                error: ConvertParseTreeError::NegNotImplemented { span },
+            } if span.as_str() == "-a"
+        ));
+    }
+
+    #[test]
+    fn test_directly_recursive_struct_is_rejected() {
+        let src = r#"
+        script;
+
+        struct List {
+            value: u64,
+            next: List,
+        }
+
+        fn main() {}
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            CompileError::ConvertParseTree {
+                error: ConvertParseTreeError::RecursiveType { .. }
+            }
+        )));
+    }
+}
@@ -1,12 +1,15 @@
 //! Tools related to handling/recovering from Sway compile errors and reporting them to the user.
 
 use crate::{
-    constants::STORAGE_PURITY_ATTRIBUTE_NAME,
+    constants::{
+        STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME,
+    },
     convert_parse_tree::ConvertParseTreeError,
     style::{to_screaming_snake_case, to_snake_case, to_upper_camel_case},
     type_engine::*,
     VariableDeclaration,
 };
+use serde::Serialize;
 use sway_types::{ident::Ident, span::Span, Spanned};
 
 use std::{fmt, path::PathBuf, sync::Arc};
@@ -219,7 +222,7 @@ impl CompileWarning {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Serialize)]
 pub struct LineCol {
     pub line: usize,
     pub col: usize,
@@ -294,6 +297,53 @@ pub enum Warning {
         unneeded_attrib: String,
     },
     MatchExpressionUnreachableArm,
+    CastTruncatesConstant {
+        from: u64,
+        to: IntegerBits,
+    },
+    FunctionNeverReturns {
+        declared_type: TypeId,
+    },
+    UnusedImport {
+        name: Ident,
+    },
+}
+
+impl Warning {
+    /// The name of this warning, e.g. `DeadDeclaration`, reported as its [Diagnostic] code.
+    pub fn code(&self) -> &'static str {
+        use Warning::*;
+        match self {
+            NonClassCaseStructName { .. } => "NonClassCaseStructName",
+            NonClassCaseTypeParameter { .. } => "NonClassCaseTypeParameter",
+            NonClassCaseTraitName { .. } => "NonClassCaseTraitName",
+            NonClassCaseEnumName { .. } => "NonClassCaseEnumName",
+            NonClassCaseEnumVariantName { .. } => "NonClassCaseEnumVariantName",
+            NonSnakeCaseStructFieldName { .. } => "NonSnakeCaseStructFieldName",
+            NonSnakeCaseFunctionName { .. } => "NonSnakeCaseFunctionName",
+            NonScreamingSnakeCaseConstName { .. } => "NonScreamingSnakeCaseConstName",
+            LossOfPrecision { .. } => "LossOfPrecision",
+            UnusedReturnValue { .. } => "UnusedReturnValue",
+            SimilarMethodFound { .. } => "SimilarMethodFound",
+            ShadowsOtherSymbol { .. } => "ShadowsOtherSymbol",
+            OverridingTraitImplementation => "OverridingTraitImplementation",
+            DeadDeclaration => "DeadDeclaration",
+            DeadFunctionDeclaration => "DeadFunctionDeclaration",
+            DeadStructDeclaration => "DeadStructDeclaration",
+            DeadTrait => "DeadTrait",
+            UnreachableCode => "UnreachableCode",
+            DeadEnumVariant { .. } => "DeadEnumVariant",
+            DeadMethod => "DeadMethod",
+            StructFieldNeverRead => "StructFieldNeverRead",
+            ShadowingReservedRegister { .. } => "ShadowingReservedRegister",
+            DeadStorageDeclaration => "DeadStorageDeclaration",
+            DeadStorageDeclarationForFunction { .. } => "DeadStorageDeclarationForFunction",
+            MatchExpressionUnreachableArm => "MatchExpressionUnreachableArm",
+            CastTruncatesConstant { .. } => "CastTruncatesConstant",
+            FunctionNeverReturns { .. } => "FunctionNeverReturns",
+            UnusedImport { .. } => "UnusedImport",
+        }
+    }
 }
 
 impl fmt::Display for Warning {
@@ -417,6 +467,19 @@ impl fmt::Display for Warning {
                 and can be removed."
             ),
             MatchExpressionUnreachableArm => write!(f, "This match arm is unreachable."),
+            CastTruncatesConstant { from, to } => write!(
+                f,
+                "The constant {} does not fit in an integer type of width {} and will be \
+                 truncated by this cast.",
+                from, to
+            ),
+            FunctionNeverReturns { declared_type } => write!(
+                f,
+                "This function never returns, so it will never produce a value of its declared \
+                 return type \"{}\".",
+                declared_type
+            ),
+            UnusedImport { name } => write!(f, "Unused import \"{}\".", name),
         }
     }
 }
@@ -494,6 +557,16 @@ pub enum CompileError {
     NoScriptMainFunction(Span),
     #[error("Function \"{name}\" was already defined in scope.")]
     MultipleDefinitionsOfFunction { name: Ident },
+    #[error("The name \"{name}\" is already defined in this scope.")]
+    MultipleDefinitionsOfName { name: Ident, span: Span },
+    #[error("Constant \"{name}\" was already defined in this module.")]
+    MultipleDefinitionsOfConstant { name: Ident, span: Span },
+    #[error("Negative literals are not allowed, as all integer types are unsigned.")]
+    NegativeLiteralForUnsignedType { span: Span },
+    #[error(
+        "Enum variant has tag {tag}, but tags must be unique and numbered contiguously from 0."
+    )]
+    DuplicateEnumTag { tag: usize, span: Span },
     #[error(
         "Attempted to reassign to a symbol that is not a variable. Symbol {name} is not a mutable \
          variable, it is a {kind}."
@@ -503,8 +576,15 @@ pub enum CompileError {
         kind: &'static str,
         span: Span,
     },
-    #[error("Assignment to immutable variable. Variable {name} is not declared as mutable.")]
-    AssignmentToNonMutable { name: Ident },
+    #[error(
+        "Assignment to immutable variable. Cannot assign to \"{path}\" because variable {name} is \
+         not declared as mutable."
+    )]
+    AssignmentToNonMutable {
+        name: Ident,
+        path: String,
+        span: Span,
+    },
     #[error(
         "Generic type \"{name}\" is not in scope. Perhaps you meant to specify type parameters in \
          the function signature? For example: \n`fn \
@@ -554,6 +634,10 @@ pub enum CompileError {
     },
     #[error("\"{name}\" does not take type arguments.")]
     DoesNotTakeTypeArguments { name: Ident, span: Span },
+    #[error(
+        "Generic parameter \"{name}\" is a const parameter and expects a constant value, e.g. `3`."
+    )]
+    ConstGenericMismatch { name: Ident, span: Span },
     #[error("\"{name}\" needs type arguments.")]
     NeedsTypeArguments { name: Ident, span: Span },
     #[error(
@@ -639,25 +723,39 @@ pub enum CompileError {
     #[error("This is a {actually}, not an ABI.")]
     DeclIsNotAnAbi { actually: String, span: Span },
     #[error(
-        "Field \"{field_name}\" not found on struct \"{struct_name}\". Available fields are:\n \
-         {available_fields}"
+        "Field \"{field_name}\" not found on struct \"{struct_name}\". {}",
+        field_not_found_help(available_fields, did_you_mean)
     )]
     FieldNotFound {
         field_name: Ident,
         available_fields: String,
         struct_name: Ident,
+        did_you_mean: Option<String>,
     },
     #[error("Could not find symbol \"{name}\" in this scope.")]
     SymbolNotFound { name: Ident },
+    #[error(
+        "Could not find symbol \"{name}\" in this scope. {}",
+        symbol_not_found_help(searched, suggestion)
+    )]
+    SymbolNotFoundWithSuggestion {
+        name: Ident,
+        searched: Vec<crate::semantic_analysis::namespace::PathBuf>,
+        suggestion: Option<Ident>,
+        span: Span,
+    },
     #[error("Symbol \"{name}\" is private.")]
     ImportPrivateSymbol { name: Ident },
     #[error(
-        "Because this if expression's value is used, an \"else\" branch is required and it must \
-         return type \"{r#type}\""
+        "This if expression has no \"else\" branch, so it can't evaluate to a value of type \
+         \"{then_type}\". Add an \"else\" branch returning \"{then_type}\", or end the \
+         \"then\" branch with a semicolon."
     )]
-    NoElseBranch { span: Span, r#type: String },
+    IfExpressionMissingElse { then_type: String, span: Span },
+    #[error("This constant `{op}` operation overflows its integer type.")]
+    ConstantArithmeticOverflow { op: String, span: Span },
     #[error("Use of type `Self` outside of a context in which `Self` refers to a type.")]
-    UnqualifiedSelfType { span: Span },
+    SelfTypeNotAllowed { span: Span },
     #[error(
         "Symbol \"{name}\" does not refer to a type, it refers to a {actually_is}. It cannot be \
          used in this position."
@@ -809,11 +907,16 @@ pub enum CompileError {
         provided_args: usize,
         span: Span,
     },
-    #[error("This parameter was declared as type {should_be}, but argument of type {provided} was provided.")]
-    ArgumentParameterTypeMismatch {
+    #[error(
+        "Argument {} of this call was declared as type {expected}, but argument of type {found} \
+         was provided.",
+        arg_index + 1
+    )]
+    ArgumentTypeMismatch {
+        arg_index: usize,
+        expected: String,
+        found: String,
         span: Span,
-        should_be: String,
-        provided: String,
     },
     #[error("Function {fn_name} is recursive, which is unsupported at this time.")]
     RecursiveCall { fn_name: Ident, span: Span },
@@ -849,6 +952,21 @@ pub enum CompileError {
     ContractStorageFromExternalContext { span: Span },
     #[error("Array index out of bounds; the length is {count} but the index is {index}.")]
     ArrayOutOfBounds { index: u64, count: u64, span: Span },
+    #[error(
+        "Array element {index} has mismatched type.\n\
+         expected: {expected}\n\
+         found:    {found}."
+    )]
+    ArrayElementTypeMismatch {
+        expected: TypeId,
+        found: TypeId,
+        index: usize,
+        span: Span,
+    },
+    #[error(
+        "The type of this empty array can't be inferred. Try annotating it with a type annotation."
+    )]
+    CannotInferEmptyArrayType { span: Span },
     #[error("Tuple index out of bounds; the arity is {count} but the index is {index}.")]
     TupleIndexOutOfBounds {
         index: usize,
@@ -859,8 +977,16 @@ pub enum CompileError {
     ShadowsOtherSymbol { name: Ident },
     #[error("The name \"{name}\" is already used for a generic parameter in this scope.")]
     GenericShadowsGeneric { name: Ident },
-    #[error("The name \"{name}\" imported through `*` shadows another symbol with the same name.")]
-    StarImportShadowsOtherSymbol { name: Ident },
+    #[error(
+        "The name \"{name}\" is ambiguous, as it is imported through `*` from more than one \
+         module: {}.",
+        candidates.iter().map(|candidate| candidate.to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    AmbiguousImport {
+        name: Ident,
+        candidates: Vec<crate::parse_tree::CallPath>,
+        span: Span,
+    },
     #[error(
         "Match expression arm has mismatched types.\n\
          expected: {expected}\n\
@@ -911,6 +1037,17 @@ pub enum CompileError {
         attrs: String,
         span: Span,
     },
+    #[error(
+        "Storage cannot be accessed from a pure function. Try adding a \
+        \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}(...)]\" attribute to the function declaration."
+    )]
+    StorageAccessInPureFunction { span: Span },
+    #[error(
+        "Storage cannot be written from a function which only reads storage. Try adding \
+        \"{STORAGE_PURITY_WRITE_NAME}\" to the function's \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}(...)]\" \
+        attribute."
+    )]
+    StorageWriteInReadOnlyFunction { span: Span },
     #[error("Literal value is too large for type {ty}.")]
     IntegerTooLarge { span: Span, ty: String },
     #[error("Literal value underflows type {ty}.")]
@@ -937,6 +1074,29 @@ pub enum CompileError {
     },
     #[error("Cannot use `if let` on a non-enum type.")]
     IfLetNonEnum { span: Span },
+    #[error("The pattern \"{pattern}\" can never match a value of type \"{type_name}\".")]
+    IfLetPatternMismatch {
+        pattern: String,
+        type_name: String,
+        span: Span,
+    },
+    #[error(
+        "The argument to \"{intrinsic}\" must be of type \"{expected}\", but found \"{found}\"."
+    )]
+    IntrinsicArgumentTypeMismatch {
+        intrinsic: String,
+        expected: String,
+        found: String,
+        span: Span,
+    },
+    #[error(
+        "Cannot cast a value of type \"{from}\" to \"{to}\". Only integers can be cast with `as`."
+    )]
+    InvalidCast {
+        from: String,
+        to: String,
+        span: Span,
+    },
     #[error(
         "Contract ABI method parameter \"{param_name}\" is set multiple times for this contract ABI method call"
     )]
@@ -977,6 +1137,22 @@ pub enum CompileError {
     NonConstantDeclValue { span: Span },
     #[error("Declaring storage in a {program_kind} is not allowed.")]
     StorageDeclarationInNonContract { program_kind: String, span: Span },
+    #[error("\"break\" used outside of a loop.")]
+    BreakOutsideLoop { span: Span },
+    #[error("\"continue\" used outside of a loop.")]
+    ContinueOutsideLoop { span: Span },
+    #[error("No enclosing loop is labeled \"'{label}\".")]
+    UnknownLoopLabel { label: Ident },
+    #[error("The guard of a match arm must be of type \"bool\".")]
+    MatchGuardNotBoolean { span: Span },
+    #[error(
+        "Contract calls read and write external state, so they can only be made from a function \
+        with a \"#[{STORAGE_PURITY_ATTRIBUTE_NAME}({STORAGE_PURITY_READ_NAME}, \
+        {STORAGE_PURITY_WRITE_NAME})]\" attribute."
+    )]
+    ContractCallInPureFunction { span: Span },
+    #[error("Test functions must take no parameters and return either \"()\" or a \"Result\".")]
+    InvalidTestSignature { span: Span },
 }
 
 impl std::convert::From<TypeError> for CompileError {
@@ -1009,8 +1185,12 @@ impl Spanned for CompileError {
             PredicateMainDoesNotReturnBool(span) => span.clone(),
             NoScriptMainFunction(span) => span.clone(),
             MultipleDefinitionsOfFunction { name } => name.span(),
+            MultipleDefinitionsOfName { span, .. } => span.clone(),
+            MultipleDefinitionsOfConstant { span, .. } => span.clone(),
+            NegativeLiteralForUnsignedType { span } => span.clone(),
+            DuplicateEnumTag { span, .. } => span.clone(),
             ReassignmentToNonVariable { span, .. } => span.clone(),
-            AssignmentToNonMutable { name } => name.span(),
+            AssignmentToNonMutable { span, .. } => span.clone(),
             TypeParameterNotInTypeScope { span, .. } => span.clone(),
             MultipleImmediates(span) => span.clone(),
             MismatchedTypeInTrait { span, .. } => span.clone(),
@@ -1020,6 +1200,7 @@ impl Spanned for CompileError {
             MissingInterfaceSurfaceMethods { span, .. } => span.clone(),
             IncorrectNumberOfTypeArguments { span, .. } => span.clone(),
             DoesNotTakeTypeArguments { span, .. } => span.clone(),
+            ConstGenericMismatch { span, .. } => span.clone(),
             NeedsTypeArguments { span, .. } => span.clone(),
             StructNotFound { span, .. } => span.clone(),
             DeclaredNonStructAsStruct { span, .. } => span.clone(),
@@ -1034,9 +1215,11 @@ impl Spanned for CompileError {
             FieldAccessOnNonStruct { span, .. } => span.clone(),
             FieldNotFound { field_name, .. } => field_name.span(),
             SymbolNotFound { name, .. } => name.span(),
+            SymbolNotFoundWithSuggestion { span, .. } => span.clone(),
             ImportPrivateSymbol { name } => name.span(),
-            NoElseBranch { span, .. } => span.clone(),
-            UnqualifiedSelfType { span, .. } => span.clone(),
+            IfExpressionMissingElse { span, .. } => span.clone(),
+            ConstantArithmeticOverflow { span, .. } => span.clone(),
+            SelfTypeNotAllowed { span } => span.clone(),
             NotAType { span, .. } => span.clone(),
             MissingEnumInstantiator { span, .. } => span.clone(),
             PathDoesNotReturn { span, .. } => span.clone(),
@@ -1077,7 +1260,7 @@ impl Spanned for CompileError {
             NotAnAbi { span, .. } => span.clone(),
             ImplAbiForNonContract { span, .. } => span.clone(),
             IncorrectNumberOfInterfaceSurfaceFunctionParameters { span, .. } => span.clone(),
-            ArgumentParameterTypeMismatch { span, .. } => span.clone(),
+            ArgumentTypeMismatch { span, .. } => span.clone(),
             RecursiveCall { span, .. } => span.clone(),
             RecursiveCallChain { span, .. } => span.clone(),
             RecursiveType { span, .. } => span.clone(),
@@ -1089,9 +1272,11 @@ impl Spanned for CompileError {
             BurnFromExternalContext { span, .. } => span.clone(),
             ContractStorageFromExternalContext { span, .. } => span.clone(),
             ArrayOutOfBounds { span, .. } => span.clone(),
+            ArrayElementTypeMismatch { span, .. } => span.clone(),
+            CannotInferEmptyArrayType { span } => span.clone(),
             ShadowsOtherSymbol { name } => name.span(),
             GenericShadowsGeneric { name } => name.span(),
-            StarImportShadowsOtherSymbol { name } => name.span(),
+            AmbiguousImport { span, .. } => span.clone(),
             MatchWrongType { span, .. } => span.clone(),
             MatchExpressionNonExhaustive { span, .. } => span.clone(),
             NotAnEnum { span, .. } => span.clone(),
@@ -1105,6 +1290,8 @@ impl Spanned for CompileError {
             DeclIsNotAnAbi { span, .. } => span.clone(),
             ImpureInNonContract { span, .. } => span.clone(),
             ImpureInPureContext { span, .. } => span.clone(),
+            StorageAccessInPureFunction { span } => span.clone(),
+            StorageWriteInReadOnlyFunction { span } => span.clone(),
             IntegerTooLarge { span, .. } => span.clone(),
             IntegerTooSmall { span, .. } => span.clone(),
             IntegerContainsInvalidDigit { span, .. } => span.clone(),
@@ -1113,6 +1300,9 @@ impl Spanned for CompileError {
             SupertraitImplMissing { span, .. } => span.clone(),
             SupertraitImplRequired { span, .. } => span.clone(),
             IfLetNonEnum { span, .. } => span.clone(),
+            IfLetPatternMismatch { span, .. } => span.clone(),
+            IntrinsicArgumentTypeMismatch { span, .. } => span.clone(),
+            InvalidCast { span, .. } => span.clone(),
             ContractCallParamRepeated { span, .. } => span.clone(),
             UnrecognizedContractParam { span, .. } => span.clone(),
             CallParamForNonContractCallMethod { span, .. } => span.clone(),
@@ -1130,11 +1320,189 @@ impl Spanned for CompileError {
             TupleIndexOutOfBounds { span, .. } => span.clone(),
             NonConstantDeclValue { span } => span.clone(),
             StorageDeclarationInNonContract { span, .. } => span.clone(),
+            BreakOutsideLoop { span } => span.clone(),
+            ContinueOutsideLoop { span } => span.clone(),
+            UnknownLoopLabel { label } => label.span(),
+            MatchGuardNotBoolean { span } => span.clone(),
+            ContractCallInPureFunction { span } => span.clone(),
+            InvalidTestSignature { span } => span.clone(),
         }
     }
 }
 
 impl CompileError {
+    /// The name of this error, e.g. `UnknownVariable`, reported as its [Diagnostic] code.
+    pub fn code(&self) -> &'static str {
+        use CompileError::*;
+        match self {
+            UnknownVariable { .. } => "UnknownVariable",
+            UnknownVariablePath { .. } => "UnknownVariablePath",
+            UnknownFunction { .. } => "UnknownFunction",
+            NotAVariable { .. } => "NotAVariable",
+            NotAFunction { .. } => "NotAFunction",
+            Unimplemented(..) => "Unimplemented",
+            TypeError(..) => "TypeError",
+            ParseError { .. } => "ParseError",
+            Internal(..) => "Internal",
+            InternalOwned(..) => "InternalOwned",
+            InvalidByteLiteralLength { .. } => "InvalidByteLiteralLength",
+            ExpectedExprAfterOp { .. } => "ExpectedExprAfterOp",
+            ExpectedOp { .. } => "ExpectedOp",
+            MultipleContracts(..) => "MultipleContracts",
+            MultipleScripts(..) => "MultipleScripts",
+            MultiplePredicates(..) => "MultiplePredicates",
+            NoPredicateMainFunction(..) => "NoPredicateMainFunction",
+            PredicateMainDoesNotReturnBool(..) => "PredicateMainDoesNotReturnBool",
+            NoScriptMainFunction(..) => "NoScriptMainFunction",
+            MultipleDefinitionsOfFunction { .. } => "MultipleDefinitionsOfFunction",
+            MultipleDefinitionsOfName { .. } => "MultipleDefinitionsOfName",
+            MultipleDefinitionsOfConstant { .. } => "MultipleDefinitionsOfConstant",
+            NegativeLiteralForUnsignedType { .. } => "NegativeLiteralForUnsignedType",
+            DuplicateEnumTag { .. } => "DuplicateEnumTag",
+            ReassignmentToNonVariable { .. } => "ReassignmentToNonVariable",
+            AssignmentToNonMutable { .. } => "AssignmentToNonMutable",
+            TypeParameterNotInTypeScope { .. } => "TypeParameterNotInTypeScope",
+            MultipleImmediates(..) => "MultipleImmediates",
+            MismatchedTypeInTrait { .. } => "MismatchedTypeInTrait",
+            NotATrait { .. } => "NotATrait",
+            UnknownTrait { .. } => "UnknownTrait",
+            FunctionNotAPartOfInterfaceSurface { .. } => "FunctionNotAPartOfInterfaceSurface",
+            MissingInterfaceSurfaceMethods { .. } => "MissingInterfaceSurfaceMethods",
+            IncorrectNumberOfTypeArguments { .. } => "IncorrectNumberOfTypeArguments",
+            DoesNotTakeTypeArguments { .. } => "DoesNotTakeTypeArguments",
+            ConstGenericMismatch { .. } => "ConstGenericMismatch",
+            NeedsTypeArguments { .. } => "NeedsTypeArguments",
+            StructNotFound { .. } => "StructNotFound",
+            EnumNotFound { .. } => "EnumNotFound",
+            DeclaredNonStructAsStruct { .. } => "DeclaredNonStructAsStruct",
+            AccessedFieldOfNonStruct { .. } => "AccessedFieldOfNonStruct",
+            MethodOnNonValue { .. } => "MethodOnNonValue",
+            StructMissingField { .. } => "StructMissingField",
+            StructDoesNotHaveField { .. } => "StructDoesNotHaveField",
+            MethodNotFound { .. } => "MethodNotFound",
+            ModuleNotFound { .. } => "ModuleNotFound",
+            NotAStruct { .. } => "NotAStruct",
+            FieldAccessOnNonStruct { .. } => "FieldAccessOnNonStruct",
+            NotATuple { .. } => "NotATuple",
+            NotAnEnum { .. } => "NotAnEnum",
+            DeclIsNotAnEnum { .. } => "DeclIsNotAnEnum",
+            DeclIsNotAStruct { .. } => "DeclIsNotAStruct",
+            DeclIsNotAFunction { .. } => "DeclIsNotAFunction",
+            DeclIsNotAVariable { .. } => "DeclIsNotAVariable",
+            DeclIsNotAnAbi { .. } => "DeclIsNotAnAbi",
+            FieldNotFound { .. } => "FieldNotFound",
+            SymbolNotFound { .. } => "SymbolNotFound",
+            SymbolNotFoundWithSuggestion { .. } => "SymbolNotFoundWithSuggestion",
+            ImportPrivateSymbol { .. } => "ImportPrivateSymbol",
+            IfExpressionMissingElse { .. } => "IfExpressionMissingElse",
+            ConstantArithmeticOverflow { .. } => "ConstantArithmeticOverflow",
+            SelfTypeNotAllowed { .. } => "SelfTypeNotAllowed",
+            NotAType { .. } => "NotAType",
+            MissingEnumInstantiator { .. } => "MissingEnumInstantiator",
+            PathDoesNotReturn { .. } => "PathDoesNotReturn",
+            ExpectedImplicitReturnFromBlockWithType { .. } => {
+                "ExpectedImplicitReturnFromBlockWithType"
+            }
+            ExpectedImplicitReturnFromBlock { .. } => "ExpectedImplicitReturnFromBlock",
+            UnknownRegister { .. } => "UnknownRegister",
+            MissingImmediate { .. } => "MissingImmediate",
+            InvalidImmediateValue { .. } => "InvalidImmediateValue",
+            InvalidAssemblyMismatchedReturn { .. } => "InvalidAssemblyMismatchedReturn",
+            UnknownEnumVariant { .. } => "UnknownEnumVariant",
+            UnrecognizedOp { .. } => "UnrecognizedOp",
+            UnableToInferGeneric { .. } => "UnableToInferGeneric",
+            Immediate06TooLarge { .. } => "Immediate06TooLarge",
+            Immediate12TooLarge { .. } => "Immediate12TooLarge",
+            Immediate18TooLarge { .. } => "Immediate18TooLarge",
+            Immediate24TooLarge { .. } => "Immediate24TooLarge",
+            DisallowedJi { .. } => "DisallowedJi",
+            DisallowedJnei { .. } => "DisallowedJnei",
+            DisallowedJnzi { .. } => "DisallowedJnzi",
+            DisallowedLw { .. } => "DisallowedLw",
+            IncorrectNumberOfAsmRegisters { .. } => "IncorrectNumberOfAsmRegisters",
+            UnnecessaryImmediate { .. } => "UnnecessaryImmediate",
+            AmbiguousPath { .. } => "AmbiguousPath",
+            InvalidStrType { .. } => "InvalidStrType",
+            UnknownType { .. } => "UnknownType",
+            UnknownTypeName { .. } => "UnknownTypeName",
+            TooManyInstructions { .. } => "TooManyInstructions",
+            FileNotFound { .. } => "FileNotFound",
+            FileCouldNotBeRead { .. } => "FileCouldNotBeRead",
+            ImportMustBeLibrary { .. } => "ImportMustBeLibrary",
+            MoreThanOneEnumInstantiator { .. } => "MoreThanOneEnumInstantiator",
+            UnnecessaryEnumInstantiator { .. } => "UnnecessaryEnumInstantiator",
+            TraitNotFound { .. } => "TraitNotFound",
+            InvalidExpressionOnLhs { .. } => "InvalidExpressionOnLhs",
+            TooManyArgumentsForFunction { .. } => "TooManyArgumentsForFunction",
+            TooFewArgumentsForFunction { .. } => "TooFewArgumentsForFunction",
+            InvalidAbiType { .. } => "InvalidAbiType",
+            NotAnAbi { .. } => "NotAnAbi",
+            ImplAbiForNonContract { .. } => "ImplAbiForNonContract",
+            IncorrectNumberOfInterfaceSurfaceFunctionParameters { .. } => {
+                "IncorrectNumberOfInterfaceSurfaceFunctionParameters"
+            }
+            ArgumentTypeMismatch { .. } => "ArgumentTypeMismatch",
+            RecursiveCall { .. } => "RecursiveCall",
+            RecursiveCallChain { .. } => "RecursiveCallChain",
+            RecursiveType { .. } => "RecursiveType",
+            RecursiveTypeChain { .. } => "RecursiveTypeChain",
+            TypeWithUnknownSize { .. } => "TypeWithUnknownSize",
+            InfiniteDependencies { .. } => "InfiniteDependencies",
+            GMFromExternalContract { .. } => "GMFromExternalContract",
+            MintFromExternalContext { .. } => "MintFromExternalContext",
+            BurnFromExternalContext { .. } => "BurnFromExternalContext",
+            ContractStorageFromExternalContext { .. } => "ContractStorageFromExternalContext",
+            ArrayOutOfBounds { .. } => "ArrayOutOfBounds",
+            ArrayElementTypeMismatch { .. } => "ArrayElementTypeMismatch",
+            CannotInferEmptyArrayType { .. } => "CannotInferEmptyArrayType",
+            TupleIndexOutOfBounds { .. } => "TupleIndexOutOfBounds",
+            ShadowsOtherSymbol { .. } => "ShadowsOtherSymbol",
+            GenericShadowsGeneric { .. } => "GenericShadowsGeneric",
+            AmbiguousImport { .. } => "AmbiguousImport",
+            MatchWrongType { .. } => "MatchWrongType",
+            MatchExpressionNonExhaustive { .. } => "MatchExpressionNonExhaustive",
+            StorageAccessMismatch { .. } => "StorageAccessMismatch",
+            TraitDeclPureImplImpure { .. } => "TraitDeclPureImplImpure",
+            TraitImplPurityMismatch { .. } => "TraitImplPurityMismatch",
+            ImpureInNonContract { .. } => "ImpureInNonContract",
+            ImpureInPureContext { .. } => "ImpureInPureContext",
+            StorageAccessInPureFunction { .. } => "StorageAccessInPureFunction",
+            StorageWriteInReadOnlyFunction { .. } => "StorageWriteInReadOnlyFunction",
+            IntegerTooLarge { .. } => "IntegerTooLarge",
+            IntegerTooSmall { .. } => "IntegerTooSmall",
+            IntegerContainsInvalidDigit { .. } => "IntegerContainsInvalidDigit",
+            AsteriskWithAlias { .. } => "AsteriskWithAlias",
+            AbiAsSupertrait { .. } => "AbiAsSupertrait",
+            SupertraitImplMissing { .. } => "SupertraitImplMissing",
+            SupertraitImplRequired { .. } => "SupertraitImplRequired",
+            IfLetNonEnum { .. } => "IfLetNonEnum",
+            IfLetPatternMismatch { .. } => "IfLetPatternMismatch",
+            IntrinsicArgumentTypeMismatch { .. } => "IntrinsicArgumentTypeMismatch",
+            InvalidCast { .. } => "InvalidCast",
+            ContractCallParamRepeated { .. } => "ContractCallParamRepeated",
+            UnrecognizedContractParam { .. } => "UnrecognizedContractParam",
+            CallParamForNonContractCallMethod { .. } => "CallParamForNonContractCallMethod",
+            StorageFieldDoesNotExist { .. } => "StorageFieldDoesNotExist",
+            NoDeclaredStorage { .. } => "NoDeclaredStorage",
+            MultipleStorageDeclarations { .. } => "MultipleStorageDeclarations",
+            InvalidVariableName { .. } => "InvalidVariableName",
+            UnexpectedDeclaration { .. } => "UnexpectedDeclaration",
+            ContractAddressMustBeKnown { .. } => "ContractAddressMustBeKnown",
+            ConvertParseTree { .. } => "ConvertParseTree",
+            Lex { .. } => "Lex",
+            Parse { .. } => "Parse",
+            WhereClauseNotYetSupported { .. } => "WhereClauseNotYetSupported",
+            NonConstantDeclValue { .. } => "NonConstantDeclValue",
+            StorageDeclarationInNonContract { .. } => "StorageDeclarationInNonContract",
+            BreakOutsideLoop { .. } => "BreakOutsideLoop",
+            ContinueOutsideLoop { .. } => "ContinueOutsideLoop",
+            UnknownLoopLabel { .. } => "UnknownLoopLabel",
+            MatchGuardNotBoolean { .. } => "MatchGuardNotBoolean",
+            ContractCallInPureFunction { .. } => "ContractCallInPureFunction",
+            InvalidTestSignature { .. } => "InvalidTestSignature",
+        }
+    }
+
     pub fn path(&self) -> Option<Arc<PathBuf>> {
         self.span().path().cloned()
     }
@@ -1148,13 +1516,93 @@ impl CompileError {
     }
 }
 
+/// Whether a [Diagnostic] is fatal to compilation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A [CompileError] or [CompileWarning] flattened into plain data, for tools which consume
+/// diagnostics in a machine readable format such as JSON.
+#[derive(Clone, Debug, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// The name of the error or warning variant, e.g. `UnknownVariable`.
+    pub code: String,
+    pub message: String,
+    pub path: Option<PathBuf>,
+    pub start: LineCol,
+    pub end: LineCol,
+}
+
+impl From<&CompileError> for Diagnostic {
+    fn from(error: &CompileError) -> Self {
+        let (start, end) = error.line_col();
+        Diagnostic {
+            severity: Severity::Error,
+            code: error.code().to_string(),
+            message: error.to_string(),
+            path: error.path().map(|path| path.to_path_buf()),
+            start,
+            end,
+        }
+    }
+}
+
+impl From<&CompileWarning> for Diagnostic {
+    fn from(warning: &CompileWarning) -> Self {
+        let (start, end) = warning.line_col();
+        Diagnostic {
+            severity: Severity::Warning,
+            code: warning.warning_content.code().to_string(),
+            message: warning.to_friendly_warning_string(),
+            path: warning.path().map(|path| path.to_path_buf()),
+            start,
+            end,
+        }
+    }
+}
+
+fn symbol_not_found_help(
+    searched: &[crate::semantic_analysis::namespace::PathBuf],
+    suggestion: &Option<Ident>,
+) -> String {
+    let searched = searched
+        .iter()
+        .map(|path| {
+            if path.is_empty() {
+                "the root module".to_string()
+            } else {
+                path.iter()
+                    .map(|ident| ident.as_str())
+                    .collect::<Vec<_>>()
+                    .join("::")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    match suggestion {
+        Some(suggestion) => format!("Did you mean \"{}\"? Searched: {}.", suggestion, searched),
+        None => format!("Searched: {}.", searched),
+    }
+}
+
+fn field_not_found_help(available_fields: &str, did_you_mean: &Option<String>) -> String {
+    match did_you_mean {
+        Some(field) => format!("Did you mean \"{}\"?", field),
+        None => format!("Available fields are:\n {}", available_fields),
+    }
+}
+
 #[derive(Error, Debug, Clone, PartialEq, Hash)]
 pub enum TypeError {
     #[error(
         "Mismatched types.\n\
          expected: {expected}\n\
          found:    {received}.\n\
-         {help}", expected=look_up_type_id(*expected).to_string(), received=look_up_type_id(*received).to_string(), help=if !help_text.is_empty() { format!("help: {}", help_text) } else { String::new() }
+         {help}", expected=type_id_to_string(*expected), received=type_id_to_string(*received), help=if !help_text.is_empty() { format!("help: {}", help_text) } else { String::new() }
     )]
     MismatchedType {
         expected: TypeId,
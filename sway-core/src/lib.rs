@@ -13,6 +13,8 @@ pub mod parse_tree;
 pub mod semantic_analysis;
 pub mod source_map;
 mod style;
+#[cfg(test)]
+mod test_helpers;
 pub mod type_engine;
 
 use crate::{error::*, source_map::SourceMap};
@@ -251,10 +253,16 @@ pub fn compile_to_ast(
     warnings.append(&mut cfa_res.warnings);
     errors = dedup_unsorted(errors);
     warnings = dedup_unsorted(warnings);
-    if !errors.is_empty() {
+    let warnings_denied =
+        matches!(build_config, Some(config) if config.deny_warnings) && !warnings.is_empty();
+    if !errors.is_empty() || warnings_denied {
         return CompileAstResult::Failure { errors, warnings };
     }
 
+    if matches!(build_config, Some(config) if config.print_typed_ast) {
+        tracing::info!("{}", typed_program.pretty_print());
+    }
+
     CompileAstResult::Success {
         typed_program: Box::new(typed_program),
         warnings,
@@ -293,7 +301,7 @@ pub fn ast_to_asm(ast_res: CompileAstResult, build_config: &BuildConfig) -> Comp
                         warnings,
                         errors
                     );
-                    if !errors.is_empty() {
+                    if !errors.is_empty() || (build_config.deny_warnings && !warnings.is_empty()) {
                         return CompilationResult::Failure { errors, warnings };
                     }
                     CompilationResult::Success { asm, warnings }
@@ -725,3 +733,39 @@ fn dedup_unsorted<T: PartialEq + std::hash::Hash>(mut data: Vec<T>) -> Vec<T> {
     data.truncate(write_index);
     data
 }
+
+#[test]
+fn test_deny_warnings_promotes_warnings_to_failure() {
+    let src = r#"
+    script;
+
+    fn spin() -> u64 {
+        while true {}
+    }
+
+    fn main() {}
+    "#;
+    let compile = |deny_warnings| {
+        let build_config = BuildConfig::root_from_file_name_and_manifest_path(
+            PathBuf::from("/project/src/main.sw"),
+            PathBuf::from("/project"),
+        )
+        .deny_warnings(deny_warnings);
+        compile_to_ast(
+            src.into(),
+            namespace::Module::default(),
+            Some(&build_config),
+        )
+    };
+    match compile(false) {
+        CompileAstResult::Success { warnings, .. } => assert!(!warnings.is_empty()),
+        CompileAstResult::Failure { errors, .. } => panic!("{:?}", errors),
+    }
+    match compile(true) {
+        CompileAstResult::Success { .. } => panic!("warnings should fail the build"),
+        CompileAstResult::Failure { errors, warnings } => {
+            assert!(errors.is_empty(), "{:?}", errors);
+            assert!(!warnings.is_empty());
+        }
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
     parse_tree::{promote_purity, AsmOp, AsmRegister, LazyOp, Literal, Purity, Visibility},
     semantic_analysis::{ast_node::*, *},
     type_engine::*,
+    types::DeterministicallyAborts,
 };
 use fuel_crypto::Hasher;
 use std::{collections::HashMap, sync::Arc};
@@ -455,6 +456,9 @@ struct FnCompiler {
     function: Function,
     current_block: Block,
     lexical_map: LexicalMap,
+    // The label, the condition block and, once a `break` needs it, the end block of each
+    // enclosing loop.
+    loop_blocks: Vec<(Option<Ident>, Block, Option<Block>)>,
 }
 
 pub enum StateAccessType {
@@ -474,6 +478,7 @@ impl FnCompiler {
             function,
             current_block: function.get_entry_block(context),
             lexical_map,
+            loop_blocks: Vec::new(),
         }
     }
 
@@ -601,6 +606,12 @@ impl FnCompiler {
                     TypedAstNodeContent::WhileLoop(twl) => {
                         self.compile_while_loop(context, twl, span_md_idx)
                     }
+                    TypedAstNodeContent::Break(label) => {
+                        self.compile_break(context, label, ast_node.span, span_md_idx)
+                    }
+                    TypedAstNodeContent::Continue(label) => {
+                        self.compile_continue(context, label, ast_node.span, span_md_idx)
+                    }
                     // a side effect can be () because it just impacts the type system/namespacing.
                     // There should be no new IR generated.
                     TypedAstNodeContent::SideEffect => Ok(Constant::get_unit(context, None)),
@@ -635,6 +646,7 @@ impl FnCompiler {
                 function_body_purity,
                 self_state_idx,
                 selector,
+                ..
             } => {
                 if let Some(metadata) = selector {
                     self.compile_contract_call(
@@ -654,6 +666,7 @@ impl FnCompiler {
                         function_body_name_span,
                         function_body_purity,
                         self_state_idx,
+                        ast_expr.return_type,
                         span_md_idx,
                     )
                 }
@@ -737,7 +750,7 @@ impl FnCompiler {
                 self.compile_storage_access(context, &access.fields, &access.ix, span_md_idx)
             }
             TypedExpressionVariant::IntrinsicFunction(kind) => {
-                self.compile_intrinsic_function(context, kind, ast_expr.span)
+                self.compile_intrinsic_function(context, kind, ast_expr.return_type, ast_expr.span)
             }
             TypedExpressionVariant::AbiName(_) => {
                 Ok(Value::new_constant(context, Constant::new_unit(), None))
@@ -746,6 +759,9 @@ impl FnCompiler {
                 self.compile_unsafe_downcast(context, exp, variant)
             }
             TypedExpressionVariant::EnumTag { exp } => self.compile_enum_tag(context, exp),
+            TypedExpressionVariant::Cast { exp, cast_to } => {
+                self.compile_cast(context, exp, cast_to, ast_expr.span)
+            }
         }
     }
 
@@ -755,6 +771,7 @@ impl FnCompiler {
         &mut self,
         context: &mut Context,
         kind: TypedIntrinsicFunctionKind,
+        return_type: TypeId,
         span: Span,
     ) -> Result<Value, CompileError> {
         match kind {
@@ -789,6 +806,46 @@ impl FnCompiler {
                     .ins(context)
                     .get_storage_key(span_md_idx, None))
             }
+            TypedIntrinsicFunctionKind::Revert { code } => {
+                let code_value = self.compile_expression(context, *code)?;
+                let span_md_idx = MetadataIndex::from_span(context, &span);
+                // The block can never finish, but its result is typed as the function's return
+                // type so that it can terminate the block just like a return would.
+                let ret_type = self.function.get_return_type(context);
+                let revert_value = self.current_block.ins(context).asm_block(
+                    vec![AsmArg {
+                        name: Ident::new_no_span("code"),
+                        initializer: Some(code_value),
+                    }],
+                    vec![AsmInstruction {
+                        name: Ident::new_no_span("rvrt"),
+                        args: vec![Ident::new_no_span("code")],
+                        immediate: None,
+                        span_md_idx,
+                    }],
+                    ret_type,
+                    Some(Ident::new_no_span("zero")),
+                    span_md_idx,
+                );
+                self.current_block
+                    .ins(context)
+                    .ret(revert_value, ret_type, span_md_idx);
+                // As with return statements, anything added after this is dead code.
+                self.current_block = self.function.create_block(context, None);
+                // The value is never observed, but it must have the type the expression was
+                // unified with, e.g. so that it can merge with the other branch of an `if`.
+                match convert_resolved_typeid(context, &return_type, &span) {
+                    Ok(ty) => Ok(self.current_block.ins(context).asm_block(
+                        vec![],
+                        vec![],
+                        ty,
+                        Some(Ident::new_no_span("zero")),
+                        span_md_idx,
+                    )),
+                    // Nothing constrained the type, so the value can't be used anywhere.
+                    Err(_) => Ok(Constant::get_unit(context, span_md_idx)),
+                }
+            }
         }
     }
 
@@ -1086,6 +1143,7 @@ impl FnCompiler {
         callee_span: Span,
         callee_purity: Purity,
         self_state_idx: Option<StateIndex>,
+        call_return_type: TypeId,
         span_md_idx: Option<MetadataIndex>,
     ) -> Result<Value, CompileError> {
         // XXX OK, now, the old compiler inlines everything very lazily.  Function calls include
@@ -1118,13 +1176,19 @@ impl FnCompiler {
                 })
                 .collect();
 
-            // We're going to have to reverse engineer the return type.
-            let return_type = Self::get_codeblock_return_type(&callee_body).unwrap_or_else(||
+            // We're going to have to reverse engineer the return type.  A body which always aborts
+            // (e.g., by calling `__revert`) says nothing about it though, so for those we trust
+            // the type of the call itself.
+            let return_type = if callee_body.deterministically_aborts() {
+                call_return_type
+            } else {
+                Self::get_codeblock_return_type(&callee_body).unwrap_or_else(||
                     // This code block is missing a return or implicit return.  The only time I've
                     // seen it happen (whether it's 'valid' or not) is in std::storage::store(),
                     // which has a single asm block which also returns nothing.  In this case, it
                     // actually is Unit.
-                    insert_type(TypeInfo::Tuple(Vec::new())));
+                    insert_type(TypeInfo::Tuple(Vec::new())))
+            };
 
             let callee_fn_decl = TypedFunctionDeclaration {
                 name: callee_ident,
@@ -1137,6 +1201,7 @@ impl FnCompiler {
                 visibility: Visibility::Private,
                 is_contract_call: false,
                 purity: callee_purity,
+                is_test: false,
             };
 
             let callee = compile_function(context, self.module, callee_fn_decl)?;
@@ -1291,6 +1356,63 @@ impl FnCompiler {
 
     // ---------------------------------------------------------------------------------------------
 
+    fn compile_cast(
+        &mut self,
+        context: &mut Context,
+        exp: Box<TypedExpression>,
+        cast_to: IntegerBits,
+        span: Span,
+    ) -> Result<Value, CompileError> {
+        let from_bits = match resolve_type(exp.return_type, &exp.span) {
+            Ok(TypeInfo::UnsignedInteger(from_bits)) => from_bits,
+            _ => {
+                return Err(CompileError::Internal(
+                    "Expected integer type here.",
+                    exp.span,
+                ))
+            }
+        };
+        let value = self.compile_expression(context, *exp)?;
+        // All integers are `u64` in the IR, so widening is a no-op and narrowing only needs to
+        // clear the upper bits.
+        if cast_to.max_value() >= from_bits.max_value() {
+            return Ok(value);
+        }
+        let span_md_idx = MetadataIndex::from_span(context, &span);
+        let mask = Constant::get_uint(context, 64, cast_to.max_value(), span_md_idx);
+        Ok(self.current_block.ins(context).asm_block(
+            vec![
+                AsmArg {
+                    name: Ident::new_no_span("value"),
+                    initializer: Some(value),
+                },
+                AsmArg {
+                    name: Ident::new_no_span("mask"),
+                    initializer: Some(mask),
+                },
+                AsmArg {
+                    name: Ident::new_no_span("result"),
+                    initializer: None,
+                },
+            ],
+            vec![AsmInstruction {
+                name: Ident::new_no_span("and"),
+                args: vec![
+                    Ident::new_no_span("result"),
+                    Ident::new_no_span("value"),
+                    Ident::new_no_span("mask"),
+                ],
+                immediate: None,
+                span_md_idx,
+            }],
+            Type::Uint(64),
+            Some(Ident::new_no_span("result")),
+            span_md_idx,
+        ))
+    }
+
+    // ---------------------------------------------------------------------------------------------
+
     fn compile_while_loop(
         &mut self,
         context: &mut Context,
@@ -1312,15 +1434,23 @@ impl FnCompiler {
             .function
             .create_block(context, Some("while_body".into()));
         self.current_block = body_block;
-        self.compile_code_block(context, ast_while_loop.body)?;
+        self.loop_blocks
+            .push((ast_while_loop.label, cond_block, None));
+        let body_res = self.compile_code_block(context, ast_while_loop.body);
+        let (_, _, end_block) = self.loop_blocks.pop().unwrap();
+        body_res?;
         self.current_block
             .ins(context)
             .branch(cond_block, None, None);
 
-        // Create the final block after we're finished with the body.
-        let final_block = self
-            .function
-            .create_block(context, Some("end_while".into()));
+        // Create the final block after we're finished with the body, unless a `break` has
+        // already needed it.
+        let final_block = match end_block {
+            Some(end_block) => end_block,
+            None => self
+                .function
+                .create_block(context, Some("end_while".into())),
+        };
 
         // Add the conditional which jumps into the body or out to the final block.
         self.current_block = cond_block;
@@ -1339,6 +1469,68 @@ impl FnCompiler {
 
     // ---------------------------------------------------------------------------------------------
 
+    // The position in `loop_blocks` of the loop named by `label`, or of the innermost loop.
+    fn find_loop(&self, label: &Option<Ident>) -> Option<usize> {
+        match label {
+            Some(label) => self
+                .loop_blocks
+                .iter()
+                .rposition(|(loop_label, ..)| loop_label.as_ref() == Some(label)),
+            None => self.loop_blocks.len().checked_sub(1),
+        }
+    }
+
+    fn compile_break(
+        &mut self,
+        context: &mut Context,
+        label: Option<Ident>,
+        span: Span,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let position = match (self.find_loop(&label), label) {
+            (Some(position), _) => position,
+            (None, Some(label)) => return Err(CompileError::UnknownLoopLabel { label }),
+            (None, None) => return Err(CompileError::BreakOutsideLoop { span }),
+        };
+        let function = self.function;
+        let end_block = *self.loop_blocks[position]
+            .2
+            .get_or_insert_with(|| function.create_block(context, Some("end_while".into())));
+        self.compile_loop_exit(context, end_block, span_md_idx)
+    }
+
+    fn compile_continue(
+        &mut self,
+        context: &mut Context,
+        label: Option<Ident>,
+        span: Span,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let position = match (self.find_loop(&label), label) {
+            (Some(position), _) => position,
+            (None, Some(label)) => return Err(CompileError::UnknownLoopLabel { label }),
+            (None, None) => return Err(CompileError::ContinueOutsideLoop { span }),
+        };
+        let cond_block = self.loop_blocks[position].1;
+        self.compile_loop_exit(context, cond_block, span_md_idx)
+    }
+
+    fn compile_loop_exit(
+        &mut self,
+        context: &mut Context,
+        target_block: Block,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        self.current_block
+            .ins(context)
+            .branch(target_block, None, span_md_idx);
+        // Like RET, the branch is a terminator so anything following it goes in a new, dead block.
+        self.current_block = self.function.create_block(context, None);
+        Ok(Constant::get_unit(context, span_md_idx))
+    }
+
+    // ---------------------------------------------------------------------------------------------
+
     fn compile_var_expr(
         &mut self,
         context: &mut Context,
@@ -2711,6 +2903,8 @@ fn convert_resolved_type(
         TypeInfo::Ref(..) => reject_type!("Ref"),
         TypeInfo::ErrorRecovery => reject_type!("Error recovery"),
         TypeInfo::Storage { .. } => reject_type!("Storage"),
+        TypeInfo::GenericLengthArray(..) => reject_type!("Generic length array"),
+        TypeInfo::ConstGenericValue(..) => reject_type!("Const generic value"),
     })
 }
 
@@ -2982,6 +3176,8 @@ mod tests {
             print_intermediate_asm: false,
             print_finalized_asm: false,
             print_ir: false,
+            print_typed_ast: false,
+            deny_warnings: false,
         };
         let mut warnings = vec![];
         let mut errors = vec![];
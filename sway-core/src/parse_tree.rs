@@ -21,7 +21,7 @@ pub use literal::Literal;
 pub use module::{DepName, ParseModule, ParseSubmodule};
 pub use program::{ParseProgram, TreeType};
 pub use return_statement::*;
-use sway_types::span::Span;
+use sway_types::{span::Span, Ident};
 pub use use_statement::{ImportType, UseStatement};
 pub use visibility::Visibility;
 pub use while_loop::WhileLoop;
@@ -68,6 +68,12 @@ pub enum AstNodeContent {
     /// A control flow element which loops continually until some boolean expression evaluates as
     /// `false`.
     WhileLoop(WhileLoop),
+    /// A statement of the form `break;` or `break 'label;`, which exits the innermost enclosing
+    /// loop or the enclosing loop with the given label.
+    Break(Option<Ident>),
+    /// A statement of the form `continue;` or `continue 'label;`, which skips to the next
+    /// iteration of the innermost enclosing loop or the enclosing loop with the given label.
+    Continue(Option<Ident>),
     /// A statement of the form `dep foo::bar;` which imports/includes another source file.
     IncludeStatement(IncludeStatement),
 }
@@ -16,6 +16,7 @@ pub struct FunctionDeclaration {
     pub return_type: TypeInfo,
     pub(crate) type_parameters: Vec<TypeParameter>,
     pub(crate) return_type_span: Span,
+    pub is_test: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
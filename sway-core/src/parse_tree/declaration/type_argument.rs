@@ -41,7 +41,7 @@ impl Default for TypeArgument {
 
 impl fmt::Display for TypeArgument {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", look_up_type_id(self.type_id))
+        write!(f, "{}", type_id_to_string(self.type_id))
     }
 }
 
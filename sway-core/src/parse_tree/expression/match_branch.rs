@@ -5,6 +5,8 @@ use super::{Expression, Scrutinee};
 #[derive(Debug, Clone)]
 pub struct MatchBranch {
     pub scrutinee: Scrutinee,
+    /// The condition of an `if` guard, which must also hold for the branch to be taken.
+    pub guard: Option<Expression>,
     pub result: Expression,
     pub(crate) span: span::Span,
 }
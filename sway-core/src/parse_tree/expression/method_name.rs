@@ -20,6 +20,15 @@ pub enum MethodName {
     /// used for things like core::ops::add(a, b).
     /// in this case, the first argument determines the type to look for
     FromTrait { call_path: CallPath },
+    /// something like <Foo as Bar>::baz()
+    /// in this case, the method is looked up only among those which `as_trait` implements for
+    /// `type_name`
+    FromQualifiedPathRoot {
+        type_name: TypeInfo,
+        type_name_span: Span,
+        as_trait: CallPath,
+        method_name: Ident,
+    },
 }
 
 impl MethodName {
@@ -29,7 +38,8 @@ impl MethodName {
             MethodName::FromType { call_path, .. } | MethodName::FromTrait { call_path, .. } => {
                 call_path.suffix.clone()
             }
-            MethodName::FromModule { method_name, .. } => method_name.clone(),
+            MethodName::FromModule { method_name, .. }
+            | MethodName::FromQualifiedPathRoot { method_name, .. } => method_name.clone(),
         }
     }
 }
@@ -68,6 +68,15 @@ pub enum Expression {
         r#else: Option<Box<Expression>>,
         span: Span,
     },
+    /// An `if let` expression, which runs `then` with the variables bound by `scrutinee` if it
+    /// matches `expr`, and `else` otherwise.
+    IfLet {
+        scrutinee: Scrutinee,
+        expr: Box<Expression>,
+        then: Box<Expression>,
+        r#else: Option<Box<Expression>>,
+        span: Span,
+    },
     MatchExp {
         value: Box<Expression>,
         branches: Vec<MatchBranch>,
@@ -128,6 +137,15 @@ pub enum Expression {
         address: Box<Expression>,
         span: Span,
     },
+    /// A numeric cast of the form:
+    /// ```ignore
+    /// <expr> as <type>
+    /// ```
+    Cast {
+        expr: Box<Expression>,
+        type_arg: TypeArgument,
+        span: Span,
+    },
     ArrayIndex {
         prefix: Box<Expression>,
         index: Box<Expression>,
@@ -170,12 +188,14 @@ impl Spanned for Expression {
             StructExpression { span, .. } => span,
             CodeBlock { span, .. } => span,
             IfExp { span, .. } => span,
+            IfLet { span, .. } => span,
             MatchExp { span, .. } => span,
             AsmExpression { span, .. } => span,
             MethodApplication { span, .. } => span,
             SubfieldExpression { span, .. } => span,
             DelineatedPath { span, .. } => span,
             AbiCast { span, .. } => span,
+            Cast { span, .. } => span,
             ArrayIndex { span, .. } => span,
             StorageAccess { span, .. } => span,
             IntrinsicFunction { span, .. } => span,
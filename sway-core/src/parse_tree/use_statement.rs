@@ -1,3 +1,4 @@
+use crate::parse_tree::Visibility;
 use sway_types::ident::Ident;
 
 #[derive(Debug, Clone)]
@@ -16,4 +17,6 @@ pub struct UseStatement {
     // the project root namespace. If not, then it is relative to the current namespace.
     pub(crate) is_absolute: bool,
     pub(crate) alias: Option<Ident>,
+    /// A `pub use` re-exports the imported items from the current module.
+    pub(crate) visibility: Visibility,
 }
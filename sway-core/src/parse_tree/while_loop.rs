@@ -1,8 +1,13 @@
 use crate::{CodeBlock, Expression};
 
+use sway_types::Ident;
+
 /// A parsed while loop. Contains the `condition`, which is defined from an [Expression], and the `body` from a [CodeBlock].
 #[derive(Debug, Clone)]
 pub struct WhileLoop {
     pub condition: Expression,
     pub body: CodeBlock,
+    /// The name given to the loop by a label such as `'outer:`, which `break` and `continue`
+    /// can refer to.
+    pub label: Option<Ident>,
 }
@@ -4,6 +4,7 @@ mod const_eval;
 mod module;
 pub mod namespace;
 mod node_dependencies;
+mod pretty_print;
 mod program;
 pub(crate) mod type_check_arguments;
 pub(crate) use ast_node::*;
@@ -57,6 +57,19 @@ impl TypedCodeBlock {
             })
             .collect::<Vec<TypedAstNode>>();
 
+        // Anything following a node which deterministically aborts can never be reached.
+        if let Some(aborting_ix) = evaluated_contents
+            .iter()
+            .position(|node| node.deterministically_aborts())
+        {
+            warnings.extend(evaluated_contents[aborting_ix + 1..].iter().map(|node| {
+                CompileWarning {
+                    span: node.span.clone(),
+                    warning_content: Warning::UnreachableCode,
+                }
+            }));
+        }
+
         let implicit_return_span = other
             .contents
             .iter()
@@ -68,21 +81,20 @@ impl TypedCodeBlock {
 
         // find the implicit return, if any, and use it as the code block's return type.
         // The fact that there is at most one implicit return is an invariant held by the parser.
-        let return_type = evaluated_contents.iter().find_map(|x| match x {
+        let implicit_return = evaluated_contents.iter().find_map(|x| match x {
             TypedAstNode {
-                content:
-                    TypedAstNodeContent::ImplicitReturnExpression(TypedExpression {
-                        ref return_type,
-                        ..
-                    }),
+                content: TypedAstNodeContent::ImplicitReturnExpression(ref expr),
                 ..
-            } => Some(*return_type),
+            } => Some(expr),
             _ => None,
         });
+        let return_type = implicit_return.map(|expr| expr.return_type);
 
-        if let Some(return_type) = return_type {
+        // An implicit return which always aborts never produces a value, so it satisfies any
+        // annotation.
+        if let Some(expr) = implicit_return.filter(|expr| !expr.deterministically_aborts()) {
             let (mut new_warnings, new_errors) = unify_with_self(
-                return_type,
+                expr.return_type,
                 type_annotation,
                 self_type,
                 &implicit_return_span.unwrap_or_else(|| other.whole_block_span.clone()),
@@ -105,3 +117,49 @@ impl TypedCodeBlock {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_warnings;
+
+    #[test]
+    fn test_unreachable_code_after_return() {
+        let src = r#"
+        script;
+
+        fn main() -> u64 {
+            return 1;
+            let x = 2;
+            x
+        }
+        "#;
+        let warnings = compile_warnings(src);
+        let unreachable_spans = warnings
+            .iter()
+            .filter(|warning| matches!(warning.warning_content, Warning::UnreachableCode))
+            .map(|warning| warning.span.as_str().trim())
+            .collect::<Vec<_>>();
+        assert!(unreachable_spans.contains(&"let x = 2;"));
+        assert!(unreachable_spans.contains(&"x"));
+    }
+
+    #[test]
+    fn test_no_unreachable_code_after_conditional_return() {
+        let src = r#"
+        script;
+
+        fn main() -> u64 {
+            let a = true;
+            if a {
+                return 1;
+            }
+            2
+        }
+        "#;
+        let warnings = compile_warnings(src);
+        assert!(!warnings
+            .iter()
+            .any(|warning| matches!(warning.warning_content, Warning::UnreachableCode)));
+    }
+}
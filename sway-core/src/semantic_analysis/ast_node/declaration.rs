@@ -115,9 +115,7 @@ impl fmt::Display for TypedDeclaration {
                     }
                     builder.push_str(name.as_str());
                     builder.push_str(": ");
-                    builder.push_str(
-                        &crate::type_engine::look_up_type_id(*type_ascription).to_string(),
-                    );
+                    builder.push_str(&crate::type_engine::type_id_to_string(*type_ascription));
                     builder.push_str(" = ");
                     builder.push_str(&body.to_string());
                     builder
@@ -472,6 +470,7 @@ impl TypedTraitFn {
             visibility: Visibility::Public,
             type_parameters: vec![],
             is_contract_call: mode == Mode::ImplAbiFn,
+            is_test: false,
         }
     }
 }
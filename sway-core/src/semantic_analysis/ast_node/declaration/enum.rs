@@ -4,7 +4,8 @@ use crate::{
     parse_tree::*,
     semantic_analysis::*,
     type_engine::{
-        insert_type, look_up_type_id, CopyTypes, CreateTypeId, ReplaceSelfType, TypeId, TypeMapping,
+        insert_type, look_up_type_id, CopyTypes, CreateTypeId, ReplaceSelfType, TypeId,
+        TypeMapping, UnresolvedTypeCheck,
     },
     types::{JsonAbiString, ToJsonAbi},
     TypeInfo,
@@ -45,6 +46,17 @@ impl CopyTypes for TypedEnumDeclaration {
     }
 }
 
+impl UnresolvedTypeCheck for TypedEnumDeclaration {
+    fn check_for_unresolved_types(&self) -> Vec<CompileError> {
+        self.variants
+            .iter()
+            .map(|x| &x.type_id)
+            .chain(self.type_parameters.iter().map(|x| &x.type_id))
+            .flat_map(UnresolvedTypeCheck::check_for_unresolved_types)
+            .collect()
+    }
+}
+
 impl CreateTypeId for TypedEnumDeclaration {
     fn create_type_id(&self) -> TypeId {
         insert_type(TypeInfo::Enum {
@@ -109,6 +121,16 @@ impl TypedEnumDeclaration {
             ));
         }
 
+        // the tags are used as the discriminants, so they must identify the variants by position
+        for (index, variant) in variants.iter().enumerate() {
+            if variant.tag != index {
+                errors.push(CompileError::DuplicateEnumTag {
+                    tag: variant.tag,
+                    span: variant.span.clone(),
+                });
+            }
+        }
+
         // type check the variants
         let mut variants_buf = vec![];
         for variant in variants {
@@ -246,3 +268,55 @@ impl TypedEnumVariant {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{assert_copy_types_is_complete, map_to_bool, unknown_generic};
+
+    #[test]
+    fn test_duplicate_enum_tag() {
+        fn variant(name: &'static str, tag: usize) -> EnumVariant {
+            EnumVariant {
+                name: Ident::new_no_span(name),
+                type_info: TypeInfo::Boolean,
+                tag,
+                span: Span::new(name.into(), 0, name.len(), None).unwrap(),
+            }
+        }
+
+        let decl = EnumDeclaration {
+            name: Ident::new_no_span("Color"),
+            type_parameters: vec![],
+            variants: vec![variant("Red", 0), variant("Green", 1), variant("Blue", 1)],
+            span: Span::dummy(),
+            visibility: Visibility::Private,
+        };
+        let mut namespace = Namespace::init_root(namespace::Module::default());
+        let result =
+            TypedEnumDeclaration::type_check(decl, &mut namespace, insert_type(TypeInfo::SelfType));
+        assert!(matches!(
+            &result.errors[..],
+            [CompileError::DuplicateEnumTag { tag: 1, span }] if span.as_str() == "Blue"
+        ));
+    }
+
+    #[test]
+    fn test_copy_types_remaps_enum_declaration() {
+        let (t, e) = (unknown_generic("T"), unknown_generic("E"));
+        let variant = |name: &'static str, type_id, tag| TypedEnumVariant {
+            name: Ident::new_no_span(name),
+            type_id,
+            tag,
+            span: Span::dummy(),
+        };
+        let decl = TypedEnumDeclaration {
+            name: Ident::new_no_span("Result"),
+            type_parameters: vec![t.clone(), e.clone()],
+            variants: vec![variant("Ok", t.type_id, 0), variant("Err", e.type_id, 1)],
+            span: Span::dummy(),
+            visibility: Visibility::Private,
+        };
+        assert_copy_types_is_complete(&decl, &map_to_bool(&[t, e]));
+    }
+}
@@ -23,6 +23,8 @@ pub struct TypedFunctionDeclaration {
     /// whether this function exists in another contract and requires a call to it or not
     pub(crate) is_contract_call: bool,
     pub(crate) purity: Purity,
+    /// whether this function is marked with `#[test]`
+    pub(crate) is_test: bool,
 }
 
 impl From<&TypedFunctionDeclaration> for TypedAstNode {
@@ -50,6 +52,7 @@ impl PartialEq for TypedFunctionDeclaration {
             && self.visibility == other.visibility
             && self.is_contract_call == other.is_contract_call
             && self.purity == other.purity
+            && self.is_test == other.is_test
     }
 }
 
@@ -144,13 +147,15 @@ impl TypedFunctionDeclaration {
             return_type_span,
             visibility,
             purity,
-            ..
+            is_test,
         } = fn_decl;
         is_snake_case(&name).ok(&mut warnings, &mut errors);
         opts.purity = purity;
+        opts.in_loop = false;
 
         // create a namespace for the function
         let mut namespace = namespace.clone();
+        namespace.const_shadowing_mode = ConstShadowingMode::Sequential;
 
         // type check the type parameters
         // insert them into the namespace
@@ -220,6 +225,22 @@ impl TypedFunctionDeclaration {
             .map(|TypedReturnStatement { expr, .. }| expr)
             .collect();
 
+        // a body which aborts on every path without a single `return` can never produce a value
+        let declared_type = look_up_type_id(return_type);
+        if return_statements.is_empty()
+            && body.deterministically_aborts()
+            && !declared_type.is_unit()
+            && !declared_type.is_uninhabited()
+            && declared_type != TypeInfo::ErrorRecovery
+        {
+            warnings.push(CompileWarning {
+                span: return_type_span.clone(),
+                warning_content: Warning::FunctionNeverReturns {
+                    declared_type: return_type,
+                },
+            });
+        }
+
         // unify the types of the return statements with the function return type
         for stmt in return_statements {
             let (mut new_warnings, new_errors) = unify_with_self(
@@ -233,6 +254,18 @@ impl TypedFunctionDeclaration {
             errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
         }
 
+        // a test takes no arguments and returns either unit or a `Result`
+        if is_test {
+            let returns_unit_or_result = match look_up_type_id(return_type) {
+                TypeInfo::Enum { name, .. } => name.as_str() == "Result",
+                TypeInfo::ErrorRecovery => true,
+                other => other.is_unit(),
+            };
+            if !new_parameters.is_empty() || !returns_unit_or_result {
+                errors.push(CompileError::InvalidTestSignature { span: name.span() });
+            }
+        }
+
         let function_decl = TypedFunctionDeclaration {
             name,
             body,
@@ -245,6 +278,7 @@ impl TypedFunctionDeclaration {
             // if this is for a contract, then it is a contract call
             is_contract_call: mode == Mode::ImplAbiFn,
             purity,
+            is_test,
         };
 
         ok(function_decl, warnings, errors)
@@ -262,6 +296,18 @@ impl TypedFunctionDeclaration {
         }
     }
 
+    /// Whether `other` has the same name, parameter types and return type as this function.
+    pub(crate) fn has_same_signature(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.parameters.len() == other.parameters.len()
+            && self
+                .parameters
+                .iter()
+                .zip(other.parameters.iter())
+                .all(|(a, b)| look_up_type_id(a.type_id) == look_up_type_id(b.type_id))
+            && look_up_type_id(self.return_type) == look_up_type_id(other.return_type)
+    }
+
     pub(crate) fn replace_self_types(self, self_type: TypeId) -> Self {
         TypedFunctionDeclaration {
             parameters: self
@@ -330,9 +376,10 @@ impl TypedFunctionDeclaration {
                 |TypedFunctionParameter {
                      type_id, type_span, ..
                  }| {
-                    resolve_type(*type_id, type_span)
-                        .expect("unreachable I think?")
-                        .to_selector_name(type_span)
+                    match resolve_type(*type_id, type_span) {
+                        Ok(type_info) => type_info.to_selector_name(type_span),
+                        Err(error) => err(vec![], vec![error.into()]),
+                    }
                 },
             )
             .filter_map(|name| name.ok(&mut warnings, &mut errors))
@@ -360,6 +407,7 @@ fn test_function_selector_behavior() {
         return_type_span: Span::dummy(),
         visibility: Visibility::Public,
         is_contract_call: false,
+        is_test: false,
     };
 
     let selector_text = match decl.to_selector_name().value {
@@ -391,6 +439,7 @@ fn test_function_selector_behavior() {
         return_type_span: Span::dummy(),
         visibility: Visibility::Public,
         is_contract_call: false,
+        is_test: false,
     };
 
     let selector_text = match decl.to_selector_name().value {
@@ -400,3 +449,30 @@ fn test_function_selector_behavior() {
 
     assert_eq!(selector_text, "bar(str[5],u32)".to_string());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_errors;
+
+    #[test]
+    fn test_test_function_with_parameter() {
+        let src = r#"
+        script;
+
+        #[test]
+        fn takes_an_argument(x: u64) {}
+
+        fn main() {}
+        "#;
+        let errors = compile_errors(src);
+        assert!(
+            matches!(
+                &errors[..],
+                [CompileError::InvalidTestSignature { span }] if span.as_str() == "takes_an_argument"
+            ),
+            "{:?}",
+            errors
+        );
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
         TypedVariableDeclaration, VariableMutability,
     },
     type_engine::*,
-    CompileResult, FunctionParameter, Ident, Namespace, TypedDeclaration,
+    CompileError, CompileResult, FunctionParameter, Ident, Namespace, TypedDeclaration,
 };
 
 use sway_types::{span::Span, Spanned};
@@ -32,6 +32,12 @@ impl CopyTypes for TypedFunctionParameter {
     }
 }
 
+impl UnresolvedTypeCheck for TypedFunctionParameter {
+    fn check_for_unresolved_types(&self) -> Vec<CompileError> {
+        self.type_id.check_for_unresolved_types()
+    }
+}
+
 impl TypedFunctionParameter {
     pub(crate) fn type_check(
         parameter: FunctionParameter,
@@ -74,3 +80,20 @@ impl TypedFunctionParameter {
         ok(parameter, warnings, errors)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{assert_copy_types_is_complete, map_to_bool, unknown_generic};
+
+    #[test]
+    fn test_copy_types_remaps_function_parameter() {
+        let t = unknown_generic("T");
+        let parameter = TypedFunctionParameter {
+            name: Ident::new_no_span("value"),
+            type_id: t.type_id,
+            type_span: Span::dummy(),
+        };
+        assert_copy_types_is_complete(&parameter, &map_to_bool(&[t]));
+    }
+}
@@ -8,10 +8,10 @@ use crate::{
     },
     CallPath, CompileError, CompileResult, FunctionDeclaration, FunctionParameter, ImplSelf,
     ImplTrait, Namespace, Purity, TypeInfo, TypeParameter, TypedDeclaration,
-    TypedFunctionDeclaration,
+    TypedFunctionDeclaration, Visibility,
 };
 
-use super::TypedTraitFn;
+use super::{TypedFunctionParameter, TypedTraitFn};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TypedImplTrait {
@@ -284,6 +284,9 @@ fn type_check_trait_implementation(
         .iter()
         .map(|decl| (&decl.name, decl))
         .collect();
+    // implementations of methods which the trait already provides a default body for
+    let mut overridden_methods =
+        std::collections::HashMap::<Ident, TypedFunctionDeclaration>::new();
     for fn_decl in functions {
         // replace SelfType with type of implementor
         // i.e. fn add(self, other: u64) -> Self becomes fn
@@ -315,6 +318,11 @@ fn type_check_trait_implementation(
         // remove this function from the "checklist"
         let trait_fn = match function_checklist.remove(&fn_decl.name) {
             Some(trait_fn) => trait_fn,
+            None if methods.iter().any(|method| method.name == fn_decl.name) => {
+                // checked against the default once that has been type checked below
+                overridden_methods.insert(fn_decl.name.clone(), fn_decl);
+                continue;
+            }
             None => {
                 errors.push(CompileError::FunctionNotAPartOfInterfaceSurface {
                     name: fn_decl.name.clone(),
@@ -327,93 +335,35 @@ fn type_check_trait_implementation(
 
         // ensure this fn decl's parameters and signature lines up with the one
         // in the trait
-        let TypedTraitFn {
-            name: _,
-            purity,
-            parameters,
-            return_type,
-            return_type_span: _,
-        } = trait_fn;
-
-        if fn_decl.parameters.len() != parameters.len() {
-            errors.push(
-                CompileError::IncorrectNumberOfInterfaceSurfaceFunctionParameters {
-                    span: fn_decl.parameters_span(),
-                    fn_name: fn_decl.name.clone(),
-                    trait_name: trait_name.suffix.clone(),
-                    num_args: parameters.len(),
-                    provided_args: fn_decl.parameters.len(),
-                },
-            );
-        }
-
-        for (trait_param, fn_decl_param) in parameters.iter().zip(&fn_decl.parameters) {
-            // TODO use trait constraints as part of the type here to
-            // implement trait constraint solver */
-            let fn_decl_param_type = fn_decl_param.type_id;
-            let trait_param_type = trait_param.type_id;
-
-            let (mut new_warnings, new_errors) = unify_with_self(
-                fn_decl_param_type,
-                trait_param_type,
-                self_type_id,
-                &trait_param.type_span,
-                "",
-            );
-
-            warnings.append(&mut new_warnings);
-            if !new_errors.is_empty() {
-                errors.push(CompileError::MismatchedTypeInTrait {
-                    span: fn_decl_param.type_span.clone(),
-                    given: fn_decl_param_type.to_string(),
-                    expected: trait_param_type.to_string(),
-                });
-                break;
-            }
-        }
-
-        if fn_decl.purity != *purity {
-            errors.push(if *purity == Purity::Pure {
-                CompileError::TraitDeclPureImplImpure {
-                    fn_name: fn_decl.name.clone(),
-                    trait_name: trait_name.suffix.clone(),
-                    attrs: fn_decl.purity.to_attribute_syntax(),
-                    span: fn_decl.span.clone(),
-                }
-            } else {
-                CompileError::TraitImplPurityMismatch {
-                    fn_name: fn_decl.name.clone(),
-                    trait_name: trait_name.suffix.clone(),
-                    attrs: purity.to_attribute_syntax(),
-                    span: fn_decl.span.clone(),
-                }
-            });
-        }
-
-        let (mut new_warnings, new_errors) = unify_with_self(
-            *return_type,
-            fn_decl.return_type,
-            self_type_id,
-            &fn_decl.return_type_span,
-            "",
+        check!(
+            check_trait_fn_signature(
+                &fn_decl,
+                &trait_fn.parameters,
+                trait_fn.return_type,
+                trait_fn.purity,
+                trait_name,
+                self_type_id
+            ),
+            continue,
+            warnings,
+            errors
         );
-        warnings.append(&mut new_warnings);
-        if !new_errors.is_empty() {
-            errors.push(CompileError::MismatchedTypeInTrait {
-                span: fn_decl.return_type_span.clone(),
-                expected: return_type.to_string(),
-                given: fn_decl.return_type.to_string(),
-            });
-
-            continue;
-        }
 
         functions_buf.push(fn_decl);
     }
 
-    // This name space is temporary! It is used only so that the below methods
-    // can reference functions from the interface
-    let mut impl_trait_namespace = namespace.clone();
+    let resolved_type_implementing_for =
+        match resolve_type(type_implementing_for, type_implementing_for_span) {
+            Ok(o) => o,
+            Err(e) => {
+                errors.push(e.into());
+                return err(warnings, errors);
+            }
+        };
+
+    // These items are temporary! They are added only so that the below methods can reference
+    // functions from the interface, and are rolled back once the methods are checked.
+    let snapshot = namespace.snapshot();
 
     // A trait impl needs access to everything that the trait methods have access to, which is
     // basically everything in the path where the trait is declared.
@@ -421,24 +371,18 @@ fn type_check_trait_implementation(
     // in the symbols map and the path stored in the CallPath.
     let trait_path = [
         &trait_name.prefixes[..],
-        impl_trait_namespace.get_canonical_path(&trait_name.suffix),
+        namespace.get_canonical_path(&trait_name.suffix),
     ]
     .concat();
-    impl_trait_namespace.star_import(&trait_path);
+    namespace.star_import(&trait_path, Visibility::Private);
 
-    impl_trait_namespace.insert_trait_implementation(
+    namespace.insert_trait_implementation(
         CallPath {
             prefixes: vec![],
             suffix: trait_name.suffix.clone(),
             is_absolute: false,
         },
-        match resolve_type(type_implementing_for, type_implementing_for_span) {
-            Ok(o) => o,
-            Err(e) => {
-                errors.push(e.into());
-                return err(warnings, errors);
-            }
-        },
+        resolved_type_implementing_for,
         functions_buf.clone(),
     );
     for method in methods {
@@ -450,7 +394,7 @@ fn type_check_trait_implementation(
         let method = check!(
             TypedFunctionDeclaration::type_check(TypeCheckArguments {
                 checkee: method.clone(),
-                namespace: &mut impl_trait_namespace,
+                namespace,
                 return_type_annotation: insert_type(TypeInfo::Unknown),
                 help_text: Default::default(),
                 self_type: type_implementing_for,
@@ -462,9 +406,29 @@ fn type_check_trait_implementation(
             errors
         );
         let fn_decl = method.replace_self_types(self_type_id);
-        functions_buf.push(fn_decl);
+        match overridden_methods.remove(&fn_decl.name) {
+            Some(overriding_fn_decl) => {
+                check!(
+                    check_trait_fn_signature(
+                        &overriding_fn_decl,
+                        &fn_decl.parameters,
+                        fn_decl.return_type,
+                        fn_decl.purity,
+                        trait_name,
+                        self_type_id
+                    ),
+                    continue,
+                    warnings,
+                    errors
+                );
+                functions_buf.push(overriding_fn_decl);
+            }
+            None => functions_buf.push(fn_decl),
+        }
     }
 
+    namespace.restore(snapshot);
+
     // check that the implementation checklist is complete
     if !function_checklist.is_empty() {
         errors.push(CompileError::MissingInterfaceSurfaceMethods {
@@ -478,3 +442,218 @@ fn type_check_trait_implementation(
     }
     ok(functions_buf, warnings, errors)
 }
+
+/// Checks that the parameters, return type and purity of `fn_decl` line up with those of the
+/// trait function it implements. Only a mismatched return type is treated as fatal.
+fn check_trait_fn_signature(
+    fn_decl: &TypedFunctionDeclaration,
+    parameters: &[TypedFunctionParameter],
+    return_type: TypeId,
+    purity: Purity,
+    trait_name: &CallPath,
+    self_type_id: TypeId,
+) -> CompileResult<()> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    if fn_decl.parameters.len() != parameters.len() {
+        errors.push(
+            CompileError::IncorrectNumberOfInterfaceSurfaceFunctionParameters {
+                span: fn_decl.parameters_span(),
+                fn_name: fn_decl.name.clone(),
+                trait_name: trait_name.suffix.clone(),
+                num_args: parameters.len(),
+                provided_args: fn_decl.parameters.len(),
+            },
+        );
+    }
+
+    for (trait_param, fn_decl_param) in parameters.iter().zip(&fn_decl.parameters) {
+        // TODO use trait constraints as part of the type here to
+        // implement trait constraint solver */
+        let fn_decl_param_type = fn_decl_param.type_id;
+        let trait_param_type = trait_param.type_id;
+
+        let (mut new_warnings, new_errors) = unify_with_self(
+            fn_decl_param_type,
+            trait_param_type,
+            self_type_id,
+            &trait_param.type_span,
+            "",
+        );
+
+        warnings.append(&mut new_warnings);
+        if !new_errors.is_empty() {
+            errors.push(CompileError::MismatchedTypeInTrait {
+                span: fn_decl_param.type_span.clone(),
+                given: fn_decl_param_type.to_string(),
+                expected: trait_param_type.to_string(),
+            });
+            break;
+        }
+    }
+
+    if fn_decl.purity != purity {
+        errors.push(if purity == Purity::Pure {
+            CompileError::TraitDeclPureImplImpure {
+                fn_name: fn_decl.name.clone(),
+                trait_name: trait_name.suffix.clone(),
+                attrs: fn_decl.purity.to_attribute_syntax(),
+                span: fn_decl.span.clone(),
+            }
+        } else {
+            CompileError::TraitImplPurityMismatch {
+                fn_name: fn_decl.name.clone(),
+                trait_name: trait_name.suffix.clone(),
+                attrs: purity.to_attribute_syntax(),
+                span: fn_decl.span.clone(),
+            }
+        });
+    }
+
+    let (mut new_warnings, new_errors) = unify_with_self(
+        return_type,
+        fn_decl.return_type,
+        self_type_id,
+        &fn_decl.return_type_span,
+        "",
+    );
+    warnings.append(&mut new_warnings);
+    if !new_errors.is_empty() {
+        errors.push(CompileError::MismatchedTypeInTrait {
+            span: fn_decl.return_type_span.clone(),
+            expected: return_type.to_string(),
+            given: fn_decl.return_type.to_string(),
+        });
+        return err(warnings, errors);
+    }
+
+    ok((), warnings, errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_errors;
+
+    fn trait_with_default_method(impl_body: &str) -> String {
+        format!(
+            r#"
+        script;
+
+        struct Foo {{
+            x: u64,
+        }}
+
+        trait Answer {{
+            fn answer(self) -> u64;
+        }} {{
+            fn also_answer(self) -> u64 {{
+                self.answer()
+            }}
+        }}
+
+        impl Answer for Foo {{
+            {}
+        }}
+
+        fn main() -> u64 {{
+            let foo = Foo {{ x: 42 }};
+            foo.also_answer()
+        }}
+        "#,
+            impl_body
+        )
+    }
+
+    #[test]
+    fn test_trait_default_method_is_inherited() {
+        assert!(compile_errors(&trait_with_default_method(
+            "fn answer(self) -> u64 { self.x }"
+        ))
+        .is_empty());
+    }
+
+    #[test]
+    fn test_trait_missing_required_method() {
+        let errors = compile_errors(&trait_with_default_method(""));
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            CompileError::MissingInterfaceSurfaceMethods { missing_functions, .. }
+                if missing_functions == "answer"
+        )));
+    }
+
+    #[test]
+    fn test_trait_default_method_can_be_overridden() {
+        assert!(compile_errors(&trait_with_default_method(
+            "fn answer(self) -> u64 { self.x } fn also_answer(self) -> u64 { 0 }"
+        ))
+        .is_empty());
+    }
+
+    #[test]
+    fn test_trait_default_method_override_signature_mismatch() {
+        let errors = compile_errors(&trait_with_default_method(
+            "fn answer(self) -> u64 { self.x } fn also_answer(self) -> bool { true }",
+        ));
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error, CompileError::MismatchedTypeInTrait { .. })));
+    }
+
+    fn compile_trait_impl(impl_body: &str) -> Vec<CompileError> {
+        let src = format!(
+            r#"
+        script;
+
+        struct Foo {{
+            x: u64,
+        }}
+
+        trait Shape {{
+            fn width(self) -> u64;
+            fn height(self) -> u64;
+        }}
+
+        impl Shape for Foo {{
+            {}
+        }}
+
+        fn main() {{}}
+        "#,
+            impl_body
+        );
+        compile_errors(&src)
+    }
+
+    #[test]
+    fn test_trait_impl_complete() {
+        let errors = compile_trait_impl(
+            "fn width(self) -> u64 { self.x } fn height(self) -> u64 { self.x }",
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_trait_impl_missing_methods() {
+        let errors = compile_trait_impl("");
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            CompileError::MissingInterfaceSurfaceMethods { missing_functions, .. }
+                if missing_functions == "height\nwidth"
+        )));
+    }
+
+    #[test]
+    fn test_trait_impl_extraneous_method() {
+        let errors = compile_trait_impl(
+            "fn width(self) -> u64 { self.x } fn height(self) -> u64 { self.x } fn depth(self) -> u64 { self.x }",
+        );
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            CompileError::FunctionNotAPartOfInterfaceSurface { name, trait_name, .. }
+                if name.as_str() == "depth" && trait_name.as_str() == "Shape"
+        )));
+    }
+}
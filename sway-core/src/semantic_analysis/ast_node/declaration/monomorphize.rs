@@ -2,7 +2,7 @@ use sway_types::{Ident, Span, Spanned};
 
 use crate::{
     error::*, namespace::*, type_engine::*, CompileError, CompileResult, TypeArgument, TypeInfo,
-    TypeParameter,
+    TypeParameter, TypeParameterKind,
 };
 
 use super::CreateTypeId;
@@ -138,9 +138,30 @@ where
                     errors.push(CompileError::IncorrectNumberOfTypeArguments {
                         given: type_arguments.len(),
                         expected: self.type_parameters().len(),
-                        span: type_arguments_span,
+                        span: type_arguments_span.clone(),
                     });
-                    return err(warnings, errors);
+                    // recover by dropping the extra arguments and filling in the missing ones, so
+                    // that only the root error is reported
+                    type_arguments.resize_with(self.type_parameters().len(), || TypeArgument {
+                        type_id: insert_type(TypeInfo::ErrorRecovery),
+                        span: type_arguments_span.clone(),
+                    });
+                }
+                for (type_parameter, type_argument) in
+                    self.type_parameters().iter().zip(type_arguments.iter_mut())
+                {
+                    if let TypeParameterKind::Const(_) = type_parameter.kind {
+                        if !matches!(
+                            look_up_type_id(type_argument.type_id),
+                            TypeInfo::ConstGenericValue(_) | TypeInfo::ErrorRecovery
+                        ) {
+                            errors.push(CompileError::ConstGenericMismatch {
+                                name: type_parameter.name_ident.clone(),
+                                span: type_argument.span.clone(),
+                            });
+                            type_argument.type_id = insert_type(TypeInfo::ErrorRecovery);
+                        }
+                    }
                 }
                 let type_mapping = insert_type_parameters(self.type_parameters());
                 for ((_, interim_type), type_argument) in
@@ -205,3 +226,111 @@ where
     );
     new_decl
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_errors;
+
+    #[test]
+    fn test_const_generic_array_length() {
+        let src = r#"
+        script;
+
+        struct Arr<const N: u64> {
+            items: [u64; N],
+        }
+
+        fn first(a: Arr<3>) -> [u64; 3] {
+            a.items
+        }
+
+        fn main() {}
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_const_generic_array_length_mismatch() {
+        let src = r#"
+        script;
+
+        struct Arr<const N: u64> {
+            items: [u64; N],
+        }
+
+        fn first(a: Arr<3>) -> [u64; 4] {
+            a.items
+        }
+
+        fn main() {}
+        "#;
+        let errors = compile_errors(src);
+        assert!(
+            errors
+                .iter()
+                .any(|error| matches!(error, CompileError::TypeError(..))),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_const_generic_given_a_type() {
+        let src = r#"
+        script;
+
+        struct Arr<const N: u64> {
+            items: [u64; N],
+        }
+
+        fn first(a: Arr<u64>) {}
+
+        fn main() {}
+        "#;
+        let errors = compile_errors(src);
+        assert!(
+            errors.iter().any(|error| matches!(
+                error,
+                CompileError::ConstGenericMismatch { name, .. } if name.as_str() == "N"
+            )),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_incorrect_number_of_type_arguments_reports_single_error() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        struct Foo<T> {
+            x: T,
+        }
+
+        impl<T> Foo<T> {
+            fn consume(self) {}
+        }
+
+        fn main() {
+            let foo: Foo<u64, u64> = Foo { x: 0 };
+            foo.consume();
+        }
+        "#,
+        );
+        assert!(
+            matches!(
+                &errors[..],
+                [CompileError::IncorrectNumberOfTypeArguments {
+                    given: 2,
+                    expected: 1,
+                    span,
+                }] if span.as_str() == "u64, u64"
+            ),
+            "{:?}",
+            errors
+        );
+    }
+}
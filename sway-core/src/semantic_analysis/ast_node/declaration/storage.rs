@@ -4,6 +4,7 @@ use crate::semantic_analysis::{
 use crate::type_engine::look_up_type_id;
 use crate::{
     error::*,
+    style::find_closest_match,
     type_engine::{TypeId, TypeInfo},
     Ident,
 };
@@ -37,6 +38,7 @@ impl TypedStorageDeclaration {
         &self,
         fields: Vec<Ident>,
         storage_fields: &[TypedStorageField],
+        access_span: &Span,
     ) -> CompileResult<(TypeCheckedStorageAccess, TypeId)> {
         let mut errors = vec![];
         let warnings = vec![];
@@ -44,7 +46,16 @@ impl TypedStorageDeclaration {
         let mut type_checked_buf = vec![];
         let mut fields: Vec<_> = fields.into_iter().rev().collect();
 
-        let first_field = fields.pop().expect("guaranteed by grammar");
+        let first_field = match fields.pop() {
+            Some(first_field) => first_field,
+            None => {
+                errors.push(CompileError::Internal(
+                    "storage access without any field names",
+                    access_span.clone(),
+                ));
+                return err(warnings, errors);
+            }
+        };
         let (ix, initial_field_type) = match storage_fields
             .iter()
             .enumerate()
@@ -104,6 +115,8 @@ impl TypedStorageDeclaration {
                     errors.push(CompileError::FieldNotFound {
                         field_name: field.clone(),
                         available_fields: available_fields.join(", "),
+                        did_you_mean: find_closest_match(field.as_str(), available_fields)
+                            .map(str::to_string),
                         struct_name: type_checked_buf.last().unwrap().name.clone(),
                     });
                     return err(warnings, errors);
@@ -1,5 +1,6 @@
 use crate::{
-    error::*, namespace::*, parse_tree::*, semantic_analysis::*, type_engine::*, types::*,
+    error::*, namespace::*, parse_tree::*, semantic_analysis::*, style::find_closest_match,
+    type_engine::*, types::*,
 };
 use fuels_types::Property;
 use std::hash::{Hash, Hasher};
@@ -37,6 +38,17 @@ impl CopyTypes for TypedStructDeclaration {
     }
 }
 
+impl UnresolvedTypeCheck for TypedStructDeclaration {
+    fn check_for_unresolved_types(&self) -> Vec<CompileError> {
+        self.fields
+            .iter()
+            .map(|x| &x.type_id)
+            .chain(self.type_parameters.iter().map(|x| &x.type_id))
+            .flat_map(UnresolvedTypeCheck::check_for_unresolved_types)
+            .collect()
+    }
+}
+
 impl CreateTypeId for TypedStructDeclaration {
     fn create_type_id(&self) -> TypeId {
         insert_type(TypeInfo::Struct {
@@ -134,13 +146,14 @@ impl TypedStructDeclaration {
         {
             Some(field) => ok(field, warnings, errors),
             None => {
+                let field_names = self
+                    .fields
+                    .iter()
+                    .map(|TypedStructField { name, .. }| name.as_str());
                 errors.push(CompileError::FieldNotFound {
-                    available_fields: self
-                        .fields
-                        .iter()
-                        .map(|TypedStructField { name, .. }| name.to_string())
-                        .collect::<Vec<_>>()
-                        .join("\n"),
+                    available_fields: field_names.clone().collect::<Vec<_>>().join("\n"),
+                    did_you_mean: find_closest_match(field_to_access.as_str(), field_names)
+                        .map(str::to_string),
                     field_name: field_to_access.clone(),
                     struct_name: self.name.clone(),
                 });
@@ -227,3 +240,76 @@ impl TypedStructField {
         ok(field, warnings, errors)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{
+        assert_copy_types_is_complete, compile_errors, map_to_bool, unknown_generic,
+    };
+
+    fn field_not_found_in_match(field_to_access: &str) -> CompileError {
+        let src = format!(
+            r#"
+        script;
+
+        struct Point {{
+            my_field: u64,
+            other: u64,
+        }}
+
+        fn main() -> u64 {{
+            let p = Point {{ my_field: 1, other: 2 }};
+            match p {{
+                Point {{ {} }} => 0,
+            }}
+        }}
+        "#,
+            field_to_access
+        );
+        compile_errors(&src)
+            .into_iter()
+            .find(|error| matches!(error, CompileError::FieldNotFound { .. }))
+            .expect("expected a `FieldNotFound` error")
+    }
+
+    #[test]
+    fn test_field_not_found_did_you_mean() {
+        let error = field_not_found_in_match("myField");
+        assert!(matches!(&error,
+                         CompileError::FieldNotFound { did_you_mean: Some(field), .. }
+                         if field == "my_field"));
+        assert!(error.to_string().contains("Did you mean \"my_field\"?"));
+    }
+
+    #[test]
+    fn test_field_not_found_no_close_match() {
+        let error = field_not_found_in_match("unrelated");
+        assert!(matches!(
+            &error,
+            CompileError::FieldNotFound {
+                did_you_mean: None,
+                ..
+            }
+        ));
+        assert!(error.to_string().contains("my_field\nother"));
+    }
+
+    #[test]
+    fn test_copy_types_remaps_struct_declaration() {
+        let (t, u) = (unknown_generic("T"), unknown_generic("U"));
+        let field = |name: &'static str, type_id| TypedStructField {
+            name: Ident::new_no_span(name),
+            type_id,
+            span: Span::dummy(),
+        };
+        let decl = TypedStructDeclaration {
+            name: Ident::new_no_span("Pair"),
+            fields: vec![field("first", t.type_id), field("second", u.type_id)],
+            type_parameters: vec![t.clone(), u.clone()],
+            visibility: Visibility::Private,
+            span: Span::dummy(),
+        };
+        assert_copy_types_is_complete(&decl, &map_to_bool(&[t, u]));
+    }
+}
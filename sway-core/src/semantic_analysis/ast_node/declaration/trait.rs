@@ -57,13 +57,16 @@ impl TypedTraitDeclaration {
             errors
         );
 
-        // A temporary namespace for checking within the trait's scope.
-        let mut namespace = namespace.clone();
+        // Items added while checking within the trait's scope are rolled back afterwards.
+        let snapshot = namespace.snapshot();
 
         // Recursively handle supertraits: make their interfaces and methods available to this trait
         check!(
-            handle_supertraits(&trait_decl.supertraits, &mut namespace),
-            return err(warnings, errors),
+            handle_supertraits(&trait_decl.supertraits, namespace),
+            {
+                namespace.restore(snapshot);
+                return err(warnings, errors);
+            },
             warnings,
             errors
         );
@@ -86,13 +89,14 @@ impl TypedTraitDeclaration {
         let _methods = check!(
             type_check_trait_methods(
                 trait_decl.methods.clone(),
-                &mut namespace,
+                namespace,
                 insert_type(TypeInfo::SelfType),
             ),
             vec![],
             warnings,
             errors
         );
+        namespace.restore(snapshot);
         let typed_trait_decl = TypedTraitDeclaration {
             name: trait_decl.name.clone(),
             interface_surface,
@@ -232,6 +236,7 @@ fn convert_trait_methods_to_dummy_funcs(
                 visibility: Visibility::Public,
                 type_parameters: vec![],
                 is_contract_call: false,
+                is_test: false,
             },
         )
         .collect::<Vec<_>>();
@@ -18,6 +18,7 @@ pub enum TypedIntrinsicFunctionKind {
     SizeOfType { type_id: TypeId, type_span: Span },
     IsRefType { type_id: TypeId, type_span: Span },
     GetStorageKey,
+    Revert { code: Box<TypedExpression> },
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -45,6 +46,7 @@ impl PartialEq for TypedIntrinsicFunctionKind {
                 },
             ) => look_up_type_id(*l_type_id) == look_up_type_id(*r_type_id),
             (GetStorageKey, GetStorageKey) => true,
+            (Revert { code: l_code }, Revert { code: r_code }) => *l_code == *r_code,
             _ => false,
         }
     }
@@ -64,6 +66,9 @@ impl CopyTypes for TypedIntrinsicFunctionKind {
                 type_id.update_type(type_mapping, type_span);
             }
             GetStorageKey => {}
+            Revert { code } => {
+                code.copy_types(type_mapping);
+            }
         }
     }
 }
@@ -76,6 +81,7 @@ impl fmt::Display for TypedIntrinsicFunctionKind {
             SizeOfType { type_id, .. } => format!("size_of({})", look_up_type_id(*type_id)),
             IsRefType { type_id, .. } => format!("is_ref_type({})", look_up_type_id(*type_id)),
             GetStorageKey => "get_storage_key".to_string(),
+            Revert { code } => format!("revert({})", code),
         };
         write!(f, "{}", s)
     }
@@ -87,6 +93,7 @@ impl DeterministicallyAborts for TypedIntrinsicFunctionKind {
         match self {
             SizeOfVal { exp } => exp.deterministically_aborts(),
             SizeOfType { .. } | GetStorageKey | IsRefType { .. } => false,
+            Revert { .. } => true,
         }
     }
 }
@@ -99,11 +106,21 @@ impl UnresolvedTypeCheck for TypedIntrinsicFunctionKind {
             SizeOfType { type_id, .. } => type_id.check_for_unresolved_types(),
             IsRefType { type_id, .. } => type_id.check_for_unresolved_types(),
             GetStorageKey => vec![],
+            Revert { code } => code.check_for_unresolved_types(),
         }
     }
 }
 
 impl TypedIntrinsicFunctionKind {
+    pub(crate) fn gather_contract_calls(&self) -> Vec<&TypedExpression> {
+        use TypedIntrinsicFunctionKind::*;
+        match self {
+            SizeOfVal { exp } => exp.gather_contract_calls(),
+            Revert { code } => code.gather_contract_calls(),
+            SizeOfType { .. } | IsRefType { .. } | GetStorageKey => vec![],
+        }
+    }
+
     pub(crate) fn type_check(
         kind: IntrinsicFunctionKind,
         self_type: TypeId,
@@ -176,7 +193,139 @@ impl TypedIntrinsicFunctionKind {
                 TypedIntrinsicFunctionKind::GetStorageKey,
                 insert_type(TypeInfo::B256),
             ),
+            IntrinsicFunctionKind::Revert { code } => {
+                let code = check!(
+                    TypedExpression::type_check(TypeCheckArguments {
+                        checkee: *code,
+                        namespace,
+                        self_type,
+                        mode: Mode::NonAbi,
+                        opts,
+                        return_type_annotation: insert_type(TypeInfo::Unknown),
+                        help_text: Default::default(),
+                    }),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                let u64_type = insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour));
+                let (mut new_warnings, new_errors) =
+                    unify(code.return_type, u64_type, &code.span, "");
+                warnings.append(&mut new_warnings);
+                if !new_errors.is_empty() {
+                    errors.push(CompileError::IntrinsicArgumentTypeMismatch {
+                        intrinsic: "__revert".into(),
+                        expected: u64_type.to_string(),
+                        found: code.return_type.to_string(),
+                        span: code.span.clone(),
+                    });
+                }
+                // `__revert` never returns, so it may be used wherever a value of any type is
+                // expected, e.g. as one branch of an `if` whose other branch is a `u64`.
+                let intrinsic_function = TypedIntrinsicFunctionKind::Revert {
+                    code: Box::new(code),
+                };
+                (intrinsic_function, insert_type(TypeInfo::Unknown))
+            }
         };
         ok((intrinsic_function, return_type), warnings, errors)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{compile_errors, compile_to_asm_errors, compile_warnings};
+    use crate::Warning;
+
+    #[test]
+    fn test_revert_satisfies_return_type() {
+        let src = r#"
+        script;
+
+        struct Point {
+            x: u64,
+            y: u64,
+        }
+
+        fn fail(code: u64) -> u64 {
+            __revert(code)
+        }
+
+        fn fail_with_point() -> Point {
+            __revert(1)
+        }
+
+        fn main() -> u64 {
+            fail(0)
+        }
+        "#;
+        assert!(compile_errors(src).is_empty());
+    }
+
+    #[test]
+    fn test_revert_in_if_branch_takes_the_other_branch_type() {
+        let src = r#"
+        script;
+
+        fn main() -> u64 {
+            let x = if true { __revert(3) } else { 5 };
+            let y = if false { x } else { __revert(4) };
+            y
+        }
+        "#;
+        let errors = compile_to_asm_errors(src);
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_revert_argument_must_be_u64() {
+        let src = r#"
+        script;
+
+        fn main() -> u64 {
+            __revert(true)
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            CompileError::IntrinsicArgumentTypeMismatch { intrinsic, found, .. }
+                if intrinsic == "__revert" && found == "bool"
+        )));
+    }
+
+    #[test]
+    fn test_code_after_revert_is_unreachable() {
+        let src = r#"
+        script;
+
+        fn main() -> u64 {
+            __revert(1);
+            let x = 5;
+            x
+        }
+        "#;
+        let warnings = compile_warnings(src);
+        assert!(warnings
+            .iter()
+            .any(|warning| matches!(warning.warning_content, Warning::UnreachableCode)));
+    }
+
+    #[test]
+    fn test_revert_statement_has_no_unused_value() {
+        let src = r#"
+        script;
+
+        fn fail() {
+            __revert(1);
+        }
+
+        fn main() {
+            fail();
+        }
+        "#;
+        let warnings = compile_warnings(src);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+}
@@ -173,9 +173,20 @@ impl Pattern {
                 Pattern::Tuple(new_elems)
             }
             Scrutinee::EnumScrutinee {
-                call_path, value, ..
+                call_path,
+                value,
+                span,
             } => {
-                let enum_name = call_path.prefixes.last().unwrap().to_string();
+                let enum_name = match call_path.prefixes.last() {
+                    Some(enum_name) => enum_name.to_string(),
+                    None => {
+                        errors.push(CompileError::Internal(
+                            "enum scrutinee without an enum name",
+                            span,
+                        ));
+                        return err(warnings, errors);
+                    }
+                };
                 let variant_name = call_path.suffix.to_string();
                 Pattern::Enum(EnumPattern {
                     enum_name,
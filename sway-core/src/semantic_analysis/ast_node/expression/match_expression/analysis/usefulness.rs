@@ -195,9 +195,14 @@ use super::{
 /// is an imaginary additional wildcard pattern. The match expression is
 /// exhaustive if the imaginary additional wildcard pattern has an empty
 /// `WitnessReport`.
+///
+/// Each scrutinee is paired with whether its match arm has an `if` guard. A
+/// guarded arm may not be taken even when its pattern matches, so its pattern
+/// is checked for reachability but is never added to *P*, and so can't make
+/// the match expression exhaustive.
 pub(crate) fn check_match_expression_usefulness(
     type_id: TypeId,
-    scrutinees: Vec<Scrutinee>,
+    scrutinees: Vec<(Scrutinee, bool)>,
     span: Span,
 ) -> CompileResult<(WitnessReport, Vec<(Scrutinee, bool)>)> {
     let mut warnings = vec![];
@@ -210,7 +215,7 @@ pub(crate) fn check_match_expression_usefulness(
         warnings,
         errors
     );
-    for scrutinee in scrutinees.iter() {
+    for (scrutinee, is_guarded) in scrutinees.into_iter() {
         let pat = check!(
             Pattern::from_scrutinee(scrutinee.clone()),
             return err(warnings, errors),
@@ -224,9 +229,11 @@ pub(crate) fn check_match_expression_usefulness(
             warnings,
             errors
         );
-        matrix.push(v);
+        if !is_guarded {
+            matrix.push(v);
+        }
         // if an arm has witnesses to its usefulness then it is reachable
-        arms_reachability.push((scrutinee.clone(), witness_report.has_witnesses()));
+        arms_reachability.push((scrutinee, witness_report.has_witnesses()));
     }
     let v = PatStack::from_pattern(Pattern::wild_pattern());
     let witness_report = check!(
@@ -837,3 +844,34 @@ fn compute_specialized_matrix_row(
     }
     ok(rows, warnings, errors)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_errors;
+
+    #[test]
+    fn test_guarded_match_arm_is_not_exhaustive() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        fn main() -> u64 {
+            let p = (true, 42);
+            match p {
+                (flag, n) if flag => n,
+                _ if true => 0,
+            }
+        }
+        "#,
+        );
+        assert!(
+            matches!(
+                &errors[..],
+                [CompileError::MatchExpressionNonExhaustive { .. }]
+            ),
+            "{:?}",
+            errors
+        );
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     },
     type_engine::{insert_type, unify_with_self},
     types::DeterministicallyAborts,
-    CompileResult, MatchBranch, TypeInfo, TypedDeclaration,
+    CompileError, CompileResult, MatchBranch, TypeInfo, TypedDeclaration,
 };
 
 use super::matcher::{matcher, MatchReqMap};
@@ -17,6 +17,7 @@ use super::matcher::{matcher, MatchReqMap};
 #[derive(Debug)]
 pub(crate) struct TypedMatchBranch {
     pub(crate) conditions: MatchReqMap,
+    pub(crate) guard: Option<TypedExpression>,
     pub(crate) result: TypedExpression,
     #[allow(dead_code)]
     span: Span,
@@ -41,6 +42,7 @@ impl TypedMatchBranch {
 
         let MatchBranch {
             scrutinee,
+            guard,
             result,
             span: branch_span,
         } = branch;
@@ -84,6 +86,56 @@ impl TypedMatchBranch {
             });
         }
 
+        // type check the guard in the scope of the variables bound by the scrutinee,
+        // and wrap it in a code block that declares those variables for itself, as
+        // the guard is evaluated before the branch result
+        let typed_guard = match guard {
+            Some(guard) => {
+                let typed_guard = check!(
+                    TypedExpression::type_check(TypeCheckArguments {
+                        checkee: guard,
+                        namespace: &mut namespace,
+                        return_type_annotation: insert_type(TypeInfo::Unknown),
+                        help_text: Default::default(),
+                        self_type,
+                        mode,
+                        opts,
+                    }),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                let (mut new_warnings, new_errors) = unify_with_self(
+                    typed_guard.return_type,
+                    insert_type(TypeInfo::Boolean),
+                    self_type,
+                    &typed_guard.span,
+                    "",
+                );
+                warnings.append(&mut new_warnings);
+                if !new_errors.is_empty() {
+                    errors.push(CompileError::MatchGuardNotBoolean {
+                        span: typed_guard.span.clone(),
+                    });
+                }
+                let guard_span = typed_guard.span.clone();
+                let mut guard_contents = code_block_contents.clone();
+                guard_contents.push(TypedAstNode {
+                    content: TypedAstNodeContent::ImplicitReturnExpression(typed_guard),
+                    span: guard_span.clone(),
+                });
+                Some(TypedExpression {
+                    expression: TypedExpressionVariant::CodeBlock(TypedCodeBlock {
+                        contents: guard_contents,
+                    }),
+                    return_type: insert_type(TypeInfo::Boolean),
+                    is_constant: IsConstant::No,
+                    span: guard_span,
+                })
+            }
+            None => None,
+        };
+
         // type check the branch result
         let typed_result = check!(
             TypedExpression::type_check(TypeCheckArguments {
@@ -154,9 +206,59 @@ impl TypedMatchBranch {
         // return!
         let branch = TypedMatchBranch {
             conditions: match_req_map,
+            guard: typed_guard,
             result: new_result,
             span: branch_span,
         };
         ok(branch, warnings, errors)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_errors;
+
+    #[test]
+    fn test_match_guard_uses_bound_variables() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        fn main() -> u64 {
+            let p = (true, 42);
+            match p {
+                (flag, n) if flag => n,
+                (_, n) if false => n,
+                _ => 0,
+            }
+        }
+        "#,
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_match_guard_not_boolean() {
+        let src = r#"
+        script;
+
+        fn main() -> u64 {
+            let p = (true, 42);
+            match p {
+                (flag, n) if n => n,
+                _ => 0,
+            }
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(
+            matches!(
+                &errors[..],
+                [CompileError::MatchGuardNotBoolean { span }] if span.as_str() == "n"
+            ),
+            "{:?}",
+            errors
+        );
+    }
+}
@@ -88,7 +88,10 @@ impl TypedMatchExpression {
 
         // for every branch of the match expression, in reverse
         for TypedMatchBranch {
-            conditions, result, ..
+            conditions,
+            guard,
+            result,
+            ..
         } in branches.into_iter().rev()
         {
             // create the conditional that will act as the conditional for the if statement, in reverse
@@ -122,6 +125,24 @@ impl TypedMatchExpression {
                 });
             }
 
+            // a guard is checked only once all of the pattern's requirements hold, since
+            // the variables it refers to are only valid if the pattern matched
+            if let Some(guard) = guard {
+                conditional = Some(match conditional {
+                    Some(conditional) => {
+                        let joined_span = Span::join(conditional.span.clone(), guard.span.clone());
+                        instantiate_lazy_operator(
+                            LazyOp::And,
+                            conditional,
+                            guard,
+                            insert_type(TypeInfo::Boolean),
+                            joined_span,
+                        )
+                    }
+                    None => guard,
+                });
+            }
+
             // add to the if expression that we are building using the result component
             // of the match branch and using the conditional that we just built
             let result_span = result.span.clone();
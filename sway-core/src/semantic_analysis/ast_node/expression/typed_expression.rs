@@ -51,6 +51,12 @@ impl CopyTypes for TypedExpression {
     }
 }
 
+impl Spanned for TypedExpression {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
 impl fmt::Display for TypedExpression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -186,6 +192,7 @@ impl UnresolvedTypeCheck for TypedExpression {
                 .into_iter()
                 .chain(variant.type_id.check_for_unresolved_types().into_iter())
                 .collect(),
+            Cast { exp, .. } => exp.check_for_unresolved_types(),
         }
     }
 }
@@ -257,6 +264,7 @@ impl DeterministicallyAborts for TypedExpression {
             AbiName(_) => false,
             EnumTag { exp } => exp.deterministically_aborts(),
             UnsafeDowncast { exp, .. } => exp.deterministically_aborts(),
+            Cast { exp, .. } => exp.deterministically_aborts(),
         }
     }
 }
@@ -346,27 +354,203 @@ impl TypedExpression {
                 }
                 buf
             }
+            TypedExpressionVariant::LazyOperator { lhs, rhs, .. } => {
+                let mut buf = lhs.gather_return_statements();
+                buf.append(&mut rhs.gather_return_statements());
+                buf
+            }
+            TypedExpressionVariant::ArrayIndex { prefix, index } => {
+                let mut buf = prefix.gather_return_statements();
+                buf.append(&mut index.gather_return_statements());
+                buf
+            }
+            TypedExpressionVariant::Tuple { fields: exps }
+            | TypedExpressionVariant::Array { contents: exps } => exps
+                .iter()
+                .flat_map(|exp| exp.gather_return_statements())
+                .collect(),
+            TypedExpressionVariant::StructExpression { fields, .. } => fields
+                .iter()
+                .flat_map(|field| field.value.gather_return_statements())
+                .collect(),
+            // the returns within the body of the called function belong to that function, so
+            // only the arguments are searched here.
+            TypedExpressionVariant::FunctionApplication { arguments, .. } => arguments
+                .iter()
+                .flat_map(|(_, arg)| arg.gather_return_statements())
+                .collect(),
+            TypedExpressionVariant::StructFieldAccess { prefix: exp, .. }
+            | TypedExpressionVariant::TupleElemAccess { prefix: exp, .. }
+            | TypedExpressionVariant::AbiCast { address: exp, .. }
+            | TypedExpressionVariant::EnumTag { exp }
+            | TypedExpressionVariant::UnsafeDowncast { exp, .. }
+            | TypedExpressionVariant::Cast { exp, .. } => exp.gather_return_statements(),
+            TypedExpressionVariant::EnumInstantiation { contents, .. } => contents
+                .iter()
+                .flat_map(|exp| exp.gather_return_statements())
+                .collect(),
             // if it is impossible for an expression to contain a return _statement_ (not an
             // implicit return!), put it in the pattern below.
-            TypedExpressionVariant::LazyOperator { .. }
-            | TypedExpressionVariant::Literal(_)
-            | TypedExpressionVariant::Tuple { .. }
-            | TypedExpressionVariant::Array { .. }
-            | TypedExpressionVariant::ArrayIndex { .. }
+            TypedExpressionVariant::Literal(_)
             | TypedExpressionVariant::FunctionParameter { .. }
             | TypedExpressionVariant::AsmExpression { .. }
-            | TypedExpressionVariant::StructFieldAccess { .. }
-            | TypedExpressionVariant::TupleElemAccess { .. }
-            | TypedExpressionVariant::EnumInstantiation { .. }
-            | TypedExpressionVariant::AbiCast { .. }
             | TypedExpressionVariant::IntrinsicFunction { .. }
-            | TypedExpressionVariant::StructExpression { .. }
             | TypedExpressionVariant::VariableExpression { .. }
             | TypedExpressionVariant::AbiName(_)
-            | TypedExpressionVariant::StorageAccess { .. }
-            | TypedExpressionVariant::FunctionApplication { .. }
-            | TypedExpressionVariant::EnumTag { .. }
-            | TypedExpressionVariant::UnsafeDowncast { .. } => vec![],
+            | TypedExpressionVariant::StorageAccess { .. } => vec![],
+        }
+    }
+
+    /// recurse into `self` and get any `break` or `continue` statements which leave the
+    /// innermost enclosing loop.
+    pub(crate) fn gather_loop_exits(&self) -> Vec<&TypedAstNodeContent> {
+        match &self.expression {
+            TypedExpressionVariant::IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                let mut buf = condition.gather_loop_exits();
+                buf.append(&mut then.gather_loop_exits());
+                if let Some(ref r#else) = r#else {
+                    buf.append(&mut r#else.gather_loop_exits());
+                }
+                buf
+            }
+            TypedExpressionVariant::CodeBlock(TypedCodeBlock { contents, .. }) => contents
+                .iter()
+                .flat_map(|node| node.gather_loop_exits())
+                .collect(),
+            TypedExpressionVariant::LazyOperator {
+                lhs: first,
+                rhs: second,
+                ..
+            }
+            | TypedExpressionVariant::ArrayIndex {
+                prefix: first,
+                index: second,
+            } => {
+                let mut buf = first.gather_loop_exits();
+                buf.append(&mut second.gather_loop_exits());
+                buf
+            }
+            TypedExpressionVariant::Tuple { fields: exps }
+            | TypedExpressionVariant::Array { contents: exps } => exps
+                .iter()
+                .flat_map(|exp| exp.gather_loop_exits())
+                .collect(),
+            TypedExpressionVariant::StructExpression { fields, .. } => fields
+                .iter()
+                .flat_map(|field| field.value.gather_loop_exits())
+                .collect(),
+            // a loop can't be left from within the body of a called function.
+            TypedExpressionVariant::FunctionApplication { arguments, .. } => arguments
+                .iter()
+                .flat_map(|(_, arg)| arg.gather_loop_exits())
+                .collect(),
+            TypedExpressionVariant::StructFieldAccess { prefix: exp, .. }
+            | TypedExpressionVariant::TupleElemAccess { prefix: exp, .. }
+            | TypedExpressionVariant::AbiCast { address: exp, .. }
+            | TypedExpressionVariant::EnumTag { exp }
+            | TypedExpressionVariant::UnsafeDowncast { exp, .. }
+            | TypedExpressionVariant::Cast { exp, .. } => exp.gather_loop_exits(),
+            TypedExpressionVariant::EnumInstantiation { contents, .. } => contents
+                .iter()
+                .flat_map(|exp| exp.gather_loop_exits())
+                .collect(),
+            TypedExpressionVariant::Literal(_)
+            | TypedExpressionVariant::FunctionParameter
+            | TypedExpressionVariant::AsmExpression { .. }
+            | TypedExpressionVariant::IntrinsicFunction { .. }
+            | TypedExpressionVariant::VariableExpression { .. }
+            | TypedExpressionVariant::AbiName(_)
+            | TypedExpressionVariant::StorageAccess { .. } => vec![],
+        }
+    }
+
+    /// recurse into `self` and get any calls to another contract's ABI methods, including
+    /// `self` itself.
+    pub(crate) fn gather_contract_calls(&self) -> Vec<&TypedExpression> {
+        match &self.expression {
+            TypedExpressionVariant::IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                let mut buf = condition.gather_contract_calls();
+                buf.append(&mut then.gather_contract_calls());
+                if let Some(ref r#else) = r#else {
+                    buf.append(&mut r#else.gather_contract_calls());
+                }
+                buf
+            }
+            TypedExpressionVariant::CodeBlock(TypedCodeBlock { contents, .. }) => contents
+                .iter()
+                .flat_map(|node| node.gather_contract_calls())
+                .collect(),
+            TypedExpressionVariant::LazyOperator {
+                lhs: first,
+                rhs: second,
+                ..
+            }
+            | TypedExpressionVariant::ArrayIndex {
+                prefix: first,
+                index: second,
+            } => {
+                let mut buf = first.gather_contract_calls();
+                buf.append(&mut second.gather_contract_calls());
+                buf
+            }
+            TypedExpressionVariant::Tuple { fields: exps }
+            | TypedExpressionVariant::Array { contents: exps } => exps
+                .iter()
+                .flat_map(|exp| exp.gather_contract_calls())
+                .collect(),
+            TypedExpressionVariant::StructExpression { fields, .. } => fields
+                .iter()
+                .flat_map(|field| field.value.gather_contract_calls())
+                .collect(),
+            // the body of a called function is checked against its own declaration.
+            TypedExpressionVariant::FunctionApplication {
+                arguments,
+                contract_call_params,
+                selector,
+                ..
+            } => {
+                let mut buf = if selector.is_some() {
+                    vec![self]
+                } else {
+                    vec![]
+                };
+                buf.extend(
+                    contract_call_params
+                        .values()
+                        .chain(arguments.iter().map(|(_, arg)| arg))
+                        .flat_map(|exp| exp.gather_contract_calls()),
+                );
+                buf
+            }
+            TypedExpressionVariant::StructFieldAccess { prefix: exp, .. }
+            | TypedExpressionVariant::TupleElemAccess { prefix: exp, .. }
+            | TypedExpressionVariant::AbiCast { address: exp, .. }
+            | TypedExpressionVariant::EnumTag { exp }
+            | TypedExpressionVariant::UnsafeDowncast { exp, .. }
+            | TypedExpressionVariant::Cast { exp, .. } => exp.gather_contract_calls(),
+            TypedExpressionVariant::EnumInstantiation { contents, .. } => contents
+                .iter()
+                .flat_map(|exp| exp.gather_contract_calls())
+                .collect(),
+            TypedExpressionVariant::AsmExpression { registers, .. } => registers
+                .iter()
+                .filter_map(|register| register.initializer.as_ref())
+                .flat_map(|exp| exp.gather_contract_calls())
+                .collect(),
+            TypedExpressionVariant::IntrinsicFunction(kind) => kind.gather_contract_calls(),
+            TypedExpressionVariant::Literal(_)
+            | TypedExpressionVariant::FunctionParameter
+            | TypedExpressionVariant::VariableExpression { .. }
+            | TypedExpressionVariant::AbiName(_)
+            | TypedExpressionVariant::StorageAccess { .. } => vec![],
         }
     }
 
@@ -444,6 +628,24 @@ impl TypedExpression {
                 },
                 span,
             ),
+            Expression::IfLet {
+                scrutinee,
+                expr,
+                then,
+                r#else,
+                span,
+            } => Self::type_check_if_let_expression(
+                TypeCheckArguments {
+                    checkee: (scrutinee, *expr, *then, r#else.map(|x| *x)),
+                    return_type_annotation: type_annotation,
+                    namespace,
+                    self_type,
+                    mode: Mode::NonAbi,
+                    help_text: Default::default(),
+                    opts,
+                },
+                span,
+            ),
             Expression::MatchExp {
                 value,
                 branches,
@@ -503,6 +705,7 @@ impl TypedExpression {
                 span,
                 namespace,
                 self_type,
+                type_annotation,
                 opts,
             ),
             Expression::Tuple { fields, span } => {
@@ -535,8 +738,13 @@ impl TypedExpression {
                 address,
                 span,
             } => Self::type_check_abi_cast(abi_name, address, span, namespace, self_type, opts),
+            Expression::Cast {
+                expr,
+                type_arg,
+                span,
+            } => Self::type_check_cast(*expr, type_arg, span, namespace, self_type, opts),
             Expression::Array { contents, span } => {
-                Self::type_check_array(contents, span, namespace, self_type, opts)
+                Self::type_check_array(contents, span, namespace, type_annotation, self_type, opts)
             }
             Expression::ArrayIndex {
                 prefix,
@@ -591,18 +799,25 @@ impl TypedExpression {
             errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
         }
 
-        // The annotation may result in a cast, which is handled in the type engine.
-        typed_expression.return_type = check!(
-            namespace.resolve_type_with_self(
-                look_up_type_id(typed_expression.return_type),
-                self_type,
-                &expr_span,
-                EnforceTypeArguments::No
-            ),
-            insert_type(TypeInfo::ErrorRecovery),
-            warnings,
-            errors,
-        );
+        // The annotation may result in a cast, which is handled in the type engine. A type that is
+        // still unknown, e.g. that of `__revert`, has nothing to resolve yet, and a copy of it
+        // wouldn't learn what it's later unified with.
+        if !matches!(
+            look_up_type_id(typed_expression.return_type),
+            TypeInfo::Unknown
+        ) {
+            typed_expression.return_type = check!(
+                namespace.resolve_type_with_self(
+                    look_up_type_id(typed_expression.return_type),
+                    self_type,
+                    &expr_span,
+                    EnforceTypeArguments::No
+                ),
+                insert_type(TypeInfo::ErrorRecovery),
+                warnings,
+                errors,
+            );
+        }
 
         // Literals of type Numeric can now be resolved if typed_expression.return_type is
         // an UnsignedInteger or a Numeric
@@ -935,6 +1150,142 @@ impl TypedExpression {
             warnings,
             errors
         );
+        let typed_match_expression = check!(
+            Self::type_check_match_branches(
+                TypeCheckArguments {
+                    checkee: (typed_value, branches),
+                    namespace,
+                    return_type_annotation,
+                    help_text: Default::default(),
+                    self_type,
+                    mode: Mode::NonAbi,
+                    opts,
+                },
+                span
+            ),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        ok(typed_match_expression, warnings, errors)
+    }
+
+    fn type_check_if_let_expression(
+        arguments: TypeCheckArguments<'_, (Scrutinee, Expression, Expression, Option<Expression>)>,
+        span: Span,
+    ) -> CompileResult<TypedExpression> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let TypeCheckArguments {
+            checkee: (scrutinee, expr, then, r#else),
+            namespace,
+            return_type_annotation,
+            self_type,
+            opts,
+            ..
+        } = arguments;
+
+        // type check the value being destructured
+        let typed_value = check!(
+            TypedExpression::type_check(TypeCheckArguments {
+                checkee: expr.clone(),
+                namespace,
+                return_type_annotation: insert_type(TypeInfo::Unknown),
+                help_text: Default::default(),
+                self_type,
+                mode: Mode::NonAbi,
+                opts,
+            }),
+            error_recovery_expr(expr.span()),
+            warnings,
+            errors
+        );
+
+        // an enum pattern can only ever match values of that enum
+        if let Scrutinee::EnumScrutinee {
+            call_path,
+            span: pattern_span,
+            ..
+        } = &scrutinee
+        {
+            let value_type = look_up_type_id(typed_value.return_type);
+            let is_match = match (&value_type, call_path.prefixes.last()) {
+                (TypeInfo::Enum { name, .. }, Some(enum_name)) => name == enum_name,
+                (TypeInfo::Enum { .. }, None) | (TypeInfo::ErrorRecovery, _) => true,
+                _ => false,
+            };
+            if !is_match {
+                errors.push(CompileError::IfLetPatternMismatch {
+                    pattern: call_path.to_string(),
+                    type_name: value_type.to_string(),
+                    span: pattern_span.clone(),
+                });
+                return err(warnings, errors);
+            }
+        }
+
+        // the pattern's variables are only bound in the first branch, and without an `else` the
+        // second branch is an empty block, so that the whole expression is unit
+        let then_span = then.span();
+        let else_block = r#else.unwrap_or_else(|| Expression::CodeBlock {
+            contents: CodeBlock {
+                contents: vec![],
+                whole_block_span: span.clone(),
+            },
+            span: span.clone(),
+        });
+        let else_span = else_block.span();
+        let branches = vec![
+            MatchBranch {
+                span: Span::join(scrutinee.span(), then_span),
+                scrutinee,
+                guard: None,
+                result: then,
+            },
+            MatchBranch {
+                scrutinee: Scrutinee::CatchAll {
+                    span: else_span.clone(),
+                },
+                guard: None,
+                result: else_block,
+                span: else_span,
+            },
+        ];
+
+        let typed_if_exp = check!(
+            Self::type_check_match_branches(
+                TypeCheckArguments {
+                    checkee: (typed_value, branches),
+                    namespace,
+                    return_type_annotation,
+                    help_text: Default::default(),
+                    self_type,
+                    mode: Mode::NonAbi,
+                    opts,
+                },
+                span
+            ),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        ok(typed_if_exp, warnings, errors)
+    }
+
+    fn type_check_match_branches(
+        arguments: TypeCheckArguments<'_, (TypedExpression, Vec<MatchBranch>)>,
+        span: Span,
+    ) -> CompileResult<TypedExpression> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let TypeCheckArguments {
+            checkee: (typed_value, branches),
+            namespace,
+            return_type_annotation,
+            self_type,
+            opts,
+            ..
+        } = arguments;
         let type_id = typed_value.return_type;
 
         let _ = check!(
@@ -946,7 +1297,7 @@ impl TypedExpression {
 
         let scrutinees = branches
             .iter()
-            .map(|branch| branch.scrutinee.clone())
+            .map(|branch| (branch.scrutinee.clone(), branch.guard.is_some()))
             .collect::<Vec<_>>();
 
         // type check the match expression and create a TypedMatchExpression object
@@ -1113,6 +1464,8 @@ impl TypedExpression {
         .clone();
 
         // monomorphize the struct definition
+        let type_parameters = struct_decl.type_parameters.clone();
+        let has_type_arguments = !type_arguments.is_empty();
         let mut struct_decl = check!(
             namespace.monomorphize(
                 struct_decl,
@@ -1126,6 +1479,12 @@ impl TypedExpression {
             errors
         );
 
+        let type_mapping = if has_type_arguments {
+            vec![]
+        } else {
+            monomorphized_type_mapping(type_parameters, &struct_decl.type_parameters)
+        };
+
         // match up the names with their type annotations from the declaration
         let mut typed_fields_buf = vec![];
         for def_field in struct_decl.fields.iter_mut() {
@@ -1151,11 +1510,20 @@ impl TypedExpression {
                     }
                 };
 
+            // The length of an array whose length is a const parameter is only known once the
+            // field has been given a value, so the parameter is bound after type checking it.
+            let generic_length = match look_up_type_id(def_field.type_id) {
+                TypeInfo::GenericLengthArray(elem_type_id, length) => Some((elem_type_id, length)),
+                _ => None,
+            };
             let typed_field = check!(
                 TypedExpression::type_check(TypeCheckArguments {
                     checkee: expr_field.value,
                     namespace,
-                    return_type_annotation: def_field.type_id,
+                    return_type_annotation: match generic_length {
+                        Some(_) => insert_type(TypeInfo::Unknown),
+                        None => def_field.type_id,
+                    },
                     help_text: "Struct field's type must match up with the type specified in its \
                      declaration.",
                     self_type,
@@ -1166,6 +1534,20 @@ impl TypedExpression {
                 warnings,
                 errors
             );
+            if let Some((elem_type_id, length)) = generic_length {
+                def_field.type_id = check!(
+                    bind_const_generic_length(
+                        &struct_decl.type_parameters,
+                        elem_type_id,
+                        &length,
+                        &typed_field,
+                        def_field.type_id,
+                    ),
+                    continue,
+                    warnings,
+                    errors
+                );
+            }
 
             def_field.span = typed_field.span.clone();
             typed_fields_buf.push(TypedStructExpressionField {
@@ -1188,6 +1570,7 @@ impl TypedExpression {
             expression: TypedExpressionVariant::StructExpression {
                 struct_name: call_path.suffix,
                 fields: typed_fields_buf,
+                type_mapping,
             },
             return_type: struct_decl.create_type_id(),
             is_constant: IsConstant::No,
@@ -1297,7 +1680,10 @@ impl TypedExpression {
         span: &Span,
     ) -> CompileResult<TypedExpression> {
         let TypeCheckArguments {
-            checkee, namespace, ..
+            checkee,
+            namespace,
+            opts,
+            ..
         } = arguments;
 
         let mut warnings = vec![];
@@ -1306,6 +1692,9 @@ impl TypedExpression {
             errors.push(CompileError::NoDeclaredStorage { span: span.clone() });
             return err(warnings, errors);
         }
+        if opts.purity == Purity::Pure {
+            errors.push(CompileError::StorageAccessInPureFunction { span: span.clone() });
+        }
 
         let storage_fields = check!(
             namespace.get_storage_field_descriptors(),
@@ -1316,7 +1705,7 @@ impl TypedExpression {
 
         // Do all namespace checking here!
         let (storage_access, return_type) = check!(
-            namespace.apply_storage_load(checkee, &storage_fields),
+            namespace.apply_storage_load(checkee, &storage_fields, span),
             return err(warnings, errors),
             warnings,
             errors
@@ -1396,7 +1785,16 @@ impl TypedExpression {
             .is_some();
 
         // Check if the call path refers to an enum in another module.
-        let (enum_name, enum_mod_path) = call_path.prefixes.split_last().expect("empty call path");
+        let (enum_name, enum_mod_path) = match call_path.prefixes.split_last() {
+            Some(split) => split,
+            None => {
+                errors.push(CompileError::Internal(
+                    "delineated path without a prefix",
+                    span,
+                ));
+                return err(warnings, errors);
+            }
+        };
         let abs_enum_mod_path: Vec<_> = namespace.find_module_path(enum_mod_path);
         let exp = if let Some(enum_decl) = namespace
             .check_submodule_mut(enum_mod_path)
@@ -1641,21 +2039,133 @@ impl TypedExpression {
         ok(exp, warnings, errors)
     }
 
+    fn type_check_cast(
+        expr: Expression,
+        type_arg: TypeArgument,
+        span: Span,
+        namespace: &mut Namespace,
+        self_type: TypeId,
+        opts: TCOpts,
+    ) -> CompileResult<TypedExpression> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        let err_span = expr.span();
+        let typed_expr = check!(
+            TypedExpression::type_check(TypeCheckArguments {
+                checkee: expr,
+                namespace,
+                return_type_annotation: insert_type(TypeInfo::Unknown),
+                help_text: Default::default(),
+                self_type,
+                mode: Mode::NonAbi,
+                opts,
+            }),
+            error_recovery_expr(err_span),
+            warnings,
+            errors
+        );
+        let cast_to_type_id = check!(
+            namespace.resolve_type_with_self(
+                look_up_type_id(type_arg.type_id),
+                self_type,
+                &type_arg.span,
+                EnforceTypeArguments::Yes
+            ),
+            insert_type(TypeInfo::ErrorRecovery),
+            warnings,
+            errors,
+        );
+
+        let from_type = look_up_type_id(typed_expr.return_type);
+        let to_type = look_up_type_id(cast_to_type_id);
+        let cast_to = match (&from_type, &to_type) {
+            (TypeInfo::ErrorRecovery, _) | (_, TypeInfo::ErrorRecovery) => {
+                return err(warnings, errors);
+            }
+            (TypeInfo::UnsignedInteger(_), TypeInfo::UnsignedInteger(cast_to)) => *cast_to,
+            (TypeInfo::Numeric, TypeInfo::UnsignedInteger(cast_to)) => {
+                // an untyped integer literal being cast is treated as a `u64`
+                let (mut new_warnings, new_errors) = unify(
+                    typed_expr.return_type,
+                    insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+                    &typed_expr.span,
+                    "",
+                );
+                warnings.append(&mut new_warnings);
+                errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
+                *cast_to
+            }
+            _ => {
+                errors.push(CompileError::InvalidCast {
+                    from: from_type.to_string(),
+                    to: to_type.to_string(),
+                    span,
+                });
+                return err(warnings, errors);
+            }
+        };
+
+        // a constant operand which does not fit in the target type is silently truncated at
+        // runtime, so let the user know about it now
+        if typed_expr.is_constant == IsConstant::Yes {
+            let constant_value = match &typed_expr.expression {
+                TypedExpressionVariant::Literal(Literal::U8(value)) => Some(*value as u64),
+                TypedExpressionVariant::Literal(Literal::U16(value)) => Some(*value as u64),
+                TypedExpressionVariant::Literal(Literal::U32(value)) => Some(*value as u64),
+                TypedExpressionVariant::Literal(Literal::U64(value))
+                | TypedExpressionVariant::Literal(Literal::Numeric(value)) => Some(*value),
+                _ => None,
+            };
+            if let Some(value) = constant_value {
+                if value > cast_to.max_value() {
+                    warnings.push(CompileWarning {
+                        span: span.clone(),
+                        warning_content: Warning::CastTruncatesConstant {
+                            from: value,
+                            to: cast_to,
+                        },
+                    });
+                }
+            }
+        }
+
+        let exp = TypedExpression {
+            is_constant: typed_expr.is_constant,
+            expression: TypedExpressionVariant::Cast {
+                exp: Box::new(typed_expr),
+                cast_to,
+            },
+            return_type: cast_to_type_id,
+            span,
+        };
+        ok(exp, warnings, errors)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn type_check_array(
         contents: Vec<Expression>,
         span: Span,
         namespace: &mut Namespace,
+        type_annotation: TypeId,
         self_type: TypeId,
         opts: TCOpts,
     ) -> CompileResult<TypedExpression> {
+        // An empty array has no elements to infer its type from, so it must come from the
+        // annotation.
         if contents.is_empty() {
+            let elem_type = match look_up_type_id(type_annotation) {
+                TypeInfo::Array(elem_type, _) => elem_type,
+                _ => {
+                    let errors = vec![CompileError::CannotInferEmptyArrayType { span }];
+                    return err(Vec::new(), errors);
+                }
+            };
             return ok(
                 TypedExpression {
                     expression: TypedExpressionVariant::Array {
                         contents: Vec::new(),
                     },
-                    return_type: insert_type(TypeInfo::Array(insert_type(TypeInfo::Unknown), 0)),
+                    return_type: insert_type(TypeInfo::Array(elem_type, 0)),
                     is_constant: IsConstant::Yes,
                     span,
                 },
@@ -1688,7 +2198,7 @@ impl TypedExpression {
             .collect();
 
         let elem_type = typed_contents[0].return_type;
-        for typed_elem in &typed_contents[1..] {
+        for (index, typed_elem) in typed_contents.iter().enumerate().skip(1) {
             let (mut new_warnings, new_errors) = unify_with_self(
                 typed_elem.return_type,
                 elem_type,
@@ -1696,13 +2206,16 @@ impl TypedExpression {
                 &typed_elem.span,
                 "",
             );
-            let no_warnings = new_warnings.is_empty();
-            let no_errors = new_errors.is_empty();
             warnings.append(&mut new_warnings);
-            errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
-            // In both cases, if there are warnings or errors then break here, since we don't
-            // need to spam type errors for every element once we have one.
-            if !no_warnings && !no_errors {
+            // Only report the first mismatched element, since we don't need to spam type errors
+            // for every element once we have one.
+            if !new_errors.is_empty() {
+                errors.push(CompileError::ArrayElementTypeMismatch {
+                    expected: elem_type,
+                    found: typed_elem.return_type,
+                    index,
+                    span: typed_elem.span.clone(),
+                });
                 break;
             }
         }
@@ -1752,7 +2265,7 @@ impl TypedExpression {
         );
 
         // If the return type is a static array then create a TypedArrayIndex.
-        if let TypeInfo::Array(elem_type_id, _) = look_up_type_id(prefix_te.return_type) {
+        if let TypeInfo::Array(elem_type_id, count) = look_up_type_id(prefix_te.return_type) {
             let index_te = check!(
                 TypedExpression::type_check(TypeCheckArguments {
                     checkee: index,
@@ -1770,6 +2283,20 @@ impl TypedExpression {
                 errors
             );
 
+            // If the index is a literal then we can check it against the array length now rather
+            // than waiting for it to fail at runtime.
+            if let TypedExpressionVariant::Literal(Literal::U64(index)) = index_te.expression {
+                let count = count as u64;
+                if index >= count {
+                    errors.push(CompileError::ArrayOutOfBounds {
+                        index,
+                        count,
+                        span: index_te.span,
+                    });
+                    return err(warnings, errors);
+                }
+            }
+
             ok(
                 TypedExpression {
                     expression: TypedExpressionVariant::ArrayIndex {
@@ -1803,6 +2330,7 @@ impl TypedExpression {
                 span,
                 namespace,
                 self_type,
+                insert_type(TypeInfo::Unknown),
                 opts,
             )
         }
@@ -1918,9 +2446,55 @@ impl TypedExpression {
     }
 }
 
+/// Binds the const parameter `length` of a struct field declared as `[elem; length]` to the
+/// length of the array `value` given to the field, and returns the field's resulting array type.
+fn bind_const_generic_length(
+    type_parameters: &[TypeParameter],
+    elem_type_id: TypeId,
+    length: &Ident,
+    value: &TypedExpression,
+    field_type_id: TypeId,
+) -> CompileResult<TypeId> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    let help_text = "Struct field's type must match up with the type specified in its declaration.";
+    let count = match look_up_type_id(value.return_type) {
+        TypeInfo::Array(_, count) => count,
+        _ => {
+            let (mut new_warnings, new_errors) =
+                unify(value.return_type, field_type_id, &value.span, help_text);
+            warnings.append(&mut new_warnings);
+            errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
+            return err(warnings, errors);
+        }
+    };
+    let array_type_id = insert_type(TypeInfo::Array(elem_type_id, count));
+    let (mut new_warnings, new_errors) =
+        unify(value.return_type, array_type_id, &value.span, help_text);
+    warnings.append(&mut new_warnings);
+    errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
+    if let Some(type_parameter) = type_parameters
+        .iter()
+        .find(|type_parameter| type_parameter.name_ident == *length)
+    {
+        let (mut new_warnings, new_errors) = unify(
+            insert_type(TypeInfo::ConstGenericValue(count as u64)),
+            type_parameter.type_id,
+            &value.span,
+            "Array length must match the other uses of the const parameter.",
+        );
+        warnings.append(&mut new_warnings);
+        errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
+    }
+    ok(array_type_id, warnings, errors)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::{compile, compile_errors, compile_warnings};
+    use crate::CompileAstResult;
+    use std::sync::Arc;
 
     fn do_type_check(expr: Expression, type_annotation: TypeId) -> CompileResult<TypedExpression> {
         let mut namespace = Namespace::init_root(namespace::Module::default());
@@ -1964,12 +2538,13 @@ mod tests {
         let comp_res = do_type_check_for_boolx2(expr);
         assert!(comp_res.errors.len() == 1);
         assert!(matches!(&comp_res.errors[0],
-                         CompileError::TypeError(TypeError::MismatchedType {
+                         CompileError::ArrayElementTypeMismatch {
                              expected,
-                             received,
+                             found,
+                             index: 1,
                              ..
-                         }) if expected.to_string() == "bool"
-                                && received.to_string() == "u64"));
+                         } if expected.to_string() == "bool"
+                                && found.to_string() == "u64"));
     }
 
     #[test]
@@ -1992,12 +2567,13 @@ mod tests {
         let comp_res = do_type_check_for_boolx2(expr);
         assert!(comp_res.errors.len() == 2);
         assert!(matches!(&comp_res.errors[0],
-                         CompileError::TypeError(TypeError::MismatchedType {
+                         CompileError::ArrayElementTypeMismatch {
                              expected,
-                             received,
+                             found,
+                             index: 1,
                              ..
-                         }) if expected.to_string() == "u64"
-                                && received.to_string() == "bool"));
+                         } if expected.to_string() == "u64"
+                                && found.to_string() == "bool"));
         assert!(matches!(&comp_res.errors[1],
                          CompileError::TypeError(TypeError::MismatchedType {
                              expected,
@@ -2039,6 +2615,103 @@ mod tests {
                                 && received.to_string() == "[bool; 3]"));
     }
 
+    #[test]
+    fn test_array_type_check_infers_element_type() {
+        // [true, false, true] -- no annotation, infers [bool; 3].
+        let expr = Expression::Array {
+            contents: vec![
+                Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::dummy(),
+                },
+                Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::dummy(),
+                },
+                Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::dummy(),
+                },
+            ],
+            span: Span::dummy(),
+        };
+
+        let comp_res = do_type_check(expr, insert_type(TypeInfo::Unknown));
+        assert!(comp_res.errors.is_empty());
+        assert_eq!(comp_res.value.unwrap().return_type.to_string(), "[bool; 3]");
+    }
+
+    #[test]
+    fn test_array_type_check_empty_requires_annotation() {
+        let expr = || Expression::Array {
+            contents: vec![],
+            span: Span::dummy(),
+        };
+
+        // let x: [u32; 0] = [];
+        let annotation = insert_type(TypeInfo::Array(
+            insert_type(TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)),
+            0,
+        ));
+        let comp_res = do_type_check(expr(), annotation);
+        assert!(comp_res.errors.is_empty());
+        assert_eq!(comp_res.value.unwrap().return_type.to_string(), "[u32; 0]");
+
+        // let x = [];
+        let comp_res = do_type_check(expr(), insert_type(TypeInfo::Unknown));
+        assert!(comp_res.errors.len() == 1);
+        assert!(matches!(
+            &comp_res.errors[0],
+            CompileError::CannotInferEmptyArrayType { .. }
+        ));
+    }
+
+    fn do_type_check_array_index(index: u64) -> CompileResult<TypedExpression> {
+        // [true, false][index]
+        let src: Arc<str> = Arc::from(format!("[true, false][{}]", index).as_str());
+        let span = |start, end| Span::new(src.clone(), start, end, None).unwrap();
+        let expr = Expression::ArrayIndex {
+            prefix: Box::new(Expression::Array {
+                contents: vec![
+                    Expression::Literal {
+                        value: Literal::Boolean(true),
+                        span: span(1, 5),
+                    },
+                    Expression::Literal {
+                        value: Literal::Boolean(false),
+                        span: span(7, 12),
+                    },
+                ],
+                span: span(0, 13),
+            }),
+            index: Box::new(Expression::Literal {
+                value: Literal::U64(index),
+                span: span(14, src.len() - 1),
+            }),
+            span: span(0, src.len()),
+        };
+
+        do_type_check(expr, insert_type(TypeInfo::Boolean))
+    }
+
+    #[test]
+    fn test_array_index_in_bounds() {
+        let comp_res = do_type_check_array_index(1);
+        assert!(comp_res.warnings.is_empty() && comp_res.errors.is_empty());
+    }
+
+    #[test]
+    fn test_array_index_out_of_bounds() {
+        let comp_res = do_type_check_array_index(2);
+        assert!(comp_res.errors.len() == 1);
+        assert!(matches!(&comp_res.errors[0],
+                         CompileError::ArrayOutOfBounds {
+                             index,
+                             count,
+                             span,
+                         } if *index == 2 && *count == 2 && span.as_str() == "2"));
+    }
+
     #[test]
     fn test_array_type_check_empty() {
         let expr = Expression::Array {
@@ -2052,6 +2725,426 @@ mod tests {
         );
         assert!(comp_res.warnings.is_empty() && comp_res.errors.is_empty());
     }
+
+    #[test]
+    fn test_storage_access_without_fields() {
+        // synthetic `storage.` with no field names, which the parser never produces
+        let mut namespace = Namespace::init_root(namespace::Module::default());
+        namespace.set_storage_declaration(TypedStorageDeclaration::new(vec![], Span::dummy()));
+        let comp_res = TypedExpression::type_check(TypeCheckArguments {
+            checkee: Expression::StorageAccess {
+                field_names: vec![],
+                span: Span::dummy(),
+            },
+            namespace: &mut namespace,
+            return_type_annotation: insert_type(TypeInfo::Unknown),
+            help_text: Default::default(),
+            self_type: insert_type(TypeInfo::Unknown),
+            mode: Mode::NonAbi,
+            opts: TCOpts {
+                purity: Purity::Reads,
+                ..Default::default()
+            },
+        });
+        assert!(comp_res.value.is_none());
+        assert!(matches!(
+            comp_res.errors.as_slice(),
+            [CompileError::Internal(..)]
+        ));
+    }
+
+    #[test]
+    fn test_delineated_path_without_prefix() {
+        let expr = Expression::DelineatedPath {
+            call_path: CallPath {
+                prefixes: vec![],
+                suffix: Ident::new_no_span("Variant"),
+                is_absolute: false,
+            },
+            args: vec![],
+            span: Span::dummy(),
+            type_arguments: vec![],
+        };
+        let comp_res = do_type_check(expr, insert_type(TypeInfo::Unknown));
+        assert!(comp_res.value.is_none());
+        assert!(comp_res
+            .errors
+            .iter()
+            .any(|error| matches!(error, CompileError::Internal(..))));
+    }
+
+    fn unreachable_match_arms(arms: &str) -> Vec<String> {
+        let src = format!(
+            r#"
+        script;
+
+        fn main() -> u64 {{
+            let x = 5;
+            match x {{
+                {}
+            }}
+        }}
+        "#,
+            arms
+        );
+        // Literal patterns are desugared to `core::ops::eq`, which isn't available here, but the
+        // arms are checked for reachability before that point.
+        let warnings = match compile(&src) {
+            CompileAstResult::Success { warnings, .. }
+            | CompileAstResult::Failure { warnings, .. } => warnings,
+        };
+        warnings
+            .iter()
+            .filter(|warning| {
+                matches!(
+                    warning.warning_content,
+                    Warning::MatchExpressionUnreachableArm
+                )
+            })
+            .map(|warning| warning.span.as_str().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_unreachable_match_arm_after_wildcard() {
+        let unreachable = unreachable_match_arms("_ => { 0 }, 1 => { 1 },");
+        assert_eq!(unreachable, vec!["1".to_string()]);
+    }
+
+    #[test]
+    fn test_unreachable_duplicate_match_arm() {
+        let unreachable = unreachable_match_arms("1 => { 0 }, 1 => { 1 }, _ => { 2 },");
+        assert_eq!(unreachable, vec!["1".to_string()]);
+    }
+
+    #[test]
+    fn test_distinct_match_arms_are_reachable() {
+        let unreachable = unreachable_match_arms("1 => { 0 }, 2 => { 1 }, _ => { 2 },");
+        assert!(unreachable.is_empty());
+    }
+
+    fn enum_match_missing_patterns(arms: &str) -> Option<String> {
+        let src = format!(
+            r#"
+        script;
+
+        enum Color {{
+            Red: (),
+            Green: (),
+            Blue: (),
+        }}
+
+        fn main() -> u64 {{
+            let c = Color::Green;
+            match c {{
+                {}
+            }}
+        }}
+        "#,
+            arms
+        );
+        compile_errors(&src)
+            .into_iter()
+            .find_map(|error| match error {
+                CompileError::MatchExpressionNonExhaustive {
+                    missing_patterns, ..
+                } => Some(missing_patterns),
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn test_enum_match_exhaustive() {
+        let missing = enum_match_missing_patterns(
+            "Color::Red => { 0 }, Color::Green => { 1 }, Color::Blue => { 2 },",
+        );
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    fn test_enum_match_missing_variant() {
+        let missing = enum_match_missing_patterns("Color::Red => { 0 }, Color::Green => { 1 },");
+        let missing = missing.expect("expected the match to be non-exhaustive");
+        assert!(missing.contains("Blue"), "{}", missing);
+        assert!(!missing.contains("Red"), "{}", missing);
+    }
+
+    #[test]
+    fn test_enum_match_catch_all() {
+        let missing = enum_match_missing_patterns("Color::Red => { 0 }, _ => { 1 },");
+        assert_eq!(missing, None);
+    }
+
+    fn if_let_errors(body: &str) -> Vec<CompileError> {
+        let src = format!(
+            r#"
+        script;
+
+        enum MyOption {{
+            Some: u64,
+            None: (),
+        }}
+
+        enum Other {{
+            A: bool,
+        }}
+
+        fn main() -> u64 {{
+            {}
+        }}
+        "#,
+            body
+        );
+        // matching on an enum compares its tag with `core::ops::eq`, which isn't available here
+        compile_errors(&src)
+            .into_iter()
+            .filter(|error| !matches!(error, CompileError::ModuleNotFound { .. }))
+            .collect()
+    }
+
+    #[test]
+    fn test_if_let_binds_pattern_variables() {
+        let errors = if_let_errors(
+            "let o = MyOption::Some(5); if let MyOption::Some(x) = o { x } else { 0 }",
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_if_let_without_else_is_unit() {
+        let errors = if_let_errors(
+            "let o = MyOption::Some(5); if let MyOption::Some(x) = o { let y = x; } 0",
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        // the pattern's variables aren't in scope after the `if let`
+        let errors = if_let_errors("let o = MyOption::Some(5); if let MyOption::Some(x) = o { } x");
+        assert!(matches!(
+            &errors[..],
+            [CompileError::UnknownVariable { var_name }] if var_name.as_str() == "x"
+        ));
+    }
+
+    #[test]
+    fn test_if_let_branches_must_unify() {
+        let errors = if_let_errors(
+            "let o = MyOption::Some(5); let y = if let MyOption::Some(x) = o { x } else { true }; 0",
+        );
+        assert!(matches!(
+            &errors[..],
+            [CompileError::TypeError(TypeError::MismatchedType { .. })]
+        ));
+    }
+
+    #[test]
+    fn test_if_let_pattern_mismatch() {
+        let errors =
+            if_let_errors("let o = Other::A(true); if let MyOption::Some(x) = o { x } else { 0 }");
+        assert!(matches!(
+            &errors[..],
+            [CompileError::IfLetPatternMismatch { pattern, type_name, span }]
+                if pattern == "MyOption::Some" && type_name == "Other" && span.as_str() == "MyOption::Some(x)"
+        ));
+    }
+
+    #[test]
+    fn test_cast_of_constant_that_does_not_fit_warns() {
+        let src = r#"
+        script;
+
+        fn main() -> u8 {
+            300 as u8
+        }
+        "#;
+        let warnings = compile_warnings(src);
+        assert!(warnings.iter().any(|warning| matches!(
+            warning.warning_content,
+            Warning::CastTruncatesConstant {
+                from: 300,
+                to: IntegerBits::Eight,
+            }
+        ) && warning.span.as_str() == "300 as u8"));
+    }
+
+    #[test]
+    fn test_widening_and_non_constant_casts_do_not_warn() {
+        let src = r#"
+        script;
+
+        fn narrow(x: u64) -> u8 {
+            x as u8
+        }
+
+        fn main() -> u64 {
+            let small: u8 = 255 as u8;
+            let wide = small as u64;
+            let fits = 70000 as u32;
+            narrow(wide) as u64
+        }
+        "#;
+        let warnings = compile_warnings(src);
+        assert!(!warnings.iter().any(|warning| matches!(
+            warning.warning_content,
+            Warning::CastTruncatesConstant { .. }
+        )));
+    }
+
+    #[test]
+    fn test_cast_between_non_numeric_types_is_an_error() {
+        let src = r#"
+        script;
+
+        fn main() -> u64 {
+            true as u64
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            CompileError::InvalidCast { from, to, .. } if from == "bool" && to == "u64"
+        )));
+    }
+
+    #[test]
+    fn test_const_generic_length_inferred_from_struct_expression() {
+        let src = r#"
+        script;
+
+        struct Arr<const N: u64> {
+            items: [u64; N],
+        }
+
+        fn first(a: Arr<3>) -> u64 {
+            a.items[0]
+        }
+
+        fn main() -> u64 {
+            let a = Arr { items: [1, 2, 3] };
+            first(a)
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_const_generic_length_inferred_from_struct_expression_mismatch() {
+        let src = r#"
+        script;
+
+        struct Arr<const N: u64> {
+            items: [u64; N],
+        }
+
+        fn first(a: Arr<3>) -> u64 {
+            a.items[0]
+        }
+
+        fn main() -> u64 {
+            let a = Arr { items: [1, 2, 3, 4] };
+            first(a)
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(
+            errors
+                .iter()
+                .any(|error| matches!(error, CompileError::TypeError(..))),
+            "{:?}",
+            errors
+        );
+    }
+
+    fn return_type_check_result(body: &str) -> (Vec<CompileWarning>, Vec<CompileError>) {
+        let src = format!(
+            r#"
+        script;
+
+        fn g(x: ()) -> u64 {{
+            0
+        }}
+
+        fn f(x: u64) -> u64 {{
+            {}
+        }}
+
+        fn main() {{}}
+        "#,
+            body
+        );
+        match compile(&src) {
+            CompileAstResult::Success { warnings, .. } => (warnings, vec![]),
+            // matching on a literal compares it using `core::ops::eq`, which isn't available here
+            CompileAstResult::Failure { warnings, errors } => (
+                warnings,
+                errors
+                    .into_iter()
+                    .filter(|error| !matches!(error, CompileError::ModuleNotFound { .. }))
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_return_in_match_arm_is_type_checked() {
+        let (warnings, errors) = return_type_check_result(
+            r#"match x {
+                0 => { 0 },
+                _ => { return true; },
+            }"#,
+        );
+        assert!(
+            !warnings.iter().any(|warning| matches!(
+                warning.warning_content,
+                Warning::FunctionNeverReturns { .. }
+            )),
+            "{:?}",
+            warnings
+        );
+        assert!(
+            errors.iter().any(|error| matches!(
+                error,
+                CompileError::TypeError(TypeError::MismatchedType { .. })
+            )),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_return_in_else_branch_is_type_checked() {
+        let (_, errors) = return_type_check_result(
+            r#"if x == 0 {
+                0
+            } else {
+                return true;
+            }"#,
+        );
+        assert!(
+            errors.iter().any(|error| matches!(
+                error,
+                CompileError::TypeError(TypeError::MismatchedType { .. })
+            )),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_return_in_function_argument_is_type_checked() {
+        let (_, errors) = return_type_check_result(
+            r#"let y = g({ return true; });
+            y"#,
+        );
+        assert!(
+            errors.iter().any(|error| matches!(
+                error,
+                CompileError::TypeError(TypeError::MismatchedType { .. })
+            )),
+            "{:?}",
+            errors
+        );
+    }
 }
 fn disallow_opcode(op: &Ident) -> CompileResult<()> {
     let mut errors = vec![];
@@ -1,7 +1,7 @@
 use crate::{
     error::*,
     semantic_analysis::{ast_node::*, TCOpts, TypeCheckArguments},
-    type_engine::TypeId,
+    type_engine::{monomorphized_type_mapping, TypeId, TypeMapping},
 };
 use std::collections::{hash_map::RandomState, HashMap, VecDeque};
 use sway_types::{state::StateIndex, Spanned};
@@ -20,6 +20,8 @@ pub(crate) fn instantiate_function_application(
     let mut errors = vec![];
 
     // monomorphize the function declaration
+    let type_parameters = function_decl.type_parameters.clone();
+    let has_type_arguments = !type_arguments.is_empty();
     let function_decl = check!(
         namespace.monomorphize(
             function_decl,
@@ -33,6 +35,12 @@ pub(crate) fn instantiate_function_application(
         errors
     );
 
+    let type_mapping = if has_type_arguments {
+        vec![]
+    } else {
+        monomorphized_type_mapping(type_parameters, &function_decl.type_parameters)
+    };
+
     // 'purity' is that of the callee, 'opts.purity' of the caller.
     if !opts.purity.can_call(function_decl.purity) {
         errors.push(CompileError::StorageAccessMismatch {
@@ -47,23 +55,43 @@ pub(crate) fn instantiate_function_application(
     let typed_call_arguments = arguments
         .into_iter()
         .zip(function_decl.parameters.iter())
-        .map(|(arg, param)| {
-            let exp = check!(
-                TypedExpression::type_check(TypeCheckArguments {
-                    checkee: arg.clone(),
-                    namespace,
-                    return_type_annotation: param.type_id,
-                    help_text: "The argument that has been provided to this function's type does \
-                        not match the declared type of the parameter in the function \
-                        declaration.",
-                    self_type,
-                    mode: Mode::NonAbi,
-                    opts,
-                }),
-                error_recovery_expr(arg.span()),
-                warnings,
-                errors
-            );
+        .enumerate()
+        .map(|(arg_index, (arg, param))| {
+            let arg_span = arg.span();
+            let mut res = TypedExpression::type_check(TypeCheckArguments {
+                checkee: arg,
+                namespace,
+                return_type_annotation: param.type_id,
+                help_text: "The argument that has been provided to this function's type does \
+                    not match the declared type of the parameter in the function \
+                    declaration.",
+                self_type,
+                mode: Mode::NonAbi,
+                opts,
+            });
+            // point out which argument doesn't match its parameter, rather than reporting a
+            // plain type mismatch. The argument's own type is reported as it was resolved, as
+            // for method arguments, so that e.g. a numeric literal is shown as `u64`.
+            let resolved_type = res.value.as_ref().map(|exp| exp.return_type);
+            res.errors = res
+                .errors
+                .into_iter()
+                .map(|error| match error {
+                    CompileError::TypeError(TypeError::MismatchedType {
+                        expected,
+                        received,
+                        span,
+                        ..
+                    }) if span == arg_span => CompileError::ArgumentTypeMismatch {
+                        arg_index,
+                        expected: expected.to_string(),
+                        found: resolved_type.unwrap_or(received).to_string(),
+                        span,
+                    },
+                    error => error,
+                })
+                .collect();
+            let exp = check!(res, error_recovery_expr(arg_span), warnings, errors);
             (param.name.clone(), exp)
         })
         .collect();
@@ -78,6 +106,7 @@ pub(crate) fn instantiate_function_application(
             None,
             IsConstant::No,
             None,
+            type_mapping,
             span,
         ),
         return err(warnings, errors),
@@ -112,6 +141,7 @@ pub(crate) fn instantiate_function_application_simple(
         selector,
         is_constant,
         self_state_idx,
+        vec![],
         span,
     )
 }
@@ -126,6 +156,7 @@ fn instantiate_function_application_inner(
     selector: Option<ContractCallMetadata>,
     is_constant: IsConstant,
     self_state_idx: Option<StateIndex>,
+    type_mapping: TypeMapping,
     span: Span,
 ) -> CompileResult<TypedExpression> {
     let warnings = vec![];
@@ -142,6 +173,7 @@ fn instantiate_function_application_inner(
                     function_body_purity: function_decl.purity,
                     self_state_idx,
                     selector,
+                    type_mapping,
                 },
                 return_type: function_decl.return_type,
                 is_constant,
@@ -169,3 +201,34 @@ fn instantiate_function_application_inner(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_errors;
+
+    #[test]
+    fn test_function_argument_type_mismatch_points_at_argument() {
+        let src = r#"
+        script;
+
+        fn takes_two(a: u64, b: bool) -> u64 {
+            a
+        }
+
+        fn main() -> u64 {
+            takes_two(1, 2)
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(
+            errors.iter().any(|error| matches!(
+                error,
+                CompileError::ArgumentTypeMismatch { arg_index: 1, expected, found, span }
+                    if expected == "bool" && found == "u64" && span.as_str() == "2"
+            )),
+            "{:?}",
+            errors
+        );
+    }
+}
@@ -17,7 +17,19 @@ pub(crate) fn instantiate_if_expression(
 
     // if the branch aborts, then its return type doesn't matter.
     let then_deterministically_aborts = then.deterministically_aborts();
-    if !then_deterministically_aborts {
+    // without an `else` branch the if expression can only evaluate to unit, so a `then` branch
+    // that yields some other value is missing its `else`.
+    let then_type = look_up_type_id(then.return_type);
+    let missing_else = r#else.is_none()
+        && !then_deterministically_aborts
+        && !then_type.is_unit()
+        && !matches!(then_type, TypeInfo::Unknown | TypeInfo::ErrorRecovery);
+    if missing_else {
+        errors.push(CompileError::IfExpressionMissingElse {
+            then_type: then_type.to_string(),
+            span: span.clone(),
+        });
+    } else if !then_deterministically_aborts {
         // if this does not deterministically_abort, check the block return type
         let ty_to_check = if r#else.is_some() {
             type_annotation
@@ -56,8 +68,7 @@ pub(crate) fn instantiate_if_expression(
         .as_ref()
         .map(|x| x.return_type)
         .unwrap_or_else(|| insert_type(TypeInfo::Tuple(Vec::new())));
-    // if there is a type annotation, then the else branch must exist
-    if !else_deterministically_aborts && !then_deterministically_aborts {
+    if !else_deterministically_aborts && !then_deterministically_aborts && !missing_else {
         let (mut new_warnings, new_errors) = unify_with_self(
             then.return_type,
             r#else_ret_ty,
@@ -66,16 +77,7 @@ pub(crate) fn instantiate_if_expression(
             "The two branches of an if expression must return the same type.",
         );
         warnings.append(&mut new_warnings);
-        if new_errors.is_empty() {
-            if !look_up_type_id(r#else_ret_ty).is_unit() && r#else.is_none() {
-                errors.push(CompileError::NoElseBranch {
-                    span: span.clone(),
-                    r#type: look_up_type_id(type_annotation).to_string(),
-                });
-            }
-        } else {
-            errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
-        }
+        errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect());
     }
 
     let return_type = then.return_type;
@@ -91,3 +93,47 @@ pub(crate) fn instantiate_if_expression(
     };
     ok(exp, warnings, errors)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_errors;
+
+    #[test]
+    fn test_if_without_else_in_value_position() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        fn main() {
+            let c = true;
+            let x = if c { 1u64 };
+        }
+        "#,
+        );
+        assert!(
+            matches!(
+                &errors[..],
+                [CompileError::IfExpressionMissingElse { then_type, span }]
+                    if then_type == "u64" && span.as_str() == "if c { 1u64 }"
+            ),
+            "{:?}",
+            errors
+        );
+
+        let errors = compile_errors(
+            r#"
+        script;
+
+        fn main() {
+            let c = true;
+            let mut x = 0;
+            if c {
+                x = 1;
+            }
+        }
+        "#,
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+}
@@ -17,13 +17,23 @@ pub(crate) fn type_check_method_application(
     span: Span,
     namespace: &mut Namespace,
     self_type: TypeId,
+    type_annotation: TypeId,
     opts: TCOpts,
 ) -> CompileResult<TypedExpression> {
     let mut warnings = vec![];
     let mut errors = vec![];
     let mut args_buf = VecDeque::new();
     let mut contract_call_params_map = HashMap::new();
-    for arg in &arguments {
+    for (index, arg) in arguments.iter().enumerate() {
+        // A storage field used as the receiver is only loaded if the method takes `self`, which
+        // isn't known until the method is resolved below, so its purity is checked there.
+        let opts = match arg {
+            StorageAccess { .. } if index == 0 => TCOpts {
+                purity: Purity::ReadsWrites,
+                ..opts
+            },
+            _ => opts,
+        };
         args_buf.push_back(check!(
             TypedExpression::type_check(TypeCheckArguments {
                 checkee: arg.clone(),
@@ -60,6 +70,18 @@ pub(crate) fn type_check_method_application(
         None
     };
 
+    // A storage receiver is read only if the method takes `self`, otherwise just its storage
+    // index is passed to the method.
+    let receiver_is_loaded =
+        matches!(method.parameters.first(), Some(param) if param.name.as_str() == "self");
+    if let Some(receiver @ StorageAccess { .. }) = arguments.first() {
+        if receiver_is_loaded && opts.purity == Purity::Pure {
+            errors.push(CompileError::StorageAccessInPureFunction {
+                span: receiver.span(),
+            });
+        }
+    }
+
     if !method.is_contract_call {
         // 'method.purity' is that of the callee, 'opts.purity' of the caller.
         if !opts.purity.can_call(method.purity) {
@@ -167,8 +189,15 @@ pub(crate) fn type_check_method_application(
         }
     };
 
+    // In something like a.b(c) the receiver `a` is passed as the first argument, but it isn't
+    // counted when the arguments are numbered in errors, so `c` is the first argument.
+    let receiver_count = match method_name {
+        MethodName::FromModule { .. } => 1,
+        _ => 0,
+    };
+
     // type check all of the arguments against the parameters in the method declaration
-    for (arg, param) in args_buf.iter().zip(method.parameters.iter()) {
+    for (arg_index, (arg, param)) in args_buf.iter().zip(method.parameters.iter()).enumerate() {
         // if the return type cannot be cast into the annotation type then it is a type error
         let (mut new_warnings, new_errors) = unify_with_self(
             arg.return_type,
@@ -179,15 +208,29 @@ pub(crate) fn type_check_method_application(
         );
         warnings.append(&mut new_warnings);
         if !new_errors.is_empty() {
-            errors.push(CompileError::ArgumentParameterTypeMismatch {
-                span: arg.span.clone(),
-                provided: arg.return_type.to_string(),
-                should_be: param.type_id.to_string(),
-            });
+            match arg_index.checked_sub(receiver_count) {
+                Some(arg_index) => errors.push(CompileError::ArgumentTypeMismatch {
+                    arg_index,
+                    expected: param.type_id.to_string(),
+                    found: arg.return_type.to_string(),
+                    span: arg.span.clone(),
+                }),
+                None => errors.append(&mut new_errors.into_iter().map(|x| x.into()).collect()),
+            }
         }
         // The annotation may result in a cast, which is handled in the type engine.
     }
 
+    // arithmetic on two integer literals can be checked for overflow right away
+    if let Some(op) =
+        literal_arithmetic_overflow(&method_name, &arguments, &args_buf, type_annotation)
+    {
+        errors.push(CompileError::ConstantArithmeticOverflow {
+            op: op.to_string(),
+            span: span.clone(),
+        });
+    }
+
     match method_name {
         // something like a.b(c)
         MethodName::FromModule { method_name } => {
@@ -242,6 +285,30 @@ pub(crate) fn type_check_method_application(
             ok(exp, warnings, errors)
         }
 
+        // something like <Foo as Bar>::baz()
+        MethodName::FromQualifiedPathRoot { method_name, .. } => {
+            let exp = check!(
+                instantiate_function_application_simple(
+                    CallPath {
+                        prefixes: vec![],
+                        suffix: method_name,
+                        is_absolute: false,
+                    },
+                    contract_call_params_map,
+                    args_buf,
+                    method,
+                    None,
+                    IsConstant::No,
+                    self_state_idx,
+                    span,
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            ok(exp, warnings, errors)
+        }
+
         // something like blah::blah::~Type::foo()
         MethodName::FromType { call_path, .. } | MethodName::FromTrait { call_path } => {
             let selector = if method.is_contract_call {
@@ -294,6 +361,70 @@ pub(crate) fn type_check_method_application(
     }
 }
 
+/// If `method_name` is one of the `core::ops` arithmetic methods which operators like `+` are
+/// desugared to, and both of its operands are integer literals, returns the operator when the
+/// result doesn't fit in the operands' type.
+fn literal_arithmetic_overflow(
+    method_name: &MethodName,
+    arguments: &[Expression],
+    args: &VecDeque<TypedExpression>,
+    type_annotation: TypeId,
+) -> Option<&'static str> {
+    let call_path = match method_name {
+        MethodName::FromTrait { call_path } => call_path,
+        _ => return None,
+    };
+    match &call_path.prefixes[..] {
+        [core, ops] if core.as_str() == "core" && ops.as_str() == "ops" => (),
+        _ => return None,
+    }
+    let literal_value = |arg: &TypedExpression| match &arg.expression {
+        TypedExpressionVariant::Literal(Literal::U8(value)) => Some(*value as u64),
+        TypedExpressionVariant::Literal(Literal::U16(value)) => Some(*value as u64),
+        TypedExpressionVariant::Literal(Literal::U32(value)) => Some(*value as u64),
+        TypedExpressionVariant::Literal(Literal::U64(value))
+        | TypedExpressionVariant::Literal(Literal::Numeric(value)) => Some(*value),
+        _ => None,
+    };
+    let (lhs, rhs) = match (args.get(0), args.get(1)) {
+        (Some(lhs), Some(rhs)) if args.len() == 2 => (lhs, rhs),
+        _ => return None,
+    };
+    // The operands are unified to a single width, which is that of the first operand with a
+    // suffix such as `u8`. Otherwise both are untyped and take the width of the annotation.
+    let is_untyped = |arg: &Expression| {
+        matches!(
+            arg,
+            Expression::Literal {
+                value: Literal::Numeric(_),
+                ..
+            }
+        )
+    };
+    let operand_type = match arguments {
+        [lhs_arg, _] if !is_untyped(lhs_arg) => lhs.return_type,
+        [_, rhs_arg] if !is_untyped(rhs_arg) => rhs.return_type,
+        _ => type_annotation,
+    };
+    let max_value = match look_up_type_id(operand_type) {
+        TypeInfo::UnsignedInteger(bits) => bits.max_value(),
+        // untyped literals default to `u64`
+        TypeInfo::Numeric | TypeInfo::Unknown => u64::MAX,
+        _ => return None,
+    };
+    let (lhs, rhs) = (literal_value(lhs)?, literal_value(rhs)?);
+    let (op, result) = match call_path.suffix.as_str() {
+        "add" => ("+", lhs.checked_add(rhs)),
+        "subtract" => ("-", lhs.checked_sub(rhs)),
+        "multiply" => ("*", lhs.checked_mul(rhs)),
+        _ => return None,
+    };
+    match result {
+        Some(result) if result <= max_value => None,
+        _ => Some(op),
+    }
+}
+
 pub(crate) fn resolve_method_name(
     method_name: &MethodName,
     arguments: VecDeque<TypedExpression>,
@@ -343,6 +474,51 @@ pub(crate) fn resolve_method_name(
                 errors
             )
         }
+        MethodName::FromQualifiedPathRoot {
+            type_name,
+            type_name_span,
+            as_trait,
+            method_name,
+        } => {
+            match namespace
+                .resolve_call_path(as_trait)
+                .ok(&mut warnings, &mut errors)
+            {
+                Some(TypedDeclaration::TraitDeclaration(_))
+                | Some(TypedDeclaration::AbiDeclaration(_)) => (),
+                _ => {
+                    errors.push(CompileError::UnknownTrait {
+                        name: as_trait.suffix.clone(),
+                        span: as_trait.span(),
+                    });
+                    return err(warnings, errors);
+                }
+            }
+            let ty = check!(
+                namespace.resolve_type_with_self(
+                    type_name.clone(),
+                    self_type,
+                    type_name_span,
+                    EnforceTypeArguments::No
+                ),
+                insert_type(TypeInfo::ErrorRecovery),
+                warnings,
+                errors
+            );
+            let abs_path: Vec<_> = namespace.find_module_path(Some(method_name));
+            check!(
+                namespace.find_method_for_type(
+                    ty,
+                    &abs_path,
+                    self_type,
+                    &arguments,
+                    Some(as_trait)
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            )
+        }
         MethodName::FromModule { method_name } => {
             let ty = arguments
                 .get(0)
@@ -350,7 +526,7 @@ pub(crate) fn resolve_method_name(
                 .unwrap_or_else(|| insert_type(TypeInfo::Unknown));
             let abs_path: Vec<_> = namespace.find_module_path(Some(method_name));
             check!(
-                namespace.find_method_for_type(ty, &abs_path, self_type, &arguments),
+                namespace.find_method_for_type(ty, &abs_path, self_type, &arguments, None),
                 return err(warnings, errors),
                 warnings,
                 errors
@@ -410,5 +586,173 @@ fn find_method(
     } else {
         namespace.find_module_path(call_path.full_path())
     };
-    namespace.find_method_for_type(insert_type(ty), &abs_path, self_type, arguments)
+    namespace.find_method_for_type(insert_type(ty), &abs_path, self_type, arguments, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{compile_errors, compile_library, compile_with_namespace};
+    use crate::CompileAstResult;
+
+    fn qualified_path_call(call: &str) -> String {
+        format!(
+            r#"
+        script;
+
+        struct Foo {{
+            x: u64,
+        }}
+
+        trait Bar {{
+            fn baz(self) -> u64;
+        }}
+
+        trait Qux {{
+            fn baz(self) -> bool;
+        }}
+
+        impl Bar for Foo {{
+            fn baz(self) -> u64 {{
+                self.x
+            }}
+        }}
+
+        impl Qux for Foo {{
+            fn baz(self) -> bool {{
+                true
+            }}
+        }}
+
+        fn main() {{
+            let foo = Foo {{ x: 1 }};
+            {}
+        }}
+        "#,
+            call
+        )
+    }
+
+    #[test]
+    fn test_qualified_path_call_picks_trait() {
+        for call in [
+            "let a: u64 = <Foo as Bar>::baz(foo);",
+            "let b: bool = <Foo as Qux>::baz(foo);",
+        ] {
+            assert!(
+                compile_errors(&qualified_path_call(call)).is_empty(),
+                "{} should compile",
+                call
+            );
+        }
+    }
+
+    #[test]
+    fn test_qualified_path_call_wrong_trait() {
+        let errors = compile_errors(&qualified_path_call(
+            "let a: bool = <Foo as Bar>::baz(foo);",
+        ));
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error, CompileError::TypeError(_))));
+    }
+
+    #[test]
+    fn test_qualified_path_call_unknown_trait() {
+        let errors = compile_errors(&qualified_path_call("let a = <Foo as Nope>::baz(foo);"));
+        assert!(errors.iter().any(
+            |error| matches!(error, CompileError::UnknownTrait { name, .. } if name.as_str() == "Nope")
+        ));
+    }
+
+    #[test]
+    fn test_method_argument_type_mismatch_points_at_argument() {
+        let src = r#"
+        script;
+
+        struct Counter {
+            count: u64,
+        }
+
+        impl Counter {
+            fn add(self, amount: u64, double: bool) -> u64 {
+                self.count
+            }
+        }
+
+        fn main() -> u64 {
+            let counter = Counter { count: 0 };
+            counter.add(1, 2)
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(
+            errors.iter().any(|error| matches!(
+                error,
+                CompileError::ArgumentTypeMismatch { arg_index: 1, expected, found, span }
+                    if expected == "bool" && found == "u64" && span.as_str() == "2"
+            )),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_constant_arithmetic_overflow() {
+        let mut core = namespace::Module::default();
+        core.insert_submodule(
+            "ops".to_string(),
+            compile_library(
+                include_str!("../../../../../../sway-lib-core/src/ops.sw"),
+                namespace::Module::default(),
+            ),
+        );
+        let mut initial_namespace = namespace::Module::default();
+        initial_namespace.insert_submodule("core".to_string(), core);
+
+        let annotated_overflow_op = |annotation: &str, expr: &str| {
+            let src = format!(
+                "script;\n\nfn main() {{\n    let x{} = {};\n}}\n",
+                annotation, expr
+            );
+            let errors = match compile_with_namespace(&src, initial_namespace.clone()) {
+                CompileAstResult::Success { .. } => vec![],
+                CompileAstResult::Failure { errors, .. } => errors,
+            };
+            match &errors[..] {
+                [CompileError::ConstantArithmeticOverflow { op, span }] => {
+                    assert_eq!(span.as_str(), expr);
+                    Some(op.clone())
+                }
+                [] => None,
+                _ => panic!("{:?}", errors),
+            }
+        };
+        let overflow_op = |expr: &str| annotated_overflow_op("", expr);
+
+        assert_eq!(overflow_op("255u8 + 1u8").as_deref(), Some("+"));
+        assert_eq!(overflow_op("255u8 + 1").as_deref(), Some("+"));
+        assert_eq!(overflow_op("70000u32 * 70000u32").as_deref(), Some("*"));
+        assert_eq!(
+            overflow_op("18446744073709551615 + 1").as_deref(),
+            Some("+")
+        );
+        assert_eq!(overflow_op("0u16 - 1u16").as_deref(), Some("-"));
+
+        assert_eq!(overflow_op("254u8 + 1u8"), None);
+        assert_eq!(overflow_op("255u16 * 257u16"), None);
+        assert_eq!(overflow_op("4294967296 * 2"), None);
+
+        // untyped literals take the width of the annotation
+        assert_eq!(
+            annotated_overflow_op(": u8", "100 + 200").as_deref(),
+            Some("+")
+        );
+        assert_eq!(
+            annotated_overflow_op(": u16", "300 * 300").as_deref(),
+            Some("*")
+        );
+        assert_eq!(annotated_overflow_op(": u8", "100 + 100"), None);
+        assert_eq!(annotated_overflow_op(": u64", "100 + 200"), None);
+    }
 }
@@ -28,6 +28,9 @@ pub enum TypedExpressionVariant {
         self_state_idx: Option<StateIndex>,
         #[derivative(Eq(bound = ""))]
         selector: Option<ContractCallMetadata>,
+        /// The type arguments inferred for a generic function that was called without explicit
+        /// ones. Empty if the function isn't generic or the type arguments were written out.
+        type_mapping: TypeMapping,
     },
     LazyOperator {
         #[derivative(Eq(bound = ""))]
@@ -51,6 +54,10 @@ pub enum TypedExpressionVariant {
     StructExpression {
         struct_name: Ident,
         fields: Vec<TypedStructExpressionField>,
+        /// The type arguments inferred for a generic struct that was instantiated without
+        /// explicit ones. Empty if the struct isn't generic or the type arguments were written
+        /// out.
+        type_mapping: TypeMapping,
     },
     CodeBlock(TypedCodeBlock),
     // a flag that this value will later be provided as a parameter, but is currently unknown
@@ -111,6 +118,11 @@ pub enum TypedExpressionVariant {
         exp: Box<TypedExpression>,
         variant: TypedEnumVariant,
     },
+    /// a numeric cast of the integer `exp` to an integer of width `cast_to`
+    Cast {
+        exp: Box<TypedExpression>,
+        cast_to: IntegerBits,
+    },
 }
 
 // NOTE: Hash and PartialEq must uphold the invariant:
@@ -177,10 +189,12 @@ impl PartialEq for TypedExpressionVariant {
                 Self::StructExpression {
                     struct_name: l_struct_name,
                     fields: l_fields,
+                    ..
                 },
                 Self::StructExpression {
                     struct_name: r_struct_name,
                     fields: r_fields,
+                    ..
                 },
             ) => l_struct_name == r_struct_name && l_fields.clone() == r_fields.clone(),
             (Self::CodeBlock(l0), Self::CodeBlock(r0)) => l0 == r0,
@@ -307,6 +321,16 @@ impl PartialEq for TypedExpressionVariant {
                 },
             ) => *l_exp == *r_exp && l_variant == r_variant,
             (Self::EnumTag { exp: l_exp }, Self::EnumTag { exp: r_exp }) => *l_exp == *r_exp,
+            (
+                Self::Cast {
+                    exp: l_exp,
+                    cast_to: l_cast_to,
+                },
+                Self::Cast {
+                    exp: r_exp,
+                    cast_to: r_cast_to,
+                },
+            ) => *l_exp == *r_exp && l_cast_to == r_cast_to,
             _ => false,
         }
     }
@@ -320,12 +344,17 @@ impl CopyTypes for TypedExpressionVariant {
             FunctionApplication {
                 arguments,
                 function_body,
+                function_body_name_span,
+                type_mapping: inferred_type_mapping,
                 ..
             } => {
                 arguments
                     .iter_mut()
                     .for_each(|(_ident, expr)| expr.copy_types(type_mapping));
                 function_body.copy_types(type_mapping);
+                inferred_type_mapping.iter_mut().for_each(|(_, type_id)| {
+                    type_id.update_type(type_mapping, function_body_name_span)
+                });
             }
             LazyOperator { lhs, rhs, .. } => {
                 (*lhs).copy_types(type_mapping);
@@ -338,8 +367,15 @@ impl CopyTypes for TypedExpressionVariant {
                 (*prefix).copy_types(type_mapping);
                 (*index).copy_types(type_mapping);
             }
-            StructExpression { fields, .. } => {
-                fields.iter_mut().for_each(|x| x.copy_types(type_mapping))
+            StructExpression {
+                struct_name,
+                fields,
+                type_mapping: inferred_type_mapping,
+            } => {
+                fields.iter_mut().for_each(|x| x.copy_types(type_mapping));
+                inferred_type_mapping.iter_mut().for_each(|(_, type_id)| {
+                    type_id.update_type(type_mapping, &struct_name.span())
+                });
             }
             CodeBlock(block) => {
                 block.copy_types(type_mapping);
@@ -407,6 +443,9 @@ impl CopyTypes for TypedExpressionVariant {
                 exp.copy_types(type_mapping);
                 variant.copy_types(type_mapping);
             }
+            Cast { exp, .. } => {
+                exp.copy_types(type_mapping);
+            }
             AbiName(_) => (),
         }
     }
@@ -494,6 +533,13 @@ impl fmt::Display for TypedExpressionVariant {
             TypedExpressionVariant::UnsafeDowncast { exp, variant } => {
                 format!("({} as {})", look_up_type_id(exp.return_type), variant.name)
             }
+            TypedExpressionVariant::Cast { exp, cast_to } => {
+                format!(
+                    "({} as {})",
+                    look_up_type_id(exp.return_type),
+                    TypeInfo::UnsignedInteger(*cast_to)
+                )
+            }
         };
         write!(f, "{}", s)
     }
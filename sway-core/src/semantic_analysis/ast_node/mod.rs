@@ -5,7 +5,11 @@ pub mod mode;
 mod return_statement;
 pub mod while_loop;
 
-use std::fmt;
+use std::{
+    borrow::Cow,
+    fmt,
+    hash::{Hash, Hasher},
+};
 
 pub(crate) use code_block::*;
 pub use const_eval::*;
@@ -39,6 +43,8 @@ pub enum TypedAstNodeContent {
     Expression(TypedExpression),
     ImplicitReturnExpression(TypedExpression),
     WhileLoop(TypedWhileLoop),
+    Break(Option<Ident>),
+    Continue(Option<Ident>),
     // a no-op node used for something that just issues a side effect, like an import statement.
     SideEffect,
 }
@@ -62,7 +68,7 @@ impl UnresolvedTypeCheck for TypedAstNodeContent {
                 condition.append(&mut body);
                 condition
             }
-            SideEffect => vec![],
+            Break(_) | Continue(_) | SideEffect => vec![],
         }
     }
 }
@@ -86,6 +92,10 @@ impl fmt::Display for TypedAstNode {
             Expression(exp) => exp.to_string(),
             ImplicitReturnExpression(exp) => format!("return {}", exp),
             WhileLoop(w_loop) => w_loop.to_string(),
+            Break(None) => "break".into(),
+            Break(Some(label)) => format!("break '{}", label),
+            Continue(None) => "continue".into(),
+            Continue(Some(label)) => format!("continue '{}", label),
             SideEffect => "".into(),
         };
         f.write_str(&text)
@@ -106,11 +116,14 @@ impl CopyTypes for TypedAstNode {
             TypedAstNodeContent::WhileLoop(TypedWhileLoop {
                 ref mut condition,
                 ref mut body,
+                ..
             }) => {
                 condition.copy_types(type_mapping);
                 body.copy_types(type_mapping);
             }
-            TypedAstNodeContent::SideEffect => (),
+            TypedAstNodeContent::Break(_)
+            | TypedAstNodeContent::Continue(_)
+            | TypedAstNodeContent::SideEffect => (),
         }
     }
 }
@@ -125,11 +138,32 @@ impl DeterministicallyAborts for TypedAstNode {
     fn deterministically_aborts(&self) -> bool {
         use TypedAstNodeContent::*;
         match &self.content {
-            ReturnStatement(_) => true,
+            // `break` and `continue` leave the current path just like `return` does.
+            ReturnStatement(_) | Break(_) | Continue(_) => true,
             Declaration(_) => false,
             Expression(exp) | ImplicitReturnExpression(exp) => exp.deterministically_aborts(),
-            WhileLoop(TypedWhileLoop { condition, body }) => {
-                condition.deterministically_aborts() || body.deterministically_aborts()
+            // A body which aborts only aborts the loop if it can't `break` or `continue` this
+            // loop first, and a `while true` loop can only be left by aborting or with a `break`.
+            // Leaving an enclosing loop by its label leaves this one without reaching its end.
+            WhileLoop(
+                while_loop @ TypedWhileLoop {
+                    condition, body, ..
+                },
+            ) => {
+                let loop_exits = body
+                    .contents
+                    .iter()
+                    .flat_map(TypedAstNode::gather_loop_exits)
+                    .filter(|exit| while_loop.is_left_by(exit))
+                    .collect::<Vec<_>>();
+                condition.deterministically_aborts()
+                    || (loop_exits.is_empty() && body.deterministically_aborts())
+                    || (matches!(
+                        condition.expression,
+                        TypedExpressionVariant::Literal(Literal::Boolean(true))
+                    ) && !loop_exits
+                        .iter()
+                        .any(|exit| matches!(exit, TypedAstNodeContent::Break(_))))
             }
             SideEffect => false,
         }
@@ -145,6 +179,8 @@ impl TypedAstNode {
             ReturnStatement(_)
             | Expression(_)
             | WhileLoop(_)
+            | Break(_)
+            | Continue(_)
             | SideEffect
             | ImplicitReturnExpression(_) => false,
         }
@@ -196,10 +232,65 @@ impl TypedAstNode {
                 TypedReassignment { rhs, .. },
             )) => rhs.gather_return_statements(),
             TypedAstNodeContent::Expression(exp) => exp.gather_return_statements(),
+            TypedAstNodeContent::Break(_)
+            | TypedAstNodeContent::Continue(_)
+            | TypedAstNodeContent::SideEffect
+            | TypedAstNodeContent::Declaration(_) => vec![],
+        }
+    }
+
+    /// recurse into `self` and get any `break` or `continue` statements which leave the
+    /// innermost enclosing loop, i.e. those which are not within a nested loop's body, or which
+    /// leave a loop enclosing that one by its label.
+    pub(crate) fn gather_loop_exits(&self) -> Vec<&TypedAstNodeContent> {
+        match &self.content {
+            TypedAstNodeContent::Break(_) | TypedAstNodeContent::Continue(_) => {
+                vec![&self.content]
+            }
+            TypedAstNodeContent::ReturnStatement(TypedReturnStatement { expr })
+            | TypedAstNodeContent::Expression(expr)
+            | TypedAstNodeContent::ImplicitReturnExpression(expr)
+            | TypedAstNodeContent::Declaration(TypedDeclaration::VariableDeclaration(
+                TypedVariableDeclaration { body: expr, .. },
+            ))
+            | TypedAstNodeContent::Declaration(TypedDeclaration::Reassignment(
+                TypedReassignment { rhs: expr, .. },
+            )) => expr.gather_loop_exits(),
+            // the body of a nested loop is left by its own `break`s and `continue`s, unless they
+            // name an enclosing loop.
+            TypedAstNodeContent::WhileLoop(while_loop) => while_loop.gather_loop_exits(),
             TypedAstNodeContent::SideEffect | TypedAstNodeContent::Declaration(_) => vec![],
         }
     }
 
+    /// recurse into `self` and get any calls to another contract's ABI methods.
+    pub(crate) fn gather_contract_calls(&self) -> Vec<&TypedExpression> {
+        match &self.content {
+            TypedAstNodeContent::ReturnStatement(TypedReturnStatement { expr })
+            | TypedAstNodeContent::Expression(expr)
+            | TypedAstNodeContent::ImplicitReturnExpression(expr)
+            | TypedAstNodeContent::Declaration(TypedDeclaration::VariableDeclaration(
+                TypedVariableDeclaration { body: expr, .. },
+            ))
+            | TypedAstNodeContent::Declaration(TypedDeclaration::Reassignment(
+                TypedReassignment { rhs: expr, .. },
+            )) => expr.gather_contract_calls(),
+            TypedAstNodeContent::WhileLoop(TypedWhileLoop {
+                condition, body, ..
+            }) => {
+                let mut buf = condition.gather_contract_calls();
+                for node in &body.contents {
+                    buf.append(&mut node.gather_contract_calls())
+                }
+                buf
+            }
+            TypedAstNodeContent::Break(_)
+            | TypedAstNodeContent::Continue(_)
+            | TypedAstNodeContent::SideEffect
+            | TypedAstNodeContent::Declaration(_) => vec![],
+        }
+    }
+
     fn type_info(&self) -> TypeInfo {
         // return statement should be ()
         use TypedAstNodeContent::*;
@@ -211,7 +302,7 @@ impl TypedAstNode {
             ImplicitReturnExpression(TypedExpression { return_type, .. }) => {
                 crate::type_engine::look_up_type_id(*return_type)
             }
-            WhileLoop(_) | SideEffect => TypeInfo::Tuple(Vec::new()),
+            WhileLoop(_) | Break(_) | Continue(_) | SideEffect => TypeInfo::Tuple(Vec::new()),
         }
     }
 
@@ -265,9 +356,13 @@ impl TypedAstNode {
                         namespace.find_module_path(&a.call_path)
                     };
                     let mut res = match a.import_type {
-                        ImportType::Star => namespace.star_import(&path),
-                        ImportType::SelfImport => namespace.self_import(&path, a.alias),
-                        ImportType::Item(s) => namespace.item_import(&path, &s, a.alias),
+                        ImportType::Star => namespace.star_import(&path, a.visibility),
+                        ImportType::SelfImport => {
+                            namespace.self_import(&path, a.alias, a.visibility)
+                        }
+                        ImportType::Item(s) => {
+                            namespace.item_import(&path, &s, a.alias, a.visibility)
+                        }
                     };
                     warnings.append(&mut res.warnings);
                     errors.append(&mut res.errors);
@@ -321,7 +416,12 @@ impl TypedAstNode {
                                     const_decl_origin: false,
                                     type_ascription,
                                 });
-                            namespace.insert_symbol(name, typed_var_decl.clone());
+                            check!(
+                                namespace.insert_symbol(name, typed_var_decl.clone()),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
                             typed_var_decl
                         }
                         Declaration::ConstantDeclaration(ConstantDeclaration {
@@ -347,7 +447,17 @@ impl TypedAstNode {
                                     const_decl_origin: true,
                                     type_ascription: insert_type(type_ascription),
                                 });
-                            namespace.insert_symbol(name, typed_const_decl.clone());
+                            let const_shadowing_mode = namespace.const_shadowing_mode;
+                            check!(
+                                namespace.insert_const(
+                                    name,
+                                    typed_const_decl.clone(),
+                                    const_shadowing_mode
+                                ),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
                             typed_const_decl
                         }
                         Declaration::EnumDeclaration(decl) => {
@@ -390,9 +500,14 @@ impl TypedAstNode {
                                 warnings,
                                 errors
                             );
-                            namespace.insert_symbol(
-                                decl.name.clone(),
-                                TypedDeclaration::FunctionDeclaration(decl.clone()),
+                            check!(
+                                namespace.insert_symbol(
+                                    decl.name.clone(),
+                                    TypedDeclaration::FunctionDeclaration(decl.clone()),
+                                ),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
                             );
                             TypedDeclaration::FunctionDeclaration(decl)
                         }
@@ -405,7 +520,12 @@ impl TypedAstNode {
                             );
                             let name = trait_decl.name.clone();
                             let decl = TypedDeclaration::TraitDeclaration(trait_decl);
-                            namespace.insert_symbol(name, decl.clone());
+                            check!(
+                                namespace.insert_symbol(name, decl.clone()),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
                             decl
                         }
                         Declaration::Reassignment(Reassignment { lhs, rhs, span }) => {
@@ -486,7 +606,12 @@ impl TypedAstNode {
                             );
                             let name = abi_decl.name.clone();
                             let decl = TypedDeclaration::AbiDeclaration(abi_decl);
-                            namespace.insert_symbol(name, decl.clone());
+                            check!(
+                                namespace.insert_symbol(name, decl.clone()),
+                                return err(warnings, errors),
+                                warnings,
+                                errors
+                            );
                             decl
                         }
                         Declaration::StorageDeclaration(StorageDeclaration { span, fields }) => {
@@ -582,7 +707,11 @@ impl TypedAstNode {
                     );
                     TypedAstNodeContent::ImplicitReturnExpression(typed_expr)
                 }
-                AstNodeContent::WhileLoop(WhileLoop { condition, body }) => {
+                AstNodeContent::WhileLoop(WhileLoop {
+                    condition,
+                    body,
+                    label,
+                }) => {
                     let typed_condition = check!(
                         TypedExpression::type_check(TypeCheckArguments {
                             checkee: condition,
@@ -609,7 +738,10 @@ impl TypedAstNode {
                              instead.",
                             self_type,
                             mode: Mode::NonAbi,
-                            opts,
+                            opts: TCOpts {
+                                in_loop: true,
+                                ..opts
+                            },
                         }),
                         (
                             TypedCodeBlock { contents: vec![] },
@@ -618,25 +750,59 @@ impl TypedAstNode {
                         warnings,
                         errors
                     );
-                    TypedAstNodeContent::WhileLoop(TypedWhileLoop {
+                    let typed_while_loop = TypedWhileLoop {
                         condition: typed_condition,
                         body: typed_body,
-                    })
+                        label,
+                    };
+                    // Any `break` or `continue` which leaves the outermost loop must name a loop
+                    // that doesn't enclose it.
+                    if !opts.in_loop {
+                        for exit in typed_while_loop.gather_loop_exits() {
+                            if let TypedAstNodeContent::Break(Some(label))
+                            | TypedAstNodeContent::Continue(Some(label)) = exit
+                            {
+                                errors.push(CompileError::UnknownLoopLabel {
+                                    label: label.clone(),
+                                });
+                            }
+                        }
+                    }
+                    TypedAstNodeContent::WhileLoop(typed_while_loop)
+                }
+                AstNodeContent::Break(label) => {
+                    if !opts.in_loop {
+                        errors.push(CompileError::BreakOutsideLoop {
+                            span: node.span.clone(),
+                        });
+                    }
+                    TypedAstNodeContent::Break(label)
+                }
+                AstNodeContent::Continue(label) => {
+                    if !opts.in_loop {
+                        errors.push(CompileError::ContinueOutsideLoop {
+                            span: node.span.clone(),
+                        });
+                    }
+                    TypedAstNodeContent::Continue(label)
                 }
             },
             span: node.span.clone(),
         };
 
         if let TypedAstNode {
-            content: TypedAstNodeContent::Expression(TypedExpression { .. }),
+            content: TypedAstNodeContent::Expression(ref expr),
             ..
         } = node
         {
             let warning = Warning::UnusedReturnValue {
                 r#type: Box::new(node.type_info()),
             };
+            // an expression which never completes, such as `__revert(..)`, has no value to use
             assert_or_warn!(
-                node.type_info().is_unit() || node.type_info() == TypeInfo::ErrorRecovery,
+                node.type_info().is_unit()
+                    || node.type_info() == TypeInfo::ErrorRecovery
+                    || expr.deterministically_aborts(),
                 warnings,
                 node.span.clone(),
                 warning
@@ -663,6 +829,7 @@ fn reassignment(
     // ensure that the lhs is a variable expression or struct field access
     match lhs {
         ReassignmentTarget::VariableExpression(var) => {
+            let lhs_span = var.span();
             let mut expr = var;
             let mut names_vec = Vec::new();
             let (base_name, final_return_type) = loop {
@@ -670,7 +837,7 @@ fn reassignment(
                     Expression::VariableExpression { name, .. } => {
                         // check that the reassigned name exists
                         let unknown_decl = check!(
-                            namespace.resolve_symbol(&name).cloned(),
+                            namespace.resolve_symbol_with_suggestion(&name).cloned(),
                             return err(warnings, errors),
                             warnings,
                             errors
@@ -682,7 +849,17 @@ fn reassignment(
                             errors
                         );
                         if !variable_decl.is_mutable.is_mutable() {
-                            errors.push(CompileError::AssignmentToNonMutable { name });
+                            // `names_vec` is built from the outermost projection inwards, so
+                            // walk it backwards to reconstruct the path as it was written.
+                            let path = std::iter::once(Cow::Borrowed(name.as_str()))
+                                .chain(names_vec.iter().rev().map(ProjectionKind::pretty_print))
+                                .collect::<Vec<_>>()
+                                .join(".");
+                            errors.push(CompileError::AssignmentToNonMutable {
+                                name,
+                                path,
+                                span: lhs_span,
+                            });
                             return err(warnings, errors);
                         }
                         break (name, variable_decl.body.return_type);
@@ -961,7 +1138,10 @@ fn type_check_trait_methods(
                                          its return type annotation.",
                 self_type,
                 mode: Mode::NonAbi,
-                opts: TCOpts { purity }
+                opts: TCOpts {
+                    purity,
+                    in_loop: false,
+                }
             }),
             continue,
             warnings,
@@ -981,6 +1161,7 @@ fn type_check_trait_methods(
             return_type_span,
             is_contract_call: false,
             purity,
+            is_test: false,
         });
     }
     ok(methods_buf, warnings, errors)
@@ -1010,6 +1191,7 @@ fn error_recovery_function_declaration(decl: FunctionDeclaration) -> TypedFuncti
         visibility,
         return_type: insert_type(return_type),
         type_parameters: Default::default(),
+        is_test: false,
     }
 }
 
@@ -1049,6 +1231,16 @@ pub struct TypeCheckedStorageReassignDescriptor {
     pub(crate) span: Span,
 }
 
+// NOTE: Hash and PartialEq must uphold the invariant:
+// k1 == k2 -> hash(k1) == hash(k2)
+// https://doc.rust-lang.org/std/collections/struct.HashMap.html
+impl Hash for TypeCheckedStorageReassignDescriptor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        look_up_type_id(self.type_id).hash(state);
+    }
+}
+
 // NOTE: Hash and PartialEq must uphold the invariant:
 // k1 == k2 -> hash(k1) == hash(k2)
 // https://doc.rust-lang.org/std/collections/struct.HashMap.html
@@ -1077,6 +1269,15 @@ fn reassign_storage_subfield(
 
         return err(warnings, errors);
     }
+    match opts.purity {
+        Purity::Pure => {
+            errors.push(CompileError::StorageAccessInPureFunction { span: span.clone() })
+        }
+        Purity::Reads => {
+            errors.push(CompileError::StorageWriteInReadOnlyFunction { span: span.clone() })
+        }
+        Purity::Writes | Purity::ReadsWrites => {}
+    }
 
     let storage_fields = check!(
         namespace.get_storage_field_descriptors(),
@@ -1087,7 +1288,16 @@ fn reassign_storage_subfield(
     let mut type_checked_buf = vec![];
     let mut fields: Vec<_> = fields.into_iter().rev().collect();
 
-    let first_field = fields.pop().expect("guaranteed by grammar");
+    let first_field = match fields.pop() {
+        Some(first_field) => first_field,
+        None => {
+            errors.push(CompileError::Internal(
+                "storage reassignment without any field names",
+                span,
+            ));
+            return err(warnings, errors);
+        }
+    };
     let (ix, initial_field_type) = match storage_fields
         .iter()
         .enumerate()
@@ -1149,6 +1359,8 @@ fn reassign_storage_subfield(
                 errors.push(CompileError::FieldNotFound {
                     field_name: field.clone(),
                     available_fields: available_fields.join(", "),
+                    did_you_mean: find_closest_match(field.as_str(), available_fields)
+                        .map(str::to_string),
                     struct_name: type_checked_buf.last().unwrap().name.clone(),
                 });
                 return err(warnings, errors);
@@ -1180,3 +1392,357 @@ fn reassign_storage_subfield(
         errors,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{compile_errors, compile_to_asm_errors, compile_warnings};
+    use std::collections::HashSet;
+
+    #[test]
+    fn equal_storage_reassign_descriptors_hash_equally() {
+        // Two distinct type ids which resolve to the same type.
+        let descriptor = |span| TypeCheckedStorageReassignDescriptor {
+            name: Ident::new_no_span("counter"),
+            type_id: insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+            span,
+        };
+        let first = descriptor(Span::dummy());
+        let second = descriptor(Span::dummy());
+        assert_ne!(first.type_id, second.type_id);
+        assert_eq!(first, second);
+
+        let descriptors = [first, second].into_iter().collect::<HashSet<_>>();
+        assert_eq!(descriptors.len(), 1);
+    }
+
+    #[test]
+    fn test_assignment_to_non_mutable_subfield() {
+        let src = r#"
+        script;
+
+        struct Inner {
+            x: u64,
+        }
+
+        struct Outer {
+            inner: Inner,
+        }
+
+        fn main() {
+            let thing = Outer { inner: Inner { x: 0 } };
+            thing.inner.x = 42;
+        }
+        "#;
+        let errors = compile_errors(src);
+        let error = errors
+            .iter()
+            .find(|error| matches!(error, CompileError::AssignmentToNonMutable { .. }))
+            .expect("expected an `AssignmentToNonMutable` error");
+        match error {
+            CompileError::AssignmentToNonMutable { name, path, span } => {
+                assert_eq!(name.as_str(), "thing");
+                assert_eq!(path, "thing.inner.x");
+                assert_eq!(span.as_str(), "thing.inner.x");
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn tuple_reassignment(index: usize) -> String {
+        format!(
+            r#"
+        script;
+
+        fn main() -> u64 {{
+            let mut t = (1, 2);
+            t.{} = 3;
+            t.0
+        }}
+        "#,
+            index
+        )
+    }
+
+    #[test]
+    fn test_tuple_reassignment_in_bounds() {
+        assert!(compile_errors(&tuple_reassignment(1)).is_empty());
+    }
+
+    #[test]
+    fn test_tuple_reassignment_out_of_bounds() {
+        let errors = compile_errors(&tuple_reassignment(2));
+        assert!(errors.iter().any(|error| matches!(error,
+                                                   CompileError::TupleIndexOutOfBounds {
+                                                       index: 2,
+                                                       count: 2,
+                                                       span,
+                                                   } if span.as_str() == "t.2")));
+    }
+
+    fn storage_contract(function: &str) -> String {
+        format!(
+            r#"
+        contract;
+
+        storage {{
+            x: u64 = 0,
+        }}
+
+        {}
+        "#,
+            function
+        )
+    }
+
+    fn storage_access_in_pure_function_spans(function: &str) -> Vec<String> {
+        compile_errors(&storage_contract(function))
+            .iter()
+            .filter(|error| matches!(error, CompileError::StorageAccessInPureFunction { .. }))
+            .map(|error| error.span().as_str().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_storage_read_in_pure_function() {
+        let spans = storage_access_in_pure_function_spans("fn f() -> u64 { storage.x }");
+        assert_eq!(spans, vec!["storage.x".to_string()]);
+    }
+
+    #[test]
+    fn test_storage_write_in_pure_function() {
+        let spans = storage_access_in_pure_function_spans("fn f() { storage.x = 1; }");
+        assert_eq!(spans, vec!["storage.x = 1".to_string()]);
+    }
+
+    fn storage_method_receiver_errors(key_parameters: &str) -> Vec<CompileError> {
+        compile_to_asm_errors(&format!(
+            r#"
+        contract;
+
+        abi Keys {{
+            fn key_of_x() -> b256;
+        }}
+
+        storage {{
+            x: u64 = 0,
+        }}
+
+        impl u64 {{
+            fn key({}) -> b256 {{
+                __get_storage_key()
+            }}
+        }}
+
+        impl Keys for Contract {{
+            fn key_of_x() -> b256 {{
+                storage.x.key()
+            }}
+        }}
+        "#,
+            key_parameters
+        ))
+    }
+
+    #[test]
+    fn test_storage_method_receiver_in_pure_function() {
+        // only the receiver's storage key is passed to a method which doesn't take `self`
+        let errors = storage_method_receiver_errors("");
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_storage_method_self_in_pure_function() {
+        let errors = storage_method_receiver_errors("self");
+        assert!(
+            matches!(
+                &errors[..],
+                [CompileError::StorageAccessInPureFunction { span }] if span.as_str() == "storage.x"
+            ),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_storage_read_in_impure_function() {
+        assert!(compile_errors(&storage_contract(
+            "#[storage(read)] fn f() -> u64 { storage.x }"
+        ))
+        .is_empty());
+    }
+
+    #[test]
+    fn test_storage_write_in_write_function() {
+        assert!(compile_errors(&storage_contract(
+            "#[storage(write)] fn f() { storage.x = 1; }"
+        ))
+        .is_empty());
+    }
+
+    #[test]
+    fn test_storage_write_in_read_only_function() {
+        let spans = compile_errors(&storage_contract(
+            "#[storage(read)] fn f() { storage.x = 1; }",
+        ))
+        .iter()
+        .filter(|error| matches!(error, CompileError::StorageWriteInReadOnlyFunction { .. }))
+        .map(|error| error.span().as_str().to_string())
+        .collect::<Vec<_>>();
+        assert_eq!(spans, vec!["storage.x = 1".to_string()]);
+    }
+
+    #[test]
+    fn test_pure_function_without_storage_access() {
+        assert!(compile_errors(&storage_contract("fn f() -> u64 { 1 }")).is_empty());
+    }
+
+    #[test]
+    fn test_break_and_continue_in_loop() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        fn main() -> bool {
+            let mut a = true;
+            while a {
+                if a && false {
+                    continue;
+                }
+                a = false;
+                break;
+            }
+            while true {
+                break;
+            }
+            a
+        }
+        "#,
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_break_and_continue_outside_loop() {
+        let src = r#"
+        script;
+
+        fn main() {
+            break;
+            continue;
+        }
+        "#;
+        let errors = compile_errors(src);
+        let spans = errors
+            .iter()
+            .map(|error| match error {
+                CompileError::BreakOutsideLoop { span } => ("break", span.start()),
+                CompileError::ContinueOutsideLoop { span } => ("continue", span.start()),
+                _ => panic!("unexpected error: {:?}", error),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            vec![
+                ("break", src.find("break").unwrap()),
+                ("continue", src.find("continue").unwrap())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_labeled_break_and_continue() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        fn main() -> bool {
+            let mut a = true;
+            'rows: while true {
+                'columns: while true {
+                    if a {
+                        a = false;
+                        continue 'rows;
+                    }
+                    if a {
+                        break 'columns;
+                    }
+                    break 'rows;
+                }
+            }
+            a
+        }
+        "#,
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_break_to_unknown_label() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        fn main() {
+            'outer: while true {
+                while true {
+                    break 'missing;
+                }
+                continue 'outer;
+            }
+        }
+        "#,
+        );
+        assert!(
+            matches!(
+                &errors[..],
+                [CompileError::UnknownLoopLabel { label }] if label.as_str() == "missing"
+            ),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_labeled_exits_leave_the_loops_they_name() {
+        let never_returns = |body: &str| {
+            let src = format!(
+                r#"
+        script;
+
+        fn f() -> u64 {{
+            {}
+        }}
+
+        fn main() {{}}
+        "#,
+                body
+            );
+            compile_warnings(&src).iter().any(|warning| {
+                matches!(
+                    warning.warning_content,
+                    Warning::FunctionNeverReturns { .. }
+                )
+            })
+        };
+
+        // leaving the outer loop from within the inner one reaches the end of the outer loop
+        assert!(!never_returns(
+            r#"'outer: while true {
+                while true {
+                    break 'outer;
+                }
+            }
+            0"#
+        ));
+        // while continuing the outer loop from within the inner one never does
+        assert!(never_returns(
+            r#"'outer: while true {
+                while true {
+                    continue 'outer;
+                }
+            }
+            0"#
+        ));
+    }
+}
@@ -9,3 +9,19 @@ impl Default for Mode {
         Mode::NonAbi
     }
 }
+
+/// How constant declarations may shadow earlier declarations of the same name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstShadowingMode {
+    /// Constants are declared in sequence, e.g. within a function body, and may shadow earlier
+    /// constants and variables just like `let` bindings do.
+    Sequential,
+    /// Constants are items, e.g. at the module level, and must be uniquely named.
+    ItemStyle,
+}
+
+impl Default for ConstShadowingMode {
+    fn default() -> Self {
+        ConstShadowingMode::ItemStyle
+    }
+}
@@ -1,11 +1,41 @@
 use std::fmt;
 
-use super::{TypedCodeBlock, TypedExpression};
+use sway_types::Ident;
+
+use super::{TypedAstNode, TypedAstNodeContent, TypedCodeBlock, TypedExpression};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TypedWhileLoop {
     pub condition: TypedExpression,
     pub body: TypedCodeBlock,
+    pub label: Option<Ident>,
+}
+
+impl TypedWhileLoop {
+    /// Whether the `break` or `continue` `exit`, found in the body of this loop but not in the
+    /// body of a loop nested within it, leaves this loop rather than an enclosing one.
+    pub(crate) fn is_left_by(&self, exit: &TypedAstNodeContent) -> bool {
+        match exit {
+            TypedAstNodeContent::Break(label) | TypedAstNodeContent::Continue(label) => {
+                label.is_none() || *label == self.label
+            }
+            _ => false,
+        }
+    }
+
+    /// recurse into `self` and get any `break` or `continue` statements which leave a loop
+    /// enclosing this one.
+    pub(crate) fn gather_loop_exits(&self) -> Vec<&TypedAstNodeContent> {
+        let mut buf = self.condition.gather_loop_exits();
+        buf.extend(
+            self.body
+                .contents
+                .iter()
+                .flat_map(TypedAstNode::gather_loop_exits)
+                .filter(|exit| !self.is_left_by(exit)),
+        );
+        buf
+    }
 }
 
 impl fmt::Display for TypedWhileLoop {
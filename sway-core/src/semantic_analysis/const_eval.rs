@@ -227,6 +227,15 @@ pub fn const_eval_typed_expr(
             }) => fields.get(*elem_to_access_num).cloned(),
             _ => None,
         },
+        TypedExpressionVariant::Cast { exp, cast_to } => {
+            match const_eval_typed_expr(context, module, known_consts, exp) {
+                Some(Constant {
+                    value: ConstantValue::Uint(value),
+                    ..
+                }) => Some(Constant::new_uint(64, value & cast_to.max_value())),
+                _ => None,
+            }
+        }
         TypedExpressionVariant::ArrayIndex { .. }
         | TypedExpressionVariant::IntrinsicFunction(_)
         | TypedExpressionVariant::CodeBlock(_)
@@ -259,6 +268,9 @@ fn const_eval_typed_ast_node(
         TypedAstNodeContent::Expression(e) | TypedAstNodeContent::ImplicitReturnExpression(e) => {
             const_eval_typed_expr(context, module, known_consts, e)
         }
-        TypedAstNodeContent::WhileLoop(_) | TypedAstNodeContent::SideEffect => None,
+        TypedAstNodeContent::WhileLoop(_)
+        | TypedAstNodeContent::Break(_)
+        | TypedAstNodeContent::Continue(_)
+        | TypedAstNodeContent::SideEffect => None,
     }
 }
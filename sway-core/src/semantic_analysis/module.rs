@@ -42,7 +42,8 @@ impl TypedModule {
 
         let validated_nodes_res = typed_nodes_res.flat_map(|typed_nodes| {
             let errors = check_supertraits(&typed_nodes, namespace);
-            ok(typed_nodes, vec![], errors)
+            let warnings = check_unused_imports(namespace);
+            ok(typed_nodes, warnings, errors)
         });
 
         submodules_res.flat_map(|submodules| {
@@ -54,6 +55,24 @@ impl TypedModule {
         })
     }
 
+    /// All functions marked with `#[test]` in this module and its submodules.
+    pub fn test_functions(&self) -> Vec<&TypedFunctionDeclaration> {
+        let submodule_tests = self
+            .submodules
+            .iter()
+            .flat_map(|(_, submodule)| submodule.module.test_functions());
+        let tests =
+            self.all_nodes
+                .iter()
+                .filter_map(|node| match &node.content {
+                    TypedAstNodeContent::Declaration(TypedDeclaration::FunctionDeclaration(
+                        decl,
+                    )) if decl.is_test => Some(decl),
+                    _ => None,
+                });
+        submodule_tests.chain(tests).collect()
+    }
+
     fn type_check_nodes(
         nodes: Vec<AstNode>,
         namespace: &mut Namespace,
@@ -68,7 +87,8 @@ impl TypedModule {
                     namespace,
                     return_type_annotation: insert_type(TypeInfo::Unknown),
                     help_text: Default::default(),
-                    self_type: insert_type(TypeInfo::Contract),
+                    // `Self` has no meaning at module scope.
+                    self_type: insert_type(TypeInfo::Unknown),
                     mode: Mode::NonAbi,
                     opts: Default::default(),
                 })
@@ -103,6 +123,19 @@ impl TypedSubmodule {
     }
 }
 
+/// Warn about every item which was imported into the current module by name but never used.
+fn check_unused_imports(namespace: &Namespace) -> Vec<CompileWarning> {
+    namespace
+        .module()
+        .unused_imports()
+        .into_iter()
+        .map(|name| CompileWarning {
+            span: name.span(),
+            warning_content: Warning::UnusedImport { name },
+        })
+        .collect()
+}
+
 /// Given a list of typed AST nodes and a namespace, check whether all supertrait constraints are
 /// satisfied. We're basically checking the following condition:
 ///    if trait B is implemented for type T, then trait A_i is also implemented for type T for
@@ -178,3 +211,75 @@ fn check_supertraits(
     }
     errors
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_helpers::{compile_library, compile_with_namespace},
+        CompileAstResult,
+    };
+
+    fn unused_imports(src: &str) -> Vec<String> {
+        let a = compile_library(
+            "library a;\n\npub struct Foo {\n    x: u64,\n}\n\npub struct Bar {\n    y: u64,\n}\n",
+            namespace::Module::default(),
+        );
+        let mut initial_namespace = namespace::Module::default();
+        initial_namespace.insert_submodule("a".to_string(), a);
+        let warnings = match compile_with_namespace(src, initial_namespace) {
+            CompileAstResult::Success { warnings, .. } => warnings,
+            CompileAstResult::Failure { errors, .. } => panic!("failed to compile: {:?}", errors),
+        };
+        warnings
+            .into_iter()
+            .filter_map(|warning| match warning.warning_content {
+                Warning::UnusedImport { name } => {
+                    assert_eq!(warning.span.as_str(), name.as_str());
+                    Some(name.to_string())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unused_import_warns() {
+        let src = r#"
+        script;
+
+        use a::Foo;
+        use a::Bar;
+
+        fn main() -> u64 {
+            let bar = Bar { y: 0 };
+            bar.y
+        }
+        "#;
+        assert_eq!(unused_imports(src), vec!["Foo".to_string()]);
+    }
+
+    #[test]
+    fn test_used_import_does_not_warn() {
+        let src = r#"
+        script;
+
+        use a::Foo;
+
+        fn get(foo: Foo) -> u64 {
+            foo.x
+        }
+
+        fn main() -> u64 {
+            get(Foo { x: 0 })
+        }
+        "#;
+        assert!(unused_imports(src).is_empty());
+    }
+
+    #[test]
+    fn test_unused_star_import_does_not_warn() {
+        let src = "script;\n\nuse a::*;\n\nfn main() {}\n";
+        assert!(unused_imports(src).is_empty());
+    }
+}
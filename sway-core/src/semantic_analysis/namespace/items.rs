@@ -1,14 +1,36 @@
-use crate::{error::*, namespace::*, parse_tree::*, semantic_analysis::*, type_engine::*};
+use crate::{
+    error::*, namespace::*, parse_tree::*, semantic_analysis::*, style::find_closest_match,
+    type_engine::*,
+};
 
 use super::TraitMap;
 
 use sway_types::{span::Span, Spanned};
 
-use std::sync::Arc;
+use std::{
+    collections::HashSet,
+    sync::{Arc, RwLock},
+};
 
 type SymbolMap = im::OrdMap<Ident, TypedDeclaration>;
 type UseSynonyms = im::HashMap<Ident, Vec<Ident>>;
 type UseAliases = im::HashMap<String, Ident>;
+type GlobImportSources = im::HashMap<Ident, Vec<Vec<Ident>>>;
+type Reexports = im::HashSet<Ident>;
+
+/// The imports which have been resolved through, identified by the span of the name they bring
+/// into scope.
+///
+/// This is shared between every copy of a module's namespace, so that uses of an import from
+/// within a nested scope are recorded against the module that declared it.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct UsedImports(Arc<RwLock<HashSet<Span>>>);
+
+impl PartialEq for UsedImports {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || *self.0.read().unwrap() == *other.0.read().unwrap()
+    }
+}
 
 /// The set of items that exist within some lexical scope via declaration or importing.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -26,8 +48,21 @@ pub struct Items {
     /// Aliases are introduced with syntax like `use foo::bar as baz;` syntax, where `baz` is an
     /// alias for `bar`.
     pub(crate) use_aliases: UseAliases,
+    /// Represents the absolute paths of every module a symbol was imported from through `*`.
+    ///
+    /// A symbol which was star imported from more than one module is ambiguous, which is only
+    /// reported if the symbol is actually used.
+    pub(crate) glob_import_sources: GlobImportSources,
+    /// The imported symbols which were brought in with `pub use`, and so may in turn be imported
+    /// from this module.
+    pub(crate) reexports: Reexports,
+    /// The imports in `use_synonyms` which have been used.
+    pub(crate) used_imports: UsedImports,
     /// If there is a storage declaration (which are only valid in contracts), store it here.
     pub(crate) declared_storage: Option<TypedStorageDeclaration>,
+    /// The fields of `declared_storage`, shared so that every storage access can look them up
+    /// without copying them.
+    pub(crate) storage_field_descriptors: Option<Arc<[TypedStorageField]>>,
 }
 
 impl Items {
@@ -40,13 +75,14 @@ impl Items {
         &self,
         fields: Vec<Ident>,
         storage_fields: &[TypedStorageField],
+        access_span: &Span,
     ) -> CompileResult<(TypeCheckedStorageAccess, TypeId)> {
         match self.declared_storage {
-            Some(ref storage) => storage.apply_storage_load(fields, storage_fields),
+            Some(ref storage) => storage.apply_storage_load(fields, storage_fields, access_span),
             None => err(
                 vec![],
                 vec![CompileError::NoDeclaredStorage {
-                    span: fields[0].span(),
+                    span: access_span.clone(),
                 }],
             ),
         }
@@ -59,6 +95,7 @@ impl Items {
                 vec![CompileError::MultipleStorageDeclarations { span: decl.span() }],
             );
         }
+        self.storage_field_descriptors = Some(decl.fields.clone().into());
         self.declared_storage = Some(decl);
         ok((), vec![], vec![])
     }
@@ -76,8 +113,19 @@ impl Items {
         let mut errors = vec![];
         // purposefully do not preemptively return errors so that the
         // new definiton allows later usages to compile
-        if self.symbols.get(&name).is_some() {
+        if let Some(existing) = self.symbols.get(&name) {
             match item {
+                // a variable may shadow another variable or a function parameter
+                TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                    const_decl_origin: false,
+                    ..
+                }) if matches!(
+                    existing,
+                    TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                        const_decl_origin: false,
+                        ..
+                    })
+                ) => {}
                 TypedDeclaration::EnumDeclaration { .. }
                 | TypedDeclaration::StructDeclaration { .. } => {
                     errors.push(CompileError::ShadowsOtherSymbol { name: name.clone() });
@@ -85,6 +133,18 @@ impl Items {
                 TypedDeclaration::GenericTypeForFunctionScope { .. } => {
                     errors.push(CompileError::GenericShadowsGeneric { name: name.clone() });
                 }
+                TypedDeclaration::FunctionDeclaration { .. }
+                | TypedDeclaration::TraitDeclaration { .. }
+                | TypedDeclaration::AbiDeclaration { .. }
+                | TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                    const_decl_origin: true,
+                    ..
+                }) => {
+                    errors.push(CompileError::MultipleDefinitionsOfName {
+                        name: name.clone(),
+                        span: name.span(),
+                    });
+                }
                 _ => {
                     warnings.push(CompileWarning {
                         span: name.span(),
@@ -97,6 +157,38 @@ impl Items {
         ok((), warnings, errors)
     }
 
+    /// Insert a constant declaration, which may only shadow an earlier constant or variable when
+    /// the `mode` is `ConstShadowingMode::Sequential`.
+    pub(crate) fn insert_const(
+        &mut self,
+        name: Ident,
+        item: TypedDeclaration,
+        mode: ConstShadowingMode,
+    ) -> CompileResult<()> {
+        let shadowed = match self.symbols.get(&name) {
+            Some(TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                const_decl_origin,
+                ..
+            })) => Some(*const_decl_origin),
+            _ => None,
+        };
+        match (mode, shadowed) {
+            (ConstShadowingMode::Sequential, Some(_)) => {
+                self.symbols.insert(name, item);
+                ok((), vec![], vec![])
+            }
+            (ConstShadowingMode::ItemStyle, Some(true)) => {
+                let error = CompileError::MultipleDefinitionsOfConstant {
+                    name: name.clone(),
+                    span: name.span(),
+                };
+                self.symbols.insert(name, item);
+                ok((), vec![], vec![error])
+            }
+            _ => self.insert_symbol(name, item),
+        }
+    }
+
     pub(crate) fn check_symbol(&self, name: &Ident) -> CompileResult<&TypedDeclaration> {
         match self.symbols.get(name) {
             Some(decl) => ok(decl, vec![], vec![]),
@@ -135,6 +227,23 @@ impl Items {
             .get_methods_for_type(look_up_type_id(r#type))
     }
 
+    /// Returns the methods which `trait_name` implements for `r#type`.
+    ///
+    /// Traits are compared by name only, as the recorded trait paths depend on how the trait was
+    /// imported at the impl site.
+    pub(crate) fn get_methods_for_type_and_trait(
+        &self,
+        r#type: TypeId,
+        trait_name: &CallPath,
+    ) -> Vec<TypedFunctionDeclaration> {
+        self.implemented_traits
+            .get_methods_for_type_by_trait(look_up_type_id(r#type))
+            .into_iter()
+            .filter(|(name, _)| name.suffix == trait_name.suffix)
+            .flat_map(|(_, methods)| methods)
+            .collect()
+    }
+
     // Given a TypeInfo old_type with a set of methods available to it, make those same methods
     // available to TypeInfo new_type. This is useful in situations where old_type is being
     // monomorphized to new_type and and we want `get_methods_for_type()` to return the same set of
@@ -162,6 +271,31 @@ impl Items {
         }
     }
 
+    /// Record that the import which brought `import` into scope has been used.
+    pub(crate) fn mark_import_used(&self, import: &Ident) {
+        self.used_imports.0.write().unwrap().insert(import.span());
+    }
+
+    /// The items imported by name which have never been used, in the order they were imported.
+    ///
+    /// Symbols brought in through `*` are skipped as a use of one can't be attributed to any
+    /// single import, as are those re-exported with `pub use` as they may be used elsewhere.
+    pub(crate) fn unused_imports(&self) -> Vec<Ident> {
+        let used_imports = self.used_imports.0.read().unwrap();
+        let mut unused_imports = self
+            .use_synonyms
+            .keys()
+            .filter(|name| {
+                !self.glob_import_sources.contains_key(*name)
+                    && !self.reexports.contains(*name)
+                    && !used_imports.contains(&name.span())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        unused_imports.sort_by_key(|name| name.span().start());
+        unused_imports
+    }
+
     pub(crate) fn get_canonical_path(&self, symbol: &Ident) -> &[Ident] {
         self.use_synonyms.get(symbol).map(|v| &v[..]).unwrap_or(&[])
     }
@@ -170,9 +304,9 @@ impl Items {
         self.declared_storage.is_some()
     }
 
-    pub(crate) fn get_storage_field_descriptors(&self) -> CompileResult<Vec<TypedStorageField>> {
-        if let Some(fields) = self.declared_storage.as_ref().map(|ds| ds.fields.clone()) {
-            ok(fields, vec![], vec![])
+    pub(crate) fn get_storage_field_descriptors(&self) -> CompileResult<Arc<[TypedStorageField]>> {
+        if let Some(fields) = &self.storage_field_descriptors {
+            ok(fields.clone(), vec![], vec![])
         } else {
             let msg = "unknown source location";
             let span = Span::new(Arc::from(msg), 0, msg.len(), None).unwrap();
@@ -253,6 +387,11 @@ impl Items {
                                 field_name: field_name.clone(),
                                 struct_name,
                                 available_fields: available_fields.join(", "),
+                                did_you_mean: find_closest_match(
+                                    field_name.as_str(),
+                                    available_fields,
+                                )
+                                .map(str::to_string),
                             });
                             return err(warnings, errors);
                         }
@@ -308,3 +447,176 @@ impl Items {
         ok((symbol, parent_rover), warnings, errors)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{compile_errors, compile_warnings};
+
+    #[test]
+    fn test_duplicate_function_definition() {
+        let src = r#"
+        script;
+
+        fn foo() -> bool { true }
+        fn foo() -> bool { false }
+
+        fn main() {}
+        "#;
+        let errors = compile_errors(src);
+        let spans = errors
+            .iter()
+            .filter_map(|error| match error {
+                CompileError::MultipleDefinitionsOfName { name, span }
+                    if name.as_str() == "foo" =>
+                {
+                    Some(span.start())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // The error points at the second definition.
+        assert_eq!(spans, vec![src.rfind("foo").unwrap()]);
+    }
+
+    #[test]
+    fn test_variable_shadowing_allowed() {
+        let warnings = compile_warnings(
+            r#"
+        script;
+
+        fn negate(x: bool) -> bool {
+            let x = if x { false } else { true };
+            x
+        }
+
+        fn main() -> bool {
+            let x = true;
+            let x = negate(x);
+            x
+        }
+        "#,
+        );
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_method_generic_shadows_impl_generic() {
+        let src = r#"
+        script;
+
+        struct Wrapper<T> {
+            value: T,
+        }
+
+        impl<T> Wrapper<T> {
+            fn replace<T>(self, value: T) -> T {
+                value
+            }
+        }
+
+        fn main() {}
+        "#;
+        let errors = compile_errors(src);
+        let shadowed = errors
+            .iter()
+            .filter_map(|error| match error {
+                CompileError::GenericShadowsGeneric { name } => Some(name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(shadowed, vec!["T"]);
+    }
+
+    #[test]
+    fn test_nested_generics_with_distinct_names() {
+        let src = r#"
+        script;
+
+        struct Wrapper<T> {
+            value: T,
+        }
+
+        impl<T> Wrapper<T> {
+            fn replace<U>(self, value: U) -> U {
+                value
+            }
+        }
+
+        fn main() {}
+        "#;
+        assert!(compile_errors(src).is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_module_const_is_rejected() {
+        let src = r#"
+        script;
+
+        const X: u64 = 1;
+        const X: u64 = 2;
+
+        fn main() -> u64 {
+            X
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            CompileError::MultipleDefinitionsOfConstant { name, .. } if name.as_str() == "X"
+        )));
+    }
+
+    #[test]
+    fn test_const_shadowing_within_function_is_allowed() {
+        let src = r#"
+        script;
+
+        const X: u64 = 1;
+
+        fn main() -> u64 {
+            const X: u64 = 2;
+            const X: u64 = 3;
+            X
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_storage_field_descriptors_are_shared() {
+        let storage = |name| {
+            let field = TypedStorageField::new(
+                Ident::new_no_span(name),
+                insert_type(TypeInfo::Boolean),
+                Span::dummy(),
+            );
+            TypedStorageDeclaration::new(vec![field], Span::dummy())
+        };
+        let descriptors = |namespace: &Namespace| {
+            let descriptors = namespace.get_storage_field_descriptors();
+            assert!(descriptors.errors.is_empty());
+            descriptors.value.unwrap()
+        };
+
+        let mut namespace = Namespace::init_root(Module::default());
+        assert!(namespace.get_storage_field_descriptors().value.is_none());
+        assert!(namespace
+            .set_storage_declaration(storage("a"))
+            .errors
+            .is_empty());
+
+        // every lookup hands out the same list rather than a fresh copy
+        let first = descriptors(&namespace);
+        assert!(Arc::ptr_eq(&first, &descriptors(&namespace)));
+        assert_eq!(first[0].name.as_str(), "a");
+
+        // a second storage declaration is rejected and leaves the descriptors of the first in place
+        assert!(!namespace
+            .set_storage_declaration(storage("b"))
+            .errors
+            .is_empty());
+        assert_eq!(descriptors(&namespace)[0].name.as_str(), "a");
+    }
+}
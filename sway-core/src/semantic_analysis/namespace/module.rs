@@ -6,7 +6,7 @@ use crate::{
     CompileResult, Ident, TypedDeclaration,
 };
 
-use super::{items::Items, root::Root, ModuleName, Path};
+use super::{items::Items, root::Root, ModuleName, Path, PathBuf};
 
 use sway_types::{span::Span, Spanned};
 
@@ -92,10 +92,16 @@ impl Module {
     /// Given a path to a `src` module, create synonyms to every symbol in that module to the given
     /// `dst` module.
     ///
-    /// This is used when an import path contains an asterisk.
+    /// This is used when an import path contains an asterisk. A `pub` import re-exports each of the
+    /// symbols from `dst`.
     ///
     /// Paths are assumed to be relative to `self`.
-    pub(crate) fn star_import(&mut self, src: &Path, dst: &Path) -> CompileResult<()> {
+    pub(crate) fn star_import(
+        &mut self,
+        src: &Path,
+        dst: &Path,
+        visibility: Visibility,
+    ) -> CompileResult<()> {
         let mut warnings = vec![];
         let mut errors = vec![];
         let src_ns = check!(
@@ -120,12 +126,24 @@ impl Module {
         let dst_ns = &mut self[dst];
         dst_ns.implemented_traits.extend(implemented_traits);
         for symbol in symbols {
+            // Another module already provided this symbol through `*`, so it's now ambiguous.
+            if let Some(sources) = dst_ns.glob_import_sources.get_mut(&symbol) {
+                if !sources.iter().any(|source| source == src) {
+                    sources.push(src.to_vec());
+                }
+                continue;
+            }
+            // The symbol was imported by name, which takes priority over `*`.
             if dst_ns.use_synonyms.contains_key(&symbol) {
-                errors.push(CompileError::StarImportShadowsOtherSymbol {
-                    name: symbol.clone(),
-                });
+                continue;
             }
-            dst_ns.use_synonyms.insert(symbol, src.to_vec());
+            dst_ns.use_synonyms.insert(symbol.clone(), src.to_vec());
+            if visibility == Visibility::Public {
+                dst_ns.reexports.insert(symbol.clone());
+            }
+            dst_ns
+                .glob_import_sources
+                .insert(symbol, vec![src.to_vec()]);
         }
         ok((), warnings, errors)
     }
@@ -139,13 +157,17 @@ impl Module {
         src: &Path,
         dst: &Path,
         alias: Option<Ident>,
+        visibility: Visibility,
     ) -> CompileResult<()> {
         let (last_item, src) = src.split_last().expect("guaranteed by grammar");
-        self.item_import(src, last_item, dst, alias)
+        self.item_import(src, last_item, dst, alias, visibility)
     }
 
     /// Pull a single `item` from the given `src` module and import it into the `dst` module.
     ///
+    /// If `src` only re-exports `item`, it is imported from the module which declares it. A `pub`
+    /// import in turn re-exports the item from `dst`.
+    ///
     /// Paths are assumed to be relative to `self`.
     pub(crate) fn item_import(
         &mut self,
@@ -153,9 +175,19 @@ impl Module {
         item: &Ident,
         dst: &Path,
         alias: Option<Ident>,
+        visibility: Visibility,
     ) -> CompileResult<()> {
         let mut warnings = vec![];
         let mut errors = vec![];
+        let (src, item, alias) = match self.resolve_reexport(src, item) {
+            // The item was renamed when it was re-exported, so keep the name it was imported by.
+            Some((decl_src, decl_item)) if decl_item != *item => {
+                (decl_src, decl_item, alias.or_else(|| Some(item.clone())))
+            }
+            Some((decl_src, decl_item)) => (decl_src, decl_item, alias),
+            None => (src.to_vec(), item.clone(), alias),
+        };
+        let (src, item) = (&src[..], &item);
         let src_ns = check!(
             self.check_submodule(src),
             return err(warnings, errors),
@@ -189,9 +221,12 @@ impl Module {
                 impls_to_insert.append(&mut res);
                 // no matter what, import it this way though.
                 let dst_ns = &mut self[dst];
+                // an import by name replaces any symbols of the same name imported through `*`
                 match alias {
                     Some(alias) => {
-                        if dst_ns.use_synonyms.contains_key(&alias) {
+                        if dst_ns.use_synonyms.contains_key(&alias)
+                            && dst_ns.glob_import_sources.remove(&alias).is_none()
+                        {
                             errors.push(CompileError::ShadowsOtherSymbol {
                                 name: alias.clone(),
                             });
@@ -200,12 +235,20 @@ impl Module {
                         dst_ns
                             .use_aliases
                             .insert(alias.as_str().to_string(), item.clone());
+                        if visibility == Visibility::Public {
+                            dst_ns.reexports.insert(alias);
+                        }
                     }
                     None => {
-                        if dst_ns.use_synonyms.contains_key(item) {
+                        if dst_ns.use_synonyms.contains_key(item)
+                            && dst_ns.glob_import_sources.remove(item).is_none()
+                        {
                             errors.push(CompileError::ShadowsOtherSymbol { name: item.clone() });
                         }
                         dst_ns.use_synonyms.insert(item.clone(), src.to_vec());
+                        if visibility == Visibility::Public {
+                            dst_ns.reexports.insert(item.clone());
+                        }
                     }
                 };
             }
@@ -226,6 +269,21 @@ impl Module {
 
         ok((), warnings, errors)
     }
+
+    /// Follow the `pub use` re-exports of `item` from the module at `src` back to the module which
+    /// declares it, returning the path to that module along with the item's name there.
+    fn resolve_reexport(&self, src: &Path, item: &Ident) -> Option<(PathBuf, Ident)> {
+        let src_ns = self.submodule(src)?;
+        if src_ns.symbols.contains_key(item) {
+            return Some((src.to_vec(), item.clone()));
+        }
+        if !src_ns.reexports.contains(item) {
+            return None;
+        }
+        let next_src = src_ns.use_synonyms.get(item)?;
+        let next_item = src_ns.use_aliases.get(item.as_str()).unwrap_or(item);
+        self.resolve_reexport(next_src, next_item)
+    }
 }
 
 impl std::ops::Deref for Module {
@@ -278,3 +336,57 @@ fn module_not_found(path: &[Ident]) -> CompileError {
             .join("::"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{compile_library, compile_with_namespace};
+    use crate::CompileAstResult;
+
+    /// Compiles `c_src` with the library `b`, compiled from `b_src`, which can import from the
+    /// library `a` that declares `Foo`.
+    fn compile_with_reexporting_library(b_src: &str, c_src: &str) -> CompileAstResult {
+        let a = compile_library(
+            "library a;\n\npub struct Foo {\n    x: u64,\n}\n",
+            Module::default(),
+        );
+        let mut b_namespace = Module::default();
+        b_namespace.insert_submodule("a".to_string(), a.clone());
+        let b = compile_library(b_src, b_namespace);
+
+        let mut c_namespace = Module::default();
+        c_namespace.insert_submodule("a".to_string(), a);
+        c_namespace.insert_submodule("b".to_string(), b);
+        compile_with_namespace(c_src, c_namespace)
+    }
+
+    #[test]
+    fn test_item_reexported_with_pub_use() {
+        let src = r#"
+        script;
+
+        use b::Foo;
+
+        fn main() -> u64 {
+            let foo = Foo { x: 0 };
+            foo.x
+        }
+        "#;
+        match compile_with_reexporting_library("library b;\n\npub use a::Foo;\n", src) {
+            CompileAstResult::Success { .. } => {}
+            CompileAstResult::Failure { errors, .. } => panic!("failed to compile: {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn test_item_not_reexported_without_pub_use() {
+        let src = "script;\n\nuse b::Foo;\n\nfn main() {}\n";
+        let errors = match compile_with_reexporting_library("library b;\n\nuse a::Foo;\n", src) {
+            CompileAstResult::Success { .. } => vec![],
+            CompileAstResult::Failure { errors, .. } => errors,
+        };
+        assert!(errors.iter().any(
+            |error| matches!(error, CompileError::SymbolNotFound { name } if name.as_str() == "Foo")
+        ));
+    }
+}
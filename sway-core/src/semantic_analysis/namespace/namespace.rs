@@ -1,14 +1,17 @@
 use crate::{
     semantic_analysis::{
-        ast_node::TypedExpression,
+        ast_node::{ConstShadowingMode, TypedExpression},
         declaration::{EnforceTypeArguments, Monomorphize, MonomorphizeHelper},
     },
     type_engine::*,
     CallPath, CompileResult, Ident, TypeArgument, TypeInfo, TypedDeclaration,
-    TypedFunctionDeclaration,
+    TypedFunctionDeclaration, Visibility,
 };
 
-use super::{module::Module, root::Root, submodule_namespace::SubmoduleNamespace, Path, PathBuf};
+use super::{
+    items::Items, module::Module, root::Root, submodule_namespace::SubmoduleNamespace, Path,
+    PathBuf,
+};
 
 use sway_types::{span::Span, Spanned};
 
@@ -36,6 +39,22 @@ pub struct Namespace {
     /// E.g. when type-checking the root module, this is equal to `[]`. When type-checking a
     /// submodule of the root called "foo", this would be equal to `[foo]`.
     pub(crate) mod_path: PathBuf,
+    /// Whether constants declared in the current scope may shadow earlier declarations.
+    ///
+    /// Module-level constants are items, while constants within a function body are declared in
+    /// sequence.
+    pub(crate) const_shadowing_mode: ConstShadowingMode,
+}
+
+/// The state of the current module's items at some point during type-checking, as taken by
+/// [Namespace::snapshot].
+///
+/// `Items` is built from persistent maps, so a snapshot shares its structure with the namespace
+/// rather than copying it. Restoring the snapshot discards only what was added or replaced since.
+#[derive(Clone, Debug)]
+pub(crate) struct NamespaceSnapshot {
+    mod_path: PathBuf,
+    items: Items,
 }
 
 impl Namespace {
@@ -47,6 +66,7 @@ impl Namespace {
             init,
             root,
             mod_path,
+            const_shadowing_mode: ConstShadowingMode::ItemStyle,
         }
     }
 
@@ -84,11 +104,50 @@ impl Namespace {
         &mut self.root.module[&self.mod_path]
     }
 
+    /// All symbols declared within the current [Module] that are visible outside of it.
+    ///
+    /// This is the public surface of the module, e.g. for generating a manifest of the items a
+    /// library exports. Imported symbols are not included.
+    pub fn public_items(&self) -> Vec<(Ident, TypedDeclaration)> {
+        self.symbols()
+            .iter()
+            .filter(|(_, decl)| decl.visibility().is_public())
+            .map(|(name, decl)| (name.clone(), decl.clone()))
+            .collect()
+    }
+
+    /// Record the items of the current module, so that any declared, imported or implemented
+    /// during some temporary scope may later be rolled back with [Namespace::restore].
+    pub(crate) fn snapshot(&self) -> NamespaceSnapshot {
+        NamespaceSnapshot {
+            mod_path: self.mod_path.clone(),
+            items: (**self.module()).clone(),
+        }
+    }
+
+    /// Roll the items of the current module back to the given snapshot.
+    ///
+    /// The snapshot must have been taken from within the same module.
+    pub(crate) fn restore(&mut self, snapshot: NamespaceSnapshot) {
+        debug_assert_eq!(self.mod_path, snapshot.mod_path);
+        **self.module_mut() = snapshot.items;
+    }
+
     /// Short-hand for calling [Root::resolve_symbol] on `root` with the `mod_path`.
     pub(crate) fn resolve_symbol(&self, symbol: &Ident) -> CompileResult<&TypedDeclaration> {
         self.root.resolve_symbol(&self.mod_path, symbol)
     }
 
+    /// Short-hand for calling [Root::resolve_symbol_with_suggestion] on `root` with the
+    /// `mod_path`.
+    pub(crate) fn resolve_symbol_with_suggestion(
+        &self,
+        symbol: &Ident,
+    ) -> CompileResult<&TypedDeclaration> {
+        self.root
+            .resolve_symbol_with_suggestion(&self.mod_path, symbol)
+    }
+
     /// Short-hand for calling [Root::resolve_call_path] on `root` with the `mod_path`.
     pub(crate) fn resolve_call_path(
         &self,
@@ -152,19 +211,44 @@ impl Namespace {
         method_path: &Path,
         self_type: TypeId,
         args_buf: &VecDeque<TypedExpression>,
+        as_trait: Option<&CallPath>,
     ) -> CompileResult<TypedFunctionDeclaration> {
-        self.root
-            .find_method_for_type(&self.mod_path, r#type, method_path, self_type, args_buf)
+        self.root.find_method_for_type(
+            &self.mod_path,
+            r#type,
+            method_path,
+            self_type,
+            args_buf,
+            as_trait,
+        )
+    }
+
+    /// Every method callable on `r#type` from the current module, both inherent and those provided
+    /// by trait implementations. Methods sharing a signature are only returned once.
+    pub fn all_methods_for_type(&self, r#type: TypeId) -> Vec<TypedFunctionDeclaration> {
+        let mut methods: Vec<TypedFunctionDeclaration> = vec![];
+        for method in self.module().get_methods_for_type(r#type) {
+            if !methods.iter().any(|m| m.has_same_signature(&method)) {
+                methods.push(method);
+            }
+        }
+        methods
     }
 
     /// Short-hand for performing a [Module::star_import] with `mod_path` as the destination.
-    pub(crate) fn star_import(&mut self, src: &Path) -> CompileResult<()> {
-        self.root.star_import(src, &self.mod_path)
+    pub(crate) fn star_import(&mut self, src: &Path, visibility: Visibility) -> CompileResult<()> {
+        self.root.star_import(src, &self.mod_path, visibility)
     }
 
     /// Short-hand for performing a [Module::self_import] with `mod_path` as the destination.
-    pub(crate) fn self_import(&mut self, src: &Path, alias: Option<Ident>) -> CompileResult<()> {
-        self.root.self_import(src, &self.mod_path, alias)
+    pub(crate) fn self_import(
+        &mut self,
+        src: &Path,
+        alias: Option<Ident>,
+        visibility: Visibility,
+    ) -> CompileResult<()> {
+        self.root
+            .self_import(src, &self.mod_path, alias, visibility)
     }
 
     /// Short-hand for performing a [Module::item_import] with `mod_path` as the destination.
@@ -173,8 +257,10 @@ impl Namespace {
         src: &Path,
         item: &Ident,
         alias: Option<Ident>,
+        visibility: Visibility,
     ) -> CompileResult<()> {
-        self.root.item_import(src, item, &self.mod_path, alias)
+        self.root
+            .item_import(src, item, &self.mod_path, alias, visibility)
     }
 
     /// "Enter" the submodule at the given path by returning a new [SubmoduleNamespace].
@@ -212,3 +298,120 @@ impl std::ops::DerefMut for Namespace {
         self.module_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        semantic_analysis::{TypedAstNodeContent, TypedProgramKind},
+        test_helpers::{compile_library, compile_program},
+    };
+
+    #[test]
+    fn test_namespace_public_items() {
+        let src = r#"
+        library exports;
+
+        pub struct Exported {
+            x: u64,
+        }
+
+        struct Hidden {
+            x: u64,
+        }
+
+        pub enum Choice {
+            A: (),
+            B: (),
+        }
+
+        pub const ANSWER: u64 = 42;
+
+        pub fn exported_fn() -> u64 {
+            ANSWER
+        }
+
+        fn hidden_fn() -> Hidden {
+            Hidden { x: 1 }
+        }
+        "#;
+        let namespace = Namespace::init_root(compile_library(src, Module::default()));
+        let names = namespace
+            .public_items()
+            .into_iter()
+            .map(|(name, _)| name.as_str().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["ANSWER", "Choice", "Exported", "exported_fn"]);
+    }
+
+    #[test]
+    fn test_namespace_restore_discards_items_since_snapshot() {
+        let ident = Ident::new_no_span;
+        let generic = |name: &'static str| TypedDeclaration::GenericTypeForFunctionScope {
+            name: ident(name),
+            type_id: insert_type(TypeInfo::Unknown),
+        };
+
+        let mut namespace = Namespace::init_root(Module::default());
+        namespace.insert_symbol(ident("Before"), generic("Before"));
+
+        let snapshot = namespace.snapshot();
+        namespace.insert_symbol(ident("After"), generic("After"));
+        assert!(namespace.symbols().get(&ident("After")).is_some());
+
+        namespace.restore(snapshot);
+        assert!(namespace.symbols().get(&ident("After")).is_none());
+        assert!(namespace.symbols().get(&ident("Before")).is_some());
+    }
+
+    #[test]
+    fn test_all_methods_for_type() {
+        let src = r#"
+        script;
+
+        struct Foo {
+            x: u64,
+        }
+
+        impl Foo {
+            fn inherent(self) -> u64 {
+                self.x
+            }
+        }
+
+        trait Double {
+            fn double(self) -> u64;
+        }
+
+        impl Double for Foo {
+            fn double(self) -> u64 {
+                self.x
+            }
+        }
+
+        fn main() {
+            let foo = Foo { x: 1 };
+        }
+        "#;
+        let typed_program = compile_program(src);
+        let foo_type = match &typed_program.kind {
+            TypedProgramKind::Script { main_function, .. } => {
+                match &main_function.body.contents[0].content {
+                    TypedAstNodeContent::Declaration(TypedDeclaration::VariableDeclaration(
+                        decl,
+                    )) => decl.type_ascription,
+                    _ => panic!("expected a variable declaration"),
+                }
+            }
+            _ => panic!("expected a script"),
+        };
+        let namespace = Namespace::init_root(typed_program.root.namespace);
+        let mut methods: Vec<_> = namespace
+            .all_methods_for_type(foo_type)
+            .into_iter()
+            .map(|method| method.name.to_string())
+            .collect();
+        methods.sort();
+        assert_eq!(methods, ["double", "inherent"]);
+    }
+}
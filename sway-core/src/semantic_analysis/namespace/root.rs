@@ -1,9 +1,9 @@
 use crate::{
-    error::*, semantic_analysis::*, type_engine::*, CallPath, CompileResult, Ident, TypeInfo,
-    TypedDeclaration, TypedFunctionDeclaration,
+    error::*, semantic_analysis::*, style::find_closest_match, type_engine::*, CallPath,
+    CompileResult, Ident, TypeInfo, TypedDeclaration, TypedFunctionDeclaration,
 };
 
-use super::{module::Module, namespace::Namespace, Path};
+use super::{module::Module, namespace::Namespace, Path, PathBuf};
 
 use sway_types::{span::Span, Spanned};
 
@@ -51,12 +51,39 @@ impl Root {
         symbol: &Ident,
     ) -> CompileResult<&TypedDeclaration> {
         self.check_submodule(mod_path).flat_map(|module| {
+            // A symbol declared in this module shadows those imported through `*`, and so do
+            // imports by name, which are never recorded as glob imports. A name is therefore
+            // only ambiguous when all that provides it is more than one `*` import.
+            if let Some(sources) = module.glob_import_sources.get(symbol) {
+                if module.symbols.contains_key(symbol) {
+                    return module.check_symbol(symbol);
+                }
+                if sources.len() > 1 {
+                    let candidates = sources
+                        .iter()
+                        .map(|source| CallPath {
+                            prefixes: source.clone(),
+                            suffix: symbol.clone(),
+                            is_absolute: true,
+                        })
+                        .collect();
+                    return err(
+                        vec![],
+                        vec![CompileError::AmbiguousImport {
+                            name: symbol.clone(),
+                            candidates,
+                            span: symbol.span(),
+                        }],
+                    );
+                }
+            }
             let true_symbol = self[mod_path]
                 .use_aliases
                 .get(symbol.as_str())
                 .unwrap_or(symbol);
-            match module.use_synonyms.get(symbol) {
-                Some(src_path) if mod_path != src_path => {
+            match module.use_synonyms.get_key_value(symbol) {
+                Some((import, src_path)) if mod_path != src_path => {
+                    module.mark_import_used(import);
                     self.resolve_symbol(src_path, true_symbol)
                 }
                 _ => module.check_symbol(true_symbol),
@@ -64,6 +91,88 @@ impl Root {
         })
     }
 
+    /// The absolute path of the declaration that `call_path` names from within `mod_path`.
+    ///
+    /// Imports are followed in the same way as [Root::resolve_symbol], so the resulting path
+    /// points at the module in which the symbol is actually declared.
+    pub(crate) fn absolute_call_path(&self, mod_path: &Path, call_path: &CallPath) -> CallPath {
+        let mut path: PathBuf = mod_path
+            .iter()
+            .chain(&call_path.prefixes)
+            .cloned()
+            .collect();
+        let mut symbol = call_path.suffix.clone();
+        while let Some(module) = self.submodule(&path) {
+            if module.glob_import_sources.contains_key(&symbol)
+                && module.symbols.contains_key(&symbol)
+            {
+                break;
+            }
+            let true_symbol = module
+                .use_aliases
+                .get(symbol.as_str())
+                .unwrap_or(&symbol)
+                .clone();
+            match module.use_synonyms.get(&symbol) {
+                Some(src_path) if *src_path != path => {
+                    path = src_path.clone();
+                    symbol = true_symbol;
+                }
+                _ => break,
+            }
+        }
+        CallPath {
+            prefixes: path,
+            suffix: symbol,
+            is_absolute: true,
+        }
+    }
+
+    /// Resolve a symbol as with [Root::resolve_symbol], but if the symbol cannot be found, report
+    /// the modules that were searched along with the closest-named symbol within them.
+    pub(crate) fn resolve_symbol_with_suggestion(
+        &self,
+        mod_path: &Path,
+        symbol: &Ident,
+    ) -> CompileResult<&TypedDeclaration> {
+        let mut result = self.resolve_symbol(mod_path, symbol);
+        if result.value.is_some() {
+            return result;
+        }
+        let mut searched: Vec<PathBuf> = vec![mod_path.to_vec()];
+        if let Some(src_path) = self[mod_path].use_synonyms.get(symbol) {
+            if src_path.as_slice() != mod_path {
+                searched.push(src_path.clone());
+            }
+        }
+        let candidates = searched
+            .iter()
+            .flat_map(|path| self[path.as_slice()].symbols().keys())
+            .chain(self[mod_path].use_synonyms.keys())
+            .collect::<Vec<_>>();
+        let suggestion = find_closest_match(
+            symbol.as_str(),
+            candidates.iter().map(|candidate| candidate.as_str()),
+        )
+        .and_then(|closest| {
+            candidates
+                .iter()
+                .find(|candidate| candidate.as_str() == closest)
+                .map(|candidate| (*candidate).clone())
+        });
+        for error in result.errors.iter_mut() {
+            if let CompileError::SymbolNotFound { name } = error {
+                *error = CompileError::SymbolNotFoundWithSuggestion {
+                    name: name.clone(),
+                    searched: searched.clone(),
+                    suggestion: suggestion.clone(),
+                    span: name.span(),
+                };
+            }
+        }
+        result
+    }
+
     pub(crate) fn resolve_type_with_self(
         &mut self,
         type_info: TypeInfo,
@@ -128,6 +237,11 @@ impl Root {
                     }
                 }
             }
+            // Outside of an `impl` or trait declaration there is no type for `Self` to refer to.
+            TypeInfo::SelfType if look_up_type_id(self_type) == TypeInfo::Unknown => {
+                errors.push(CompileError::SelfTypeNotAllowed { span: span.clone() });
+                insert_type(TypeInfo::ErrorRecovery)
+            }
             TypeInfo::SelfType => self_type,
             TypeInfo::Ref(id, _) => id,
             TypeInfo::Array(type_id, n) => {
@@ -145,6 +259,34 @@ impl Root {
                 );
                 insert_type(TypeInfo::Array(new_type_id, n))
             }
+            TypeInfo::GenericLengthArray(type_id, length) => {
+                match self
+                    .resolve_symbol(mod_path, &length)
+                    .ok(&mut warnings, &mut errors)
+                {
+                    Some(TypedDeclaration::GenericTypeForFunctionScope { .. }) => (),
+                    _ => {
+                        errors.push(CompileError::UnknownTypeName {
+                            name: length.to_string(),
+                            span: length.span(),
+                        });
+                        return err(warnings, errors);
+                    }
+                }
+                let new_type_id = check!(
+                    self.resolve_type_with_self(
+                        look_up_type_id(type_id),
+                        self_type,
+                        span,
+                        enforce_type_arguments,
+                        mod_path
+                    ),
+                    insert_type(TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                insert_type(TypeInfo::GenericLengthArray(new_type_id, length))
+            }
             TypeInfo::Tuple(mut type_arguments) => {
                 for type_argument in type_arguments.iter_mut() {
                     type_argument.type_id = check!(
@@ -219,6 +361,8 @@ impl Root {
                     Some(TypedDeclaration::GenericTypeForFunctionScope { name, type_id }) => {
                         insert_type(TypeInfo::Ref(type_id, name.span()))
                     }
+                    // the missing symbol has already been reported
+                    None => insert_type(TypeInfo::ErrorRecovery),
                     _ => insert_type(TypeInfo::Unknown),
                 }
             }
@@ -232,6 +376,15 @@ impl Root {
                 );
                 insert_type(TypeInfo::Array(new_type_id, n))
             }
+            TypeInfo::GenericLengthArray(type_id, length) => {
+                let new_type_id = check!(
+                    self.resolve_type_without_self(look_up_type_id(type_id), mod_path),
+                    insert_type(TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+                insert_type(TypeInfo::GenericLengthArray(new_type_id, length))
+            }
             TypeInfo::Tuple(mut type_arguments) => {
                 for type_argument in type_arguments.iter_mut() {
                     type_argument.type_id = check!(
@@ -266,10 +419,17 @@ impl Root {
         method_path: &Path,
         self_type: TypeId,
         args_buf: &VecDeque<TypedExpression>,
+        as_trait: Option<&CallPath>,
     ) -> CompileResult<TypedFunctionDeclaration> {
         let mut warnings = vec![];
         let mut errors = vec![];
 
+        // if a trait was given, only consider the methods it implements
+        let get_methods = |module: &Module, r#type| match as_trait {
+            Some(as_trait) => module.get_methods_for_type_and_trait(r#type, as_trait),
+            None => module.get_methods_for_type(r#type),
+        };
+
         // grab the local module
         let local_module = check!(
             self.check_submodule(mod_path),
@@ -279,7 +439,7 @@ impl Root {
         );
 
         // grab the local methods from the local module
-        let local_methods = local_module.get_methods_for_type(r#type);
+        let local_methods = get_methods(local_module, r#type);
 
         // split into the method name and method prefix
         let (method_name, method_prefix) = method_path.split_last().expect("method path is empty");
@@ -307,7 +467,7 @@ impl Root {
         );
 
         // grab the methods from where the type is declared
-        let mut type_methods = type_module.get_methods_for_type(r#type);
+        let mut type_methods = get_methods(type_module, r#type);
 
         let mut methods = local_methods;
         methods.append(&mut type_methods);
@@ -321,9 +481,14 @@ impl Root {
                 if args_buf.get(0).map(|x| look_up_type_id(x.return_type))
                     != Some(TypeInfo::ErrorRecovery)
                 {
+                    let type_name = look_up_type_id(r#type).display_with_path(self);
+                    let type_name = match as_trait {
+                        Some(as_trait) => format!("<{} as {}>", type_name, as_trait),
+                        None => type_name,
+                    };
                     errors.push(CompileError::MethodNotFound {
                         method_name: method_name.clone(),
-                        type_name: r#type.to_string(),
+                        type_name,
                     });
                 }
                 err(warnings, errors)
@@ -356,3 +521,319 @@ impl From<Namespace> for Root {
         namespace.root
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{
+        compile_errors, compile_library, compile_program, compile_with_namespace,
+    };
+    use crate::CompileAstResult;
+
+    /// The errors from a script which starts with `imports` and then uses `Foo`, where the
+    /// libraries `a` and `b` both declare a `Foo`.
+    fn errors_with_imports(imports: &str) -> Vec<CompileError> {
+        let mut initial_namespace = Module::default();
+        for name in ["a", "b"] {
+            let src = format!("library {};\n\npub struct Foo {{\n    x: u64,\n}}\n", name);
+            initial_namespace
+                .insert_submodule(name.to_string(), compile_library(&src, Module::default()));
+        }
+        let src = format!(
+            r#"
+        script;
+
+        {}
+
+        fn main() -> u64 {{
+            let foo = Foo {{ x: 0 }};
+            foo.x
+        }}
+        "#,
+            imports
+        );
+        match compile_with_namespace(&src, initial_namespace) {
+            CompileAstResult::Success { .. } => vec![],
+            CompileAstResult::Failure { errors, .. } => errors,
+        }
+    }
+
+    fn ambiguous_imports(imports: &str) -> Vec<(String, Vec<String>)> {
+        errors_with_imports(imports)
+            .iter()
+            .filter_map(|error| match error {
+                CompileError::AmbiguousImport {
+                    name, candidates, ..
+                } => Some((
+                    name.as_str().to_string(),
+                    candidates.iter().map(ToString::to_string).collect(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_star_imports_of_the_same_name_are_ambiguous() {
+        let ambiguous = ambiguous_imports("use a::*;\n        use b::*;");
+        assert_eq!(
+            ambiguous,
+            vec![(
+                "Foo".to_string(),
+                vec!["a::Foo".to_string(), "b::Foo".to_string()]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_single_star_import_is_not_ambiguous() {
+        assert!(ambiguous_imports("use a::*;").is_empty());
+    }
+
+    #[test]
+    fn test_local_declaration_shadows_star_imports() {
+        let errors = errors_with_imports(
+            "use a::*;\n        use b::*;\n\n        struct Foo {\n            x: u64,\n        }",
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_import_by_name_shadows_star_imports() {
+        for imports in [
+            "use a::*;\n        use b::*;\n        use a::Foo;",
+            "use a::Foo;\n        use a::*;\n        use b::*;",
+        ] {
+            let errors = errors_with_imports(imports);
+            assert!(errors.is_empty(), "{}: {:?}", imports, errors);
+        }
+    }
+
+    #[test]
+    fn test_method_not_found_qualifies_type_with_module_path() {
+        let a = compile_library(
+            "library a;\n\npub struct Foo {\n    x: u64,\n}\n\npub fn make() -> Foo {\n    Foo { x: 0 }\n}\n",
+            Module::default(),
+        );
+        let mut initial_namespace = Module::default();
+        initial_namespace.insert_submodule("a".to_string(), a);
+        let src = r#"
+        script;
+
+        use a::make;
+
+        struct Foo {
+            y: u64,
+        }
+
+        fn main() {
+            let theirs = make();
+            theirs.missing();
+            let ours = Foo { y: 0 };
+            ours.missing();
+        }
+        "#;
+        let errors = match compile_with_namespace(src, initial_namespace) {
+            CompileAstResult::Success { .. } => vec![],
+            CompileAstResult::Failure { errors, .. } => errors,
+        };
+        let type_names = errors
+            .iter()
+            .filter_map(|error| match error {
+                CompileError::MethodNotFound { type_name, .. } => Some(type_name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(type_names, vec!["a::Foo", "Foo"]);
+    }
+
+    #[test]
+    fn test_reassignment_to_misspelled_variable_suggests_name() {
+        let src = r#"
+        script;
+
+        fn main() {
+            let mut counter = 0;
+            conuter = 1;
+        }
+        "#;
+        let errors = compile_errors(src);
+        let suggestions = errors
+            .iter()
+            .filter_map(|error| match error {
+                CompileError::SymbolNotFoundWithSuggestion {
+                    name, suggestion, ..
+                } => Some((name.as_str(), suggestion.as_ref().map(Ident::as_str))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(suggestions, [("conuter", Some("counter"))]);
+    }
+
+    #[test]
+    fn test_reassignment_to_unknown_variable_has_no_suggestion() {
+        let src = r#"
+        script;
+
+        fn main() {
+            let mut counter = 0;
+            total = 1;
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            CompileError::SymbolNotFoundWithSuggestion {
+                suggestion: None,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_method_found_through_type_references() {
+        let src = r#"
+        script;
+
+        struct Point {
+            x: u64,
+        }
+
+        impl Point {
+            fn x(self) -> u64 {
+                self.x
+            }
+        }
+
+        fn main() {}
+        "#;
+        let typed_program = compile_program(src);
+        let self_type_id = typed_program
+            .root
+            .all_nodes
+            .iter()
+            .find_map(|node| match &node.content {
+                TypedAstNodeContent::Declaration(TypedDeclaration::ImplTrait(TypedImplTrait {
+                    methods,
+                    ..
+                })) => Some(methods[0].parameters[0].type_id),
+                _ => None,
+            })
+            .unwrap();
+
+        // the type engine's references are looked through, however deeply they are nested
+        let reference = insert_type(TypeInfo::Ref(self_type_id, Span::dummy()));
+        let reference = insert_type(TypeInfo::Ref(reference, Span::dummy()));
+        let mut namespace = Namespace::init_root(typed_program.root.namespace.clone());
+        let method = namespace.find_method_for_type(
+            reference,
+            &[Ident::new_no_span("x")],
+            insert_type(TypeInfo::SelfType),
+            &VecDeque::new(),
+            None,
+        );
+        assert!(method.errors.is_empty(), "{:?}", method.errors);
+        assert_eq!(method.value.unwrap().name.as_str(), "x");
+    }
+
+    #[test]
+    fn test_method_call_on_generic_return_value() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        struct Point {
+            x: u64,
+        }
+
+        impl Point {
+            fn x(self) -> u64 {
+                self.x
+            }
+        }
+
+        fn id<T>(value: T) -> T {
+            value
+        }
+
+        fn main() -> u64 {
+            let p = id(Point { x: 1 });
+            p.x()
+        }
+        "#,
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_self_type_outside_impl() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        fn f(x: Self) {}
+
+        fn main() {}
+        "#,
+        );
+        assert!(
+            matches!(
+                &errors[..],
+                [CompileError::SelfTypeNotAllowed { span }] if span.as_str() == "Self"
+            ),
+            "{:?}",
+            errors
+        );
+
+        let errors = compile_errors(
+            r#"
+        script;
+
+        struct Foo {
+            x: u64,
+        }
+
+        impl Foo {
+            fn same(self, other: Self) -> Self {
+                other
+            }
+        }
+
+        fn main() {
+            let foo = Foo { x: 0 };
+            let bar = foo.same(Foo { x: 1 });
+        }
+        "#,
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_impl_for_unknown_type_reports_only_missing_symbol() {
+        let errors = compile_errors(
+            r#"
+        script;
+
+        trait Describe {
+            fn describe(self) -> u64;
+        }
+
+        impl Describe for Missing {
+            fn describe(self) -> u64 {
+                0
+            }
+        }
+
+        fn main() {}
+        "#,
+        );
+        assert!(
+            matches!(
+                &errors[..],
+                [CompileError::SymbolNotFound { name }] if name.as_str() == "Missing"
+            ),
+            "{:?}",
+            errors
+        );
+    }
+}
@@ -418,6 +418,20 @@ impl Dependencies {
             }
             .gather_from_expr(condition)
             .gather_from_expr(then),
+            Expression::IfLet {
+                scrutinee,
+                expr,
+                then,
+                r#else,
+                ..
+            } => self
+                .gather_from_iter(
+                    scrutinee.gather_approximate_typeinfo_dependencies().iter(),
+                    |deps, type_info| deps.gather_from_typeinfo(type_info),
+                )
+                .gather_from_expr(expr)
+                .gather_from_expr(then)
+                .gather_from_opt_expr(r#else.as_deref()),
             Expression::MatchExp {
                 value, branches, ..
             } => self
@@ -468,6 +482,10 @@ impl Dependencies {
                 self.gather_from_call_path(abi_name, false, false)
             }
 
+            Expression::Cast { expr, type_arg, .. } => self
+                .gather_from_expr(expr)
+                .gather_from_typeinfo(&look_up_type_id(type_arg.type_id)),
+
             Expression::Literal { .. } => self,
             Expression::Tuple { fields, .. } => {
                 self.gather_from_iter(fields.iter(), |deps, field| deps.gather_from_expr(field))
@@ -476,6 +494,7 @@ impl Dependencies {
             Expression::StorageAccess { .. } => self,
             Expression::IntrinsicFunction { kind, .. } => match kind {
                 IntrinsicFunctionKind::SizeOfVal { exp } => self.gather_from_expr(exp),
+                IntrinsicFunctionKind::Revert { code } => self.gather_from_expr(code),
                 _ => self,
             },
         }
@@ -483,12 +502,16 @@ impl Dependencies {
 
     fn gather_from_match_branch(self, branch: &MatchBranch) -> Self {
         let MatchBranch {
-            scrutinee, result, ..
+            scrutinee,
+            guard,
+            result,
+            ..
         } = branch;
         self.gather_from_iter(
             scrutinee.gather_approximate_typeinfo_dependencies().iter(),
             |deps, type_info| deps.gather_from_typeinfo(type_info),
         )
+        .gather_from_opt_expr(guard.as_ref())
         .gather_from_expr(result)
     }
 
@@ -513,13 +536,14 @@ impl Dependencies {
             AstNodeContent::Expression(expr) => self.gather_from_expr(expr),
             AstNodeContent::ImplicitReturnExpression(expr) => self.gather_from_expr(expr),
             AstNodeContent::Declaration(decl) => self.gather_from_decl(decl),
-            AstNodeContent::WhileLoop(WhileLoop { condition, body }) => {
-                self.gather_from_expr(condition).gather_from_block(body)
-            }
+            AstNodeContent::WhileLoop(WhileLoop {
+                condition, body, ..
+            }) => self.gather_from_expr(condition).gather_from_block(body),
 
             // No deps from these guys.
             AstNodeContent::UseStatement(_) => self,
             AstNodeContent::IncludeStatement(_) => self,
+            AstNodeContent::Break(_) | AstNodeContent::Continue(_) => self,
         }
     }
 
@@ -576,7 +600,9 @@ impl Dependencies {
             TypeInfo::Tuple(elems) => self.gather_from_iter(elems.iter(), |deps, elem| {
                 deps.gather_from_typeinfo(&look_up_type_id(elem.type_id))
             }),
-            TypeInfo::Array(type_id, _) => self.gather_from_typeinfo(&look_up_type_id(*type_id)),
+            TypeInfo::Array(type_id, _) | TypeInfo::GenericLengthArray(type_id, _) => {
+                self.gather_from_typeinfo(&look_up_type_id(*type_id))
+            }
             TypeInfo::Struct { fields, .. } => self
                 .gather_from_iter(fields.iter(), |deps, field| {
                     deps.gather_from_typeinfo(&look_up_type_id(field.type_id))
@@ -734,6 +760,8 @@ fn type_info_name(type_info: &TypeInfo) -> String {
         TypeInfo::Struct { .. } => "struct",
         TypeInfo::Enum { .. } => "enum",
         TypeInfo::Array(..) => "array",
+        TypeInfo::GenericLengthArray(..) => "array",
+        TypeInfo::ConstGenericValue(..) => "const generic value",
         TypeInfo::Storage { .. } => "contract storage",
     }
     .to_string()
@@ -757,3 +785,72 @@ fn recursively_depends_on(
 // -------------------------------------------------------------------------------------------------
 //
 //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::compile_errors;
+
+    #[test]
+    fn test_mutually_recursive_types_are_rejected() {
+        let src = r#"
+        script;
+
+        struct Outer {
+            inner: Inner,
+        }
+
+        enum Inner {
+            Leaf: u64,
+            Node: Outer,
+        }
+
+        fn main() {}
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            CompileError::RecursiveTypeChain { name, type_chain, .. }
+                if (name.as_str() == "Outer" && type_chain == "Inner")
+                    || (name.as_str() == "Inner" && type_chain == "Outer")
+        )));
+    }
+
+    #[test]
+    fn test_shared_non_recursive_field_types_are_accepted() {
+        // `Leaf` is reachable from `Root` through two paths, which is not a cycle.
+        let src = r#"
+        script;
+
+        struct Leaf {
+            value: u64,
+        }
+
+        struct Left {
+            leaf: Leaf,
+        }
+
+        enum Right {
+            Empty: (),
+            Full: Leaf,
+        }
+
+        struct Root {
+            left: Left,
+            right: Right,
+            leaf: Leaf,
+        }
+
+        fn main() {
+            let leaf = Leaf { value: 1 };
+            let root = Root {
+                left: Left { leaf: leaf },
+                right: Right::Full(leaf),
+                leaf: leaf,
+            };
+        }
+        "#;
+        let errors = compile_errors(src);
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+}
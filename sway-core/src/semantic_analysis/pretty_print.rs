@@ -0,0 +1,445 @@
+//! An indented, type-annotated rendering of a typed program.
+//!
+//! Unlike the `Display` implementations on the typed AST, which produce a terse one-line summary
+//! of a single node, this walks the whole program and prints every expression along with the type
+//! that was inferred for it. It is intended for inspecting the results of type checking, e.g. via
+//! `forc build --emit=typed-ast`.
+
+use crate::{
+    parse_tree::LazyOp,
+    semantic_analysis::{ast_node::*, TypedModule, TypedProgram, TypedProgramKind},
+    type_engine::*,
+};
+
+const INDENT: &str = "  ";
+
+impl TypedProgram {
+    /// Renders the whole program, one node per line, with children indented beneath their parent.
+    pub fn pretty_print(&self) -> String {
+        let mut printer = Printer::default();
+        let header = match &self.kind {
+            TypedProgramKind::Contract { .. } => "contract".to_string(),
+            TypedProgramKind::Library { name } => format!("library {}", name),
+            TypedProgramKind::Predicate { .. } => "predicate".to_string(),
+            TypedProgramKind::Script { .. } => "script".to_string(),
+        };
+        printer.line(header);
+        printer.module(&self.root);
+        printer.out
+    }
+}
+
+#[derive(Default)]
+struct Printer {
+    out: String,
+    depth: usize,
+}
+
+impl Printer {
+    fn line(&mut self, text: impl AsRef<str>) {
+        for _ in 0..self.depth {
+            self.out.push_str(INDENT);
+        }
+        self.out.push_str(text.as_ref());
+        self.out.push('\n');
+    }
+
+    /// Prints `text` and then everything written by `children` one level deeper.
+    fn nested(&mut self, text: impl AsRef<str>, children: impl FnOnce(&mut Self)) {
+        self.line(text);
+        self.depth += 1;
+        children(self);
+        self.depth -= 1;
+    }
+
+    fn module(&mut self, module: &TypedModule) {
+        for (name, submodule) in &module.submodules {
+            self.nested(format!("dep {}", name), |p| p.module(&submodule.module));
+        }
+        self.nodes(&module.all_nodes);
+    }
+
+    fn nodes(&mut self, nodes: &[TypedAstNode]) {
+        for node in nodes {
+            self.node(node);
+        }
+    }
+
+    fn node(&mut self, node: &TypedAstNode) {
+        match &node.content {
+            TypedAstNodeContent::ReturnStatement(TypedReturnStatement { expr }) => {
+                self.nested("return", |p| p.expression(expr))
+            }
+            TypedAstNodeContent::Declaration(decl) => self.declaration(decl),
+            TypedAstNodeContent::Expression(exp) => self.expression(exp),
+            TypedAstNodeContent::ImplicitReturnExpression(exp) => {
+                self.nested("implicit return", |p| p.expression(exp))
+            }
+            TypedAstNodeContent::WhileLoop(TypedWhileLoop {
+                condition,
+                body,
+                label,
+            }) => {
+                let header = match label {
+                    Some(label) => format!("'{}: while", label),
+                    None => "while".to_string(),
+                };
+                self.nested(header, |p| {
+                    p.expression(condition);
+                    p.nodes(&body.contents);
+                })
+            }
+            TypedAstNodeContent::Break(None) => self.line("break"),
+            TypedAstNodeContent::Break(Some(label)) => self.line(format!("break '{}", label)),
+            TypedAstNodeContent::Continue(None) => self.line("continue"),
+            TypedAstNodeContent::Continue(Some(label)) => self.line(format!("continue '{}", label)),
+            TypedAstNodeContent::SideEffect => self.line("side effect"),
+        }
+    }
+
+    fn function(&mut self, func: &TypedFunctionDeclaration) {
+        let parameters = func
+            .parameters
+            .iter()
+            .map(|param| format!("{}: {}", param.name, look_up_type_id(param.type_id)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let signature = format!(
+            "fn {}({}) -> {}",
+            func.name,
+            parameters,
+            look_up_type_id(func.return_type)
+        );
+        self.nested(signature, |p| p.nodes(&func.body.contents));
+    }
+
+    fn declaration(&mut self, decl: &TypedDeclaration) {
+        match decl {
+            TypedDeclaration::VariableDeclaration(TypedVariableDeclaration {
+                name,
+                body,
+                is_mutable,
+                ..
+            }) => {
+                let keyword = match is_mutable {
+                    VariableMutability::Mutable => "let mut",
+                    VariableMutability::Immutable => "let",
+                    VariableMutability::ExportedConst => "pub const",
+                };
+                let text = format!(
+                    "{} {}: {}",
+                    keyword,
+                    name,
+                    look_up_type_id(body.return_type)
+                );
+                self.nested(text, |p| p.expression(body));
+            }
+            TypedDeclaration::ConstantDeclaration(TypedConstantDeclaration {
+                name, value, ..
+            }) => {
+                let text = format!("const {}: {}", name, look_up_type_id(value.return_type));
+                self.nested(text, |p| p.expression(value));
+            }
+            TypedDeclaration::FunctionDeclaration(func) => self.function(func),
+            TypedDeclaration::TraitDeclaration(TypedTraitDeclaration { name, .. }) => {
+                self.line(format!("trait {}", name))
+            }
+            TypedDeclaration::StructDeclaration(TypedStructDeclaration {
+                name, fields, ..
+            }) => self.nested(format!("struct {}", name), |p| {
+                for field in fields {
+                    p.line(format!(
+                        "{}: {}",
+                        field.name,
+                        look_up_type_id(field.type_id)
+                    ));
+                }
+            }),
+            TypedDeclaration::EnumDeclaration(TypedEnumDeclaration { name, variants, .. }) => self
+                .nested(format!("enum {}", name), |p| {
+                    for variant in variants {
+                        p.line(format!(
+                            "{}: {}",
+                            variant.name,
+                            look_up_type_id(variant.type_id)
+                        ));
+                    }
+                }),
+            TypedDeclaration::Reassignment(TypedReassignment {
+                lhs_base_name,
+                lhs_type,
+                lhs_indices,
+                rhs,
+            }) => {
+                let lhs = std::iter::once(lhs_base_name.to_string())
+                    .chain(
+                        lhs_indices
+                            .iter()
+                            .map(|index| format!(".{}", index.pretty_print())),
+                    )
+                    .collect::<String>();
+                let text = format!("reassign {}: {}", lhs, look_up_type_id(*lhs_type));
+                self.nested(text, |p| p.expression(rhs));
+            }
+            TypedDeclaration::ImplTrait(TypedImplTrait {
+                trait_name,
+                methods,
+                type_implementing_for,
+                ..
+            }) => {
+                let text = format!("impl {} for {}", trait_name, type_implementing_for);
+                self.nested(text, |p| {
+                    for method in methods {
+                        p.function(method);
+                    }
+                })
+            }
+            TypedDeclaration::AbiDeclaration(TypedAbiDeclaration { name, .. }) => {
+                self.line(format!("abi {}", name))
+            }
+            TypedDeclaration::GenericTypeForFunctionScope { name, type_id } => {
+                self.line(format!("generic {}: {}", name, look_up_type_id(*type_id)))
+            }
+            TypedDeclaration::ErrorRecovery => self.line("error recovery"),
+            TypedDeclaration::StorageDeclaration(TypedStorageDeclaration { fields, .. }) => self
+                .nested("storage", |p| {
+                    for field in fields {
+                        p.line(format!(
+                            "{}: {}",
+                            field.name,
+                            look_up_type_id(field.type_id)
+                        ));
+                    }
+                }),
+            TypedDeclaration::StorageReassignment(TypeCheckedStorageReassignment {
+                fields,
+                rhs,
+                ..
+            }) => {
+                let lhs = fields
+                    .iter()
+                    .map(|field| format!(".{}", field.name))
+                    .collect::<String>();
+                let ty = fields
+                    .last()
+                    .map(|field| type_id_to_string(field.type_id))
+                    .unwrap_or_default();
+                let text = format!("reassign storage{}: {}", lhs, ty);
+                self.nested(text, |p| p.expression(rhs));
+            }
+        }
+    }
+
+    fn expression(&mut self, exp: &TypedExpression) {
+        let label = match &exp.expression {
+            TypedExpressionVariant::Literal(lit) => format!("literal {}", lit),
+            TypedExpressionVariant::FunctionApplication { call_path, .. } => {
+                format!("call {}", call_path)
+            }
+            TypedExpressionVariant::LazyOperator { op, .. } => match op {
+                LazyOp::And => "&&".to_string(),
+                LazyOp::Or => "||".to_string(),
+            },
+            TypedExpressionVariant::VariableExpression { name } => format!("var {}", name),
+            TypedExpressionVariant::Tuple { .. } => "tuple".to_string(),
+            TypedExpressionVariant::Array { .. } => "array".to_string(),
+            TypedExpressionVariant::ArrayIndex { .. } => "index".to_string(),
+            TypedExpressionVariant::StructExpression { struct_name, .. } => {
+                format!("struct {}", struct_name)
+            }
+            TypedExpressionVariant::CodeBlock(_) => "block".to_string(),
+            TypedExpressionVariant::FunctionParameter => "fn param".to_string(),
+            TypedExpressionVariant::IfExp { .. } => "if".to_string(),
+            TypedExpressionVariant::AsmExpression { .. } => "asm".to_string(),
+            TypedExpressionVariant::StructFieldAccess {
+                field_to_access, ..
+            } => format!("field access .{}", field_to_access.name),
+            TypedExpressionVariant::TupleElemAccess {
+                elem_to_access_num, ..
+            } => format!("tuple access .{}", elem_to_access_num),
+            TypedExpressionVariant::EnumInstantiation {
+                enum_decl,
+                variant_name,
+                ..
+            } => format!("enum {}::{}", enum_decl.name, variant_name),
+            TypedExpressionVariant::AbiCast { abi_name, .. } => format!("abi cast {}", abi_name),
+            TypedExpressionVariant::StorageAccess(access) => {
+                let path = access
+                    .fields
+                    .iter()
+                    .map(|field| format!(".{}", field.name))
+                    .collect::<String>();
+                format!("storage access storage{}", path)
+            }
+            TypedExpressionVariant::IntrinsicFunction(kind) => match kind {
+                TypedIntrinsicFunctionKind::SizeOfVal { .. } => "size_of_val".to_string(),
+                TypedIntrinsicFunctionKind::SizeOfType { type_id, .. } => {
+                    format!("size_of {}", look_up_type_id(*type_id))
+                }
+                TypedIntrinsicFunctionKind::IsRefType { type_id, .. } => {
+                    format!("is_ref_type {}", look_up_type_id(*type_id))
+                }
+                TypedIntrinsicFunctionKind::GetStorageKey => "get_storage_key".to_string(),
+                TypedIntrinsicFunctionKind::Revert { .. } => "revert".to_string(),
+            },
+            TypedExpressionVariant::AbiName(name) => format!("abi name {}", name),
+            TypedExpressionVariant::EnumTag { .. } => "enum tag".to_string(),
+            TypedExpressionVariant::UnsafeDowncast { variant, .. } => {
+                format!("downcast to {}", variant.name)
+            }
+            TypedExpressionVariant::Cast { cast_to, .. } => {
+                format!("cast to {}", TypeInfo::UnsignedInteger(*cast_to))
+            }
+        };
+        let text = format!("{} : {}", label, look_up_type_id(exp.return_type));
+        self.nested(text, |p| p.expression_children(exp));
+    }
+
+    fn expression_children(&mut self, exp: &TypedExpression) {
+        match &exp.expression {
+            TypedExpressionVariant::FunctionApplication {
+                contract_call_params,
+                arguments,
+                ..
+            } => {
+                let mut params = contract_call_params.iter().collect::<Vec<_>>();
+                params.sort_by(|(a, _), (b, _)| a.cmp(b));
+                for (name, param) in params {
+                    self.nested(format!("call param {}", name), |p| p.expression(param));
+                }
+                for (name, arg) in arguments {
+                    self.nested(format!("arg {}", name), |p| p.expression(arg));
+                }
+            }
+            TypedExpressionVariant::LazyOperator { lhs, rhs, .. } => {
+                self.expression(lhs);
+                self.expression(rhs);
+            }
+            TypedExpressionVariant::Tuple { fields } => {
+                for field in fields {
+                    self.expression(field);
+                }
+            }
+            TypedExpressionVariant::Array { contents } => {
+                for elem in contents {
+                    self.expression(elem);
+                }
+            }
+            TypedExpressionVariant::ArrayIndex { prefix, index } => {
+                self.expression(prefix);
+                self.expression(index);
+            }
+            TypedExpressionVariant::StructExpression { fields, .. } => {
+                for field in fields {
+                    self.nested(format!("field {}", field.name), |p| {
+                        p.expression(&field.value)
+                    });
+                }
+            }
+            TypedExpressionVariant::CodeBlock(block) => self.nodes(&block.contents),
+            TypedExpressionVariant::IfExp {
+                condition,
+                then,
+                r#else,
+            } => {
+                self.expression(condition);
+                self.expression(then);
+                if let Some(r#else) = r#else {
+                    self.expression(r#else);
+                }
+            }
+            TypedExpressionVariant::AsmExpression { registers, .. } => {
+                for register in registers {
+                    if let Some(initializer) = &register.initializer {
+                        self.nested(format!("register {}", register.name), |p| {
+                            p.expression(initializer)
+                        });
+                    }
+                }
+            }
+            TypedExpressionVariant::StructFieldAccess { prefix, .. }
+            | TypedExpressionVariant::TupleElemAccess { prefix, .. } => self.expression(prefix),
+            TypedExpressionVariant::EnumInstantiation { contents, .. } => {
+                if let Some(contents) = contents {
+                    self.expression(contents);
+                }
+            }
+            TypedExpressionVariant::AbiCast { address, .. } => self.expression(address),
+            TypedExpressionVariant::IntrinsicFunction(kind) => match kind {
+                TypedIntrinsicFunctionKind::SizeOfVal { exp } => self.expression(exp),
+                TypedIntrinsicFunctionKind::Revert { code } => self.expression(code),
+                TypedIntrinsicFunctionKind::SizeOfType { .. }
+                | TypedIntrinsicFunctionKind::IsRefType { .. }
+                | TypedIntrinsicFunctionKind::GetStorageKey => {}
+            },
+            TypedExpressionVariant::EnumTag { exp }
+            | TypedExpressionVariant::UnsafeDowncast { exp, .. }
+            | TypedExpressionVariant::Cast { exp, .. } => self.expression(exp),
+            TypedExpressionVariant::Literal(_)
+            | TypedExpressionVariant::VariableExpression { .. }
+            | TypedExpressionVariant::FunctionParameter
+            | TypedExpressionVariant::StorageAccess(_)
+            | TypedExpressionVariant::AbiName(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helpers::compile_program;
+
+    #[test]
+    fn test_typed_ast_pretty_print() {
+        let src = r#"script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn origin() -> Point {
+    Point { x: 0, y: 0 }
+}
+
+fn main() -> u64 {
+    let p = origin();
+    let flag = true;
+    if flag {
+        p.x
+    } else {
+        p.y
+    }
+}
+"#;
+        let typed_program = compile_program(src);
+        let expected = r#"script
+struct Point
+  x: u64
+  y: u64
+fn origin() -> Point
+  implicit return
+    struct Point : Point
+      field x
+        literal 0 : u64
+      field y
+        literal 0 : u64
+fn main() -> u64
+  let p: Point
+    call origin : Point
+  let flag: bool
+    literal true : bool
+  implicit return
+    if : u64
+      var flag : bool
+      block : u64
+        implicit return
+          field access .x : u64
+            var p : Point
+      block : u64
+        implicit return
+          field access .y : u64
+            var p : Point
+"#;
+        assert_eq!(typed_program.pretty_print(), expected);
+    }
+}
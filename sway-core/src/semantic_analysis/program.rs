@@ -69,7 +69,6 @@ impl TypedProgram {
         let mut mains = Vec::new();
         let mut declarations = Vec::new();
         let mut abi_entries = Vec::new();
-        let mut fn_declarations = std::collections::HashSet::new();
         for node in &root.all_nodes {
             match &node.content {
                 TypedAstNodeContent::Declaration(TypedDeclaration::FunctionDeclaration(func))
@@ -85,17 +84,8 @@ impl TypedProgram {
                     ..
                 })) => abi_entries.extend(methods.clone()),
                 // XXX we're excluding the above ABI methods, is that OK?
-                TypedAstNodeContent::Declaration(decl) => {
-                    // Variable and constant declarations don't need a duplicate check.
-                    // Type declarations are checked elsewhere. That leaves functions.
-                    if let TypedDeclaration::FunctionDeclaration(func) = &decl {
-                        let name = func.name.clone();
-                        if !fn_declarations.insert(name.clone()) {
-                            errors.push(CompileError::MultipleDefinitionsOfFunction { name });
-                        }
-                    }
-                    declarations.push(decl.clone())
-                }
+                // Duplicate declarations are reported when they're inserted into the namespace.
+                TypedAstNodeContent::Declaration(decl) => declarations.push(decl.clone()),
                 _ => (),
             };
         }
@@ -124,6 +114,15 @@ impl TypedProgram {
             }
         }
 
+        // contract calls are only checked against the function's purity in contracts, as
+        // impure functions are disallowed elsewhere
+        if kind == TreeType::Contract {
+            errors.extend(disallow_contract_calls_in_pure_functions(
+                &declarations,
+                &abi_entries,
+            ));
+        }
+
         // Perform other validation based on the tree type.
         let typed_program_kind = match kind {
             TreeType::Contract => TypedProgramKind::Contract {
@@ -137,11 +136,6 @@ impl TypedProgram {
                     errors.push(CompileError::NoPredicateMainFunction(module_span));
                     return err(vec![], errors);
                 }
-                if mains.len() > 1 {
-                    errors.push(CompileError::MultipleDefinitionsOfFunction {
-                        name: mains.last().unwrap().name.clone(),
-                    });
-                }
                 let main_func = mains.remove(0);
                 match look_up_type_id(main_func.return_type) {
                     TypeInfo::Boolean => (),
@@ -160,11 +154,6 @@ impl TypedProgram {
                     errors.push(CompileError::NoScriptMainFunction(module_span));
                     return err(vec![], errors);
                 }
-                if mains.len() > 1 {
-                    errors.push(CompileError::MultipleDefinitionsOfFunction {
-                        name: mains.last().unwrap().name.clone(),
-                    });
-                }
                 TypedProgramKind::Script {
                     main_function: mains.remove(0),
                     declarations,
@@ -175,6 +164,16 @@ impl TypedProgram {
         ok(typed_program_kind, vec![], errors)
     }
 
+    /// The names and spans of every `#[test]` function in the program, including those declared
+    /// in submodules.
+    pub fn list_tests(&self) -> Vec<(Ident, Span)> {
+        self.root
+            .test_functions()
+            .into_iter()
+            .map(|decl| (decl.name.clone(), decl.span()))
+            .collect()
+    }
+
     /// Ensures there are no unresolved types or types awaiting resolution in the AST.
     pub(crate) fn finalize_types(&self) -> CompileResult<()> {
         // Get all of the entry points for this tree type. For libraries, that's everything
@@ -264,6 +263,34 @@ impl TypedProgramKind {
     }
 }
 
+/// A call to another contract reads and writes that contract's state, so any function in a
+/// contract which makes one must be declared `#[storage(read, write)]`.
+fn disallow_contract_calls_in_pure_functions(
+    declarations: &[TypedDeclaration],
+    abi_entries: &[TypedFunctionDeclaration],
+) -> Vec<CompileError> {
+    let fn_decls = declarations
+        .iter()
+        .flat_map(|decl| match decl {
+            TypedDeclaration::FunctionDeclaration(decl) => vec![decl],
+            TypedDeclaration::ImplTrait(TypedImplTrait { methods, .. }) => methods.iter().collect(),
+            _ => vec![],
+        })
+        .chain(abi_entries);
+    fn_decls
+        .filter(|TypedFunctionDeclaration { purity, .. }| *purity != Purity::ReadsWrites)
+        .flat_map(|TypedFunctionDeclaration { body, .. }| {
+            body.contents
+                .iter()
+                .flat_map(|node| node.gather_contract_calls())
+                .map(|call| CompileError::ContractCallInPureFunction {
+                    span: call.span.clone(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 fn disallow_impure_functions(
     declarations: &[TypedDeclaration],
     mains: &[TypedFunctionDeclaration],
@@ -285,3 +312,118 @@ fn disallow_impure_functions(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_helpers::{compile, compile_errors},
+        CompileAstResult,
+    };
+
+    fn contract_calling_another(attribute: &str, body: &str) -> String {
+        format!(
+            r#"
+        contract;
+
+        abi Counter {{
+            fn increment() -> u64;
+        }}
+
+        abi Caller {{
+            {attribute}
+            fn call_counter() -> u64;
+        }}
+
+        impl Caller for Contract {{
+            {attribute}
+            fn call_counter() -> u64 {{
+                let counter = abi(Counter, 0x0000000000000000000000000000000000000000000000000000000000000000);
+                {body}
+            }}
+        }}
+        "#
+        )
+    }
+
+    fn contract_call_in_pure_function_spans(body: &str) -> Vec<String> {
+        compile_errors(&contract_calling_another("", body))
+            .iter()
+            .map(|error| match error {
+                CompileError::ContractCallInPureFunction { span } => span.as_str().to_string(),
+                _ => panic!("unexpected error: {:?}", error),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_contract_call_in_pure_function() {
+        let spans = contract_call_in_pure_function_spans("counter.increment()");
+        assert_eq!(spans, vec!["counter.increment()".to_string()]);
+    }
+
+    #[test]
+    fn test_contract_call_in_asm_register_initializer() {
+        let spans =
+            contract_call_in_pure_function_spans("asm(r1: counter.increment()) { r1: u64 }");
+        assert_eq!(spans, vec!["counter.increment()".to_string()]);
+    }
+
+    #[test]
+    fn test_contract_call_in_revert_code() {
+        let spans = contract_call_in_pure_function_spans("__revert(counter.increment())");
+        assert_eq!(spans, vec!["counter.increment()".to_string()]);
+    }
+
+    #[test]
+    fn test_contract_call_in_read_write_function() {
+        let errors = compile_errors(&contract_calling_another(
+            "#[storage(read, write)]",
+            "counter.increment()",
+        ));
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_list_tests() {
+        let src = r#"
+        script;
+
+        enum Result<T, E> {
+            Ok: T,
+            Err: E,
+        }
+
+        #[test]
+        fn returns_unit() {}
+
+        #[test]
+        fn returns_result() -> Result<(), u64> {
+            Result::Ok(())
+        }
+
+        fn main() {}
+        "#;
+        let (typed_program, warnings) = match compile(src) {
+            CompileAstResult::Success {
+                typed_program,
+                warnings,
+            } => (typed_program, warnings),
+            CompileAstResult::Failure { errors, .. } => panic!("failed to compile: {:?}", errors),
+        };
+        let tests: Vec<_> = typed_program
+            .list_tests()
+            .into_iter()
+            .map(|(name, _span)| name.to_string())
+            .collect();
+        assert_eq!(tests, ["returns_unit", "returns_result"]);
+        // tests are entry points, so they are never reported as dead code
+        assert!(
+            !warnings
+                .iter()
+                .any(|warning| matches!(warning.warning_content, Warning::DeadFunctionDeclaration)),
+            "{:?}",
+            warnings
+        );
+    }
+}
@@ -15,4 +15,7 @@ pub struct TypeCheckArguments<'a, T> {
 #[derive(Default, Clone, Copy)]
 pub struct TCOpts {
     pub(crate) purity: Purity,
+    /// Whether the node being checked is inside a loop body, where `break` and `continue` are
+    /// allowed.
+    pub(crate) in_loop: bool,
 }
@@ -148,6 +148,26 @@ pub fn to_upper_camel_case(ident: &str) -> String {
     ret
 }
 
+/// Find the candidate closest to `ident`, if any is close enough that `ident` is likely to be a
+/// typo of it. Identifiers are compared in snake_case so that style mistakes such as `myField`
+/// for `my_field` are always caught.
+pub(crate) fn find_closest_match<'a>(
+    ident: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let ident_snake = to_snake_case(ident);
+    let threshold = std::cmp::max(1, ident_snake.len() / 3);
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            let distance = strsim::levenshtein(&ident_snake, &to_snake_case(candidate));
+            (distance, candidate)
+        })
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -292,4 +312,14 @@ mod test {
         assert_eq!("__MixOfThings", to_upper_camel_case("__Mix_Of_Things"));
         assert_eq!("FooBar123", to_upper_camel_case("FooBar_123"));
     }
+
+    #[test]
+    fn closest_match() {
+        let candidates = ["my_field", "other_field", "x"];
+        assert_eq!(Some("my_field"), find_closest_match("myField", candidates));
+        assert_eq!(Some("my_field"), find_closest_match("my_feild", candidates));
+        assert_eq!(Some("x"), find_closest_match("y", candidates));
+        assert_eq!(None, find_closest_match("something_else", candidates));
+        assert_eq!(None, find_closest_match("my_field", []));
+    }
 }
@@ -0,0 +1,106 @@
+//! Helpers for unit tests which compile small Sway programs.
+
+use crate::{
+    compile_to_asm, compile_to_ast,
+    semantic_analysis::{namespace, TypedProgram},
+    type_engine::{insert_type, CopyTypes, TypeMapping, UnresolvedTypeCheck},
+    BuildConfig, CompilationResult, CompileAstResult, CompileError, CompileWarning, TypeInfo,
+    TypeParameter, TypeParameterKind,
+};
+use std::path::PathBuf;
+use sway_types::Ident;
+
+/// Type checks `src` with an empty initial namespace.
+pub(crate) fn compile(src: &str) -> CompileAstResult {
+    compile_with_namespace(src, namespace::Module::default())
+}
+
+/// Type checks `src` with `initial_namespace`, e.g. one holding previously compiled libraries.
+pub(crate) fn compile_with_namespace(
+    src: &str,
+    initial_namespace: namespace::Module,
+) -> CompileAstResult {
+    compile_to_ast(src.into(), initial_namespace, None)
+}
+
+/// The errors from type checking `src`, which are empty if it compiles.
+pub(crate) fn compile_errors(src: &str) -> Vec<CompileError> {
+    match compile(src) {
+        CompileAstResult::Success { .. } => vec![],
+        CompileAstResult::Failure { errors, .. } => errors,
+    }
+}
+
+/// The typed program from type checking `src`, which must compile.
+pub(crate) fn compile_program(src: &str) -> TypedProgram {
+    match compile(src) {
+        CompileAstResult::Success { typed_program, .. } => *typed_program,
+        CompileAstResult::Failure { errors, .. } => panic!("failed to compile: {:?}", errors),
+    }
+}
+
+/// The warnings from type checking `src`, which must compile.
+pub(crate) fn compile_warnings(src: &str) -> Vec<CompileWarning> {
+    match compile(src) {
+        CompileAstResult::Success { warnings, .. } => warnings,
+        CompileAstResult::Failure { errors, .. } => panic!("failed to compile: {:?}", errors),
+    }
+}
+
+/// Type checks the library `src` and returns its namespace, ready to be inserted as a submodule
+/// of a dependent program's initial namespace.
+pub(crate) fn compile_library(
+    src: &str,
+    initial_namespace: namespace::Module,
+) -> namespace::Module {
+    match compile_with_namespace(src, initial_namespace) {
+        CompileAstResult::Success { typed_program, .. } => typed_program.root.namespace,
+        CompileAstResult::Failure { errors, .. } => panic!("failed to compile: {:?}", errors),
+    }
+}
+
+/// The errors from compiling `src` all the way to assembly, which are empty if it compiles.
+pub(crate) fn compile_to_asm_errors(src: &str) -> Vec<CompileError> {
+    let build_config = BuildConfig::root_from_file_name_and_manifest_path(
+        PathBuf::from("/project/src/main.sw"),
+        PathBuf::from("/project"),
+    );
+    match compile_to_asm(src.into(), namespace::Module::default(), build_config) {
+        CompilationResult::Failure { errors, .. } => errors,
+        CompilationResult::Success { .. } | CompilationResult::Library { .. } => vec![],
+    }
+}
+
+/// Asserts that `decl` refers to a generic which `type_mapping` doesn't yet resolve, and that once
+/// the mapping is applied with `copy_types` every `TypeId` within it has been remapped.
+pub(crate) fn assert_copy_types_is_complete<T>(decl: &T, type_mapping: &TypeMapping)
+where
+    T: Clone + CopyTypes + UnresolvedTypeCheck,
+{
+    assert!(!decl.check_for_unresolved_types().is_empty());
+    let mut copy = decl.clone();
+    copy.copy_types(type_mapping);
+    let errors = copy.check_for_unresolved_types();
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+/// A type parameter named `name` which hasn't been given a type.
+pub(crate) fn unknown_generic(name: &'static str) -> TypeParameter {
+    let name_ident = Ident::new_no_span(name);
+    TypeParameter {
+        type_id: insert_type(TypeInfo::UnknownGeneric {
+            name: name_ident.clone(),
+        }),
+        name_ident,
+        trait_constraints: vec![],
+        kind: TypeParameterKind::Type,
+    }
+}
+
+/// A mapping which gives each of `type_parameters` the type `bool`.
+pub(crate) fn map_to_bool(type_parameters: &[TypeParameter]) -> TypeMapping {
+    type_parameters
+        .iter()
+        .map(|param| (param.clone(), insert_type(TypeInfo::Boolean)))
+        .collect()
+}
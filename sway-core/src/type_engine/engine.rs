@@ -2,6 +2,7 @@ use super::*;
 use crate::concurrent_slab::ConcurrentSlab;
 use crate::type_engine::AbiName;
 use lazy_static::lazy_static;
+use std::{collections::HashMap, sync::RwLock};
 use sway_types::span::Span;
 use sway_types::Spanned;
 
@@ -12,13 +13,52 @@ lazy_static! {
 #[derive(Debug, Default)]
 pub(crate) struct Engine {
     slab: ConcurrentSlab<TypeInfo>,
+    interned: RwLock<HashMap<TypeInfo, TypeId>>,
 }
 
 impl Engine {
     pub fn insert_type(&self, ty: TypeInfo) -> TypeId {
+        #[cfg(feature = "check-type-interning")]
+        if is_internable(&ty) {
+            let id = self.slab.insert(ty.clone());
+            self.check_duplicate_insertion(ty, id);
+            return id;
+        }
         self.slab.insert(ty)
     }
 
+    /// Returns the id of an identical type which is already in the engine, inserting `ty` if
+    /// there isn't one. Only concrete types are shared, see `is_internable`; any other type always
+    /// gets a new id.
+    pub fn intern(&self, ty: TypeInfo) -> TypeId {
+        if !is_internable(&ty) {
+            return self.insert_type(ty);
+        }
+        if let Some(id) = self.interned.read().unwrap().get(&ty) {
+            return *id;
+        }
+        let mut interned = self.interned.write().unwrap();
+        *interned
+            .entry(ty.clone())
+            .or_insert_with(|| self.slab.insert(ty))
+    }
+
+    #[cfg(feature = "check-type-interning")]
+    fn check_duplicate_insertion(&self, ty: TypeInfo, id: TypeId) {
+        let mut interned = self.interned.write().unwrap();
+        match interned.get(&ty) {
+            Some(existing) => tracing::warn!(
+                "type `{}` was inserted as id {} but is identical to id {}",
+                ty,
+                *id,
+                **existing
+            ),
+            None => {
+                interned.insert(ty, id);
+            }
+        }
+    }
+
     pub fn look_up_type_id_raw(&self, id: TypeId) -> TypeInfo {
         self.slab.get(id)
     }
@@ -286,6 +326,27 @@ impl Engine {
                 (warnings, errors)
             }
 
+            // Arrays of a generic length only match within the declaration of the parameter.
+            (GenericLengthArray(a_elem, a_length), GenericLengthArray(b_elem, b_length))
+                if a_length == b_length =>
+            {
+                let (warnings, new_errors) = self.unify(a_elem, b_elem, span, help_text.clone());
+
+                // As with arrays, report the array types as mismatching rather than the elem types.
+                let mut errors = vec![];
+                if !new_errors.is_empty() {
+                    errors.push(TypeError::MismatchedType {
+                        expected,
+                        received,
+                        help_text,
+                        span: span.clone(),
+                    });
+                }
+                (warnings, errors)
+            }
+
+            (ConstGenericValue(a), ConstGenericValue(b)) if a == b => (vec![], vec![]),
+
             (
                 TypeInfo::ContractCaller {
                     abi_name: ref abi_name_a,
@@ -358,6 +419,116 @@ impl Engine {
         self.unify(received, expected, span, help_text)
     }
 
+    /// Checks whether a value of type `received` could be used where `expected` is required,
+    /// with the same coercions as [Engine::unify], without reporting any errors.
+    ///
+    /// This only compares the types, so unlike [Engine::unify] it never infers an unresolved
+    /// type or inserts any new ones.
+    pub fn can_unify(&self, received: TypeId, expected: TypeId) -> bool {
+        use TypeInfo::*;
+        match (
+            self.look_up_type_id(received),
+            self.look_up_type_id(expected),
+        ) {
+            (Boolean, Boolean)
+            | (SelfType, SelfType)
+            | (Byte, Byte)
+            | (B256, B256)
+            | (Numeric, Numeric)
+            | (Contract, Contract) => true,
+            (Str(l), Str(r)) => l == r,
+
+            // unification would infer these from the other type
+            (Unknown, _) | (_, Unknown) => true,
+            (UnknownGeneric { .. }, _) | (_, UnknownGeneric { .. }) => true,
+
+            (UnsignedInteger(_), UnsignedInteger(_)) => true,
+            (Numeric, UnsignedInteger(_)) | (UnsignedInteger(_), Numeric) => true,
+
+            (Tuple(fields_a), Tuple(fields_b)) => {
+                fields_a.len() == fields_b.len()
+                    && fields_a
+                        .iter()
+                        .zip(fields_b.iter())
+                        .all(|(a, b)| self.can_unify(a.type_id, b.type_id))
+            }
+            (
+                Struct {
+                    name: a_name,
+                    fields: a_fields,
+                    type_parameters: a_parameters,
+                    ..
+                },
+                Struct {
+                    name: b_name,
+                    fields: b_fields,
+                    type_parameters: b_parameters,
+                    ..
+                },
+            ) => {
+                a_name == b_name
+                    && a_fields.len() == b_fields.len()
+                    && a_parameters.len() == b_parameters.len()
+                    && a_fields
+                        .iter()
+                        .zip(b_fields.iter())
+                        .all(|(a, b)| self.can_unify(a.type_id, b.type_id))
+                    && a_parameters
+                        .iter()
+                        .zip(b_parameters.iter())
+                        .all(|(a, b)| self.can_unify(a.type_id, b.type_id))
+            }
+            (
+                Enum {
+                    name: a_name,
+                    variant_types: a_variants,
+                    type_parameters: a_parameters,
+                },
+                Enum {
+                    name: b_name,
+                    variant_types: b_variants,
+                    type_parameters: b_parameters,
+                },
+            ) => {
+                a_name == b_name
+                    && a_variants.len() == b_variants.len()
+                    && a_parameters.len() == b_parameters.len()
+                    && a_variants
+                        .iter()
+                        .zip(b_variants.iter())
+                        .all(|(a, b)| self.can_unify(a.type_id, b.type_id))
+                    && a_parameters
+                        .iter()
+                        .zip(b_parameters.iter())
+                        .all(|(a, b)| self.can_unify(a.type_id, b.type_id))
+            }
+            (Array(a_elem, a_count), Array(b_elem, b_count)) => {
+                a_count == b_count && self.can_unify(a_elem, b_elem)
+            }
+            (GenericLengthArray(a_elem, a_length), GenericLengthArray(b_elem, b_length)) => {
+                a_length == b_length && self.can_unify(a_elem, b_elem)
+            }
+            (ConstGenericValue(a), ConstGenericValue(b)) => a == b,
+            (
+                ContractCaller {
+                    abi_name: abi_name_a,
+                    address: address_a,
+                },
+                ContractCaller {
+                    abi_name: abi_name_b,
+                    address: address_b,
+                },
+            ) => {
+                (abi_name_a == abi_name_b && (address_a.is_none() || address_b.is_none()))
+                    || matches!(abi_name_a, AbiName::Deferred)
+                    || matches!(abi_name_b, AbiName::Deferred)
+            }
+
+            (ErrorRecovery, _) | (_, ErrorRecovery) => true,
+            (_, _) => false,
+        }
+    }
+
     pub fn resolve_type(&self, id: TypeId, error_span: &Span) -> Result<TypeInfo, TypeError> {
         match self.look_up_type_id(id) {
             TypeInfo::Unknown => Err(TypeError::UnknownType {
@@ -372,10 +543,40 @@ pub fn insert_type(ty: TypeInfo) -> TypeId {
     TYPE_ENGINE.insert_type(ty)
 }
 
+pub fn intern_type(ty: TypeInfo) -> TypeId {
+    TYPE_ENGINE.intern(ty)
+}
+
+/// Whether every use of `ty` may share a single id. Unknown types are refined in place during
+/// unification, so only types which contain no other type ids are shared; an interned aggregate
+/// could otherwise change from under its other users.
+fn is_internable(ty: &TypeInfo) -> bool {
+    matches!(
+        ty,
+        TypeInfo::Str(_)
+            | TypeInfo::UnsignedInteger(_)
+            | TypeInfo::Boolean
+            | TypeInfo::Byte
+            | TypeInfo::B256
+            | TypeInfo::Contract
+            | TypeInfo::ConstGenericValue(_)
+    )
+}
+
 pub fn look_up_type_id(id: TypeId) -> TypeInfo {
     TYPE_ENGINE.look_up_type_id(id)
 }
 
+/// Renders a type the way it should be shown to a user. `Ref` chains are followed
+/// through to the type they point at, at every level of nesting, and generic
+/// parameters are shown by name (`T`) rather than by their numeric id.
+///
+/// Diagnostics, hover information and other tooling should all go through this
+/// function so that a type is always rendered the same way.
+pub fn type_id_to_string(id: TypeId) -> String {
+    look_up_type_id(id).to_string()
+}
+
 pub(crate) fn look_up_type_id_raw(id: TypeId) -> TypeInfo {
     TYPE_ENGINE.look_up_type_id_raw(id)
 }
@@ -399,6 +600,16 @@ pub(crate) fn unify(
     TYPE_ENGINE.unify(a, b, span, help_text)
 }
 
+/// Returns whether a value of type `received` can be used where `expected` is required, without
+/// reporting any errors.
+///
+/// This has coercion semantics: a numeric literal can become any integer type and integers can
+/// be widened, just like in a type checked assignment. Narrowing an integer also counts, since
+/// the compiler only warns about the loss of precision. Nothing is inferred by asking.
+pub fn can_unify(received: TypeId, expected: TypeId) -> bool {
+    TYPE_ENGINE.can_unify(received, expected)
+}
+
 pub fn resolve_type(id: TypeId, error_span: &Span) -> Result<TypeInfo, TypeError> {
     TYPE_ENGINE.resolve_type(id, error_span)
 }
@@ -8,6 +8,19 @@ pub enum IntegerBits {
     SixtyFour,
 }
 
+impl IntegerBits {
+    /// The largest value representable by an unsigned integer of this width.
+    pub(crate) fn max_value(&self) -> u64 {
+        use IntegerBits::*;
+        match self {
+            Eight => u8::MAX as u64,
+            Sixteen => u16::MAX as u64,
+            ThirtyTwo => u32::MAX as u64,
+            SixtyFour => u64::MAX,
+        }
+    }
+}
+
 impl fmt::Display for IntegerBits {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use IntegerBits::*;
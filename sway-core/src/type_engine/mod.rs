@@ -18,7 +18,7 @@ pub(crate) use replace_self_type::*;
 pub(crate) use resolved_type::*;
 pub use type_id::*;
 pub use type_info::*;
-pub(crate) use type_mapping::*;
+pub use type_mapping::*;
 pub(crate) use unresolved_type_check::*;
 pub(crate) use update_types::*;
 
@@ -82,6 +82,68 @@ fn generic_enum_resolution() {
     }
 }
 
+#[test]
+fn interning_shares_concrete_types() {
+    let engine = Engine::default();
+    let bool_1 = engine.intern(TypeInfo::Boolean);
+    let bool_2 = engine.intern(TypeInfo::Boolean);
+    assert_eq!(*bool_1, *bool_2);
+
+    // unknown types are refined in place, so they're never shared
+    let unknown_1 = engine.intern(TypeInfo::Unknown);
+    let unknown_2 = engine.intern(TypeInfo::Unknown);
+    assert_ne!(*unknown_1, *unknown_2);
+}
+
+#[test]
+fn type_id_to_string_shows_generics_by_name() {
+    use crate::{span::Span, Ident, TypeParameter, TypeParameterKind};
+    let sp = Span::dummy();
+    let t = insert_type(TypeInfo::UnknownGeneric {
+        name: Ident::new_with_override("T", sp.clone()),
+    });
+    let foo = insert_type(TypeInfo::Struct {
+        name: Ident::new_with_override("Foo", sp.clone()),
+        type_parameters: vec![TypeParameter {
+            type_id: t,
+            name_ident: Ident::new_with_override("T", sp),
+            trait_constraints: vec![],
+            kind: TypeParameterKind::Type,
+        }],
+        fields: vec![],
+    });
+    assert_eq!(type_id_to_string(foo), "Foo<T>");
+}
+
+#[test]
+fn type_id_to_string_tuple() {
+    use crate::{span::Span, TypeArgument};
+    let fields = [
+        TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+        TypeInfo::Boolean,
+    ]
+    .into_iter()
+    .map(|ty| TypeArgument {
+        type_id: insert_type(ty),
+        span: Span::dummy(),
+    })
+    .collect();
+    let tuple = insert_type(TypeInfo::Tuple(fields));
+    assert_eq!(type_id_to_string(tuple), "(u64, bool)");
+}
+
+#[test]
+fn type_id_to_string_follows_refs() {
+    use crate::span::Span;
+    let b256 = insert_type(TypeInfo::B256);
+    let inner = insert_type(TypeInfo::Ref(b256, Span::dummy()));
+    let outer = insert_type(TypeInfo::Ref(inner, Span::dummy()));
+    assert_eq!(type_id_to_string(outer), "b256");
+
+    let array = insert_type(TypeInfo::Array(outer, 2));
+    assert_eq!(type_id_to_string(array), "[b256; 2]");
+}
+
 #[test]
 fn basic_numeric_unknown() {
     use sway_types::Span;
@@ -141,3 +203,32 @@ fn chain_of_refs_2() {
         TypeInfo::UnsignedInteger(IntegerBits::Eight)
     );
 }
+
+#[test]
+fn can_unify_without_inferring() {
+    let engine = Engine::default();
+    let u8_type = engine.insert_type(TypeInfo::UnsignedInteger(IntegerBits::Eight));
+    let u64_type = engine.insert_type(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour));
+    let bool_type = engine.insert_type(TypeInfo::Boolean);
+    assert!(engine.can_unify(u8_type, u64_type));
+    assert!(!engine.can_unify(bool_type, u64_type));
+    assert!(engine.can_unify(bool_type, bool_type));
+
+    // asking doesn't commit a numeric literal to the type it's checked against
+    let numeric_type = engine.insert_type(TypeInfo::Numeric);
+    assert!(engine.can_unify(numeric_type, u8_type));
+    assert!(matches!(
+        engine.look_up_type_id(numeric_type),
+        TypeInfo::Numeric
+    ));
+
+    // nor an unknown type, and no types are inserted along the way
+    let unknown_type = engine.insert_type(TypeInfo::Unknown);
+    assert!(engine.can_unify(unknown_type, u8_type));
+    assert!(matches!(
+        engine.look_up_type_id(unknown_type),
+        TypeInfo::Unknown
+    ));
+    let next_type = engine.insert_type(TypeInfo::Boolean);
+    assert_eq!(*next_type, *unknown_type + 1);
+}
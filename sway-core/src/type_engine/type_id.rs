@@ -18,7 +18,7 @@ impl std::ops::Deref for TypeId {
 
 impl fmt::Display for TypeId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&look_up_type_id(*self).to_string())
+        f.write_str(&type_id_to_string(*self))
     }
 }
 
@@ -37,15 +37,12 @@ impl From<usize> for TypeId {
 impl UnresolvedTypeCheck for TypeId {
     fn check_for_unresolved_types(&self) -> Vec<CompileError> {
         use TypeInfo::*;
-        let span_override = if let TypeInfo::Ref(_, span) = look_up_type_id_raw(*self) {
-            Some(span)
-        } else {
-            None
-        };
         match look_up_type_id(*self) {
+            // The name is spanned by the type parameter's declaration, which is more useful than
+            // wherever the generic happened to be last unified.
             UnknownGeneric { name } => vec![CompileError::UnableToInferGeneric {
                 ty: name.as_str().to_string(),
-                span: span_override.unwrap_or_else(|| name.span()),
+                span: name.span(),
             }],
             _ => vec![],
         }
@@ -128,7 +125,7 @@ impl ReplaceSelfType for TypeId {
                     type_argument.replace_self_type(self_type);
                 }
             }
-            TypeInfo::Array(mut type_id, _) => {
+            TypeInfo::Array(mut type_id, _) | TypeInfo::GenericLengthArray(mut type_id, _) => {
                 type_id.replace_self_type(self_type);
             }
             TypeInfo::Storage { mut fields } => {
@@ -144,6 +141,7 @@ impl ReplaceSelfType for TypeId {
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Byte
             | TypeInfo::B256
+            | TypeInfo::ConstGenericValue(_)
             | TypeInfo::Numeric
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery => {}
@@ -162,3 +160,38 @@ impl TypeId {
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_helpers::compile, CompileAstResult, CompileError};
+
+    #[test]
+    fn test_unresolved_generic_points_at_type_parameter() {
+        let src = r#"
+        script;
+
+        enum Either<T> {
+            Left: T,
+            Right: (),
+        }
+
+        fn main() {
+            let x = Either::Right;
+        }
+        "#;
+        let errors = match compile(src) {
+            CompileAstResult::Success { typed_program, .. } => {
+                typed_program.finalize_types().errors
+            }
+            CompileAstResult::Failure { errors, .. } => panic!("failed to compile: {:?}", errors),
+        };
+        let spans = errors
+            .iter()
+            .map(|error| match error {
+                CompileError::UnableToInferGeneric { ty, span } if ty == "T" => span.start(),
+                _ => panic!("unexpected error: {:?}", error),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(spans, vec![src.find("<T>").unwrap() + 1]);
+    }
+}
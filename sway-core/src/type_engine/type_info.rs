@@ -1,6 +1,9 @@
 use super::*;
 
-use crate::{semantic_analysis::*, types::*, CallPath, Ident, TypeArgument, TypeParameter};
+use crate::{
+    semantic_analysis::*, style::find_closest_match, types::*, CallPath, Ident, TypeArgument,
+    TypeParameter, TypeParameterKind,
+};
 
 use sway_types::{span::Span, Spanned};
 
@@ -79,6 +82,12 @@ pub enum TypeInfo {
     ErrorRecovery,
     // Static, constant size arrays.
     Array(TypeId, usize),
+    /// A static array whose length is given by the const generic parameter named within, e.g.
+    /// `[u64; N]` in `struct Arr<const N: u64>`. This becomes an `Array` once the declaration is
+    /// monomorphized with a value for the parameter.
+    GenericLengthArray(TypeId, Ident),
+    /// The value of a const generic argument, e.g. the `3` in `Arr<3>`.
+    ConstGenericValue(u64),
     /// Represents the entire storage declaration struct
     /// Stored without initializers here, as typed struct fields,
     /// so type checking is able to treat it as a struct with fields.
@@ -183,6 +192,15 @@ impl Hash for TypeInfo {
                 state.write_u8(19);
                 fields.hash(state);
             }
+            TypeInfo::GenericLengthArray(elem_ty, length) => {
+                state.write_u8(22);
+                look_up_type_id(*elem_ty).hash(state);
+                length.hash(state);
+            }
+            TypeInfo::ConstGenericValue(value) => {
+                state.write_u8(23);
+                value.hash(state);
+            }
         }
     }
 }
@@ -264,6 +282,10 @@ impl PartialEq for TypeInfo {
             (TypeInfo::Storage { fields: l_fields }, TypeInfo::Storage { fields: r_fields }) => {
                 l_fields == r_fields
             }
+            (Self::GenericLengthArray(l0, l1), Self::GenericLengthArray(r0, r1)) => {
+                look_up_type_id(*l0) == look_up_type_id(*r0) && l1 == r1
+            }
+            (Self::ConstGenericValue(l), Self::ConstGenericValue(r)) => l == r,
             _ => false,
         }
     }
@@ -327,6 +349,8 @@ impl fmt::Display for TypeInfo {
                 format!("contract caller {}", abi_name)
             }
             Array(elem_ty, count) => format!("[{}; {}]", elem_ty, count),
+            GenericLengthArray(elem_ty, length) => format!("[{}; {}]", elem_ty, length),
+            ConstGenericValue(value) => value.to_string(),
             Storage { .. } => "contract storage".into(),
         };
         write!(f, "{}", s)
@@ -373,6 +397,10 @@ impl JsonAbiString for TypeInfo {
                 format!("contract caller {}", abi_name)
             }
             Array(elem_ty, count) => format!("[{}; {}]", elem_ty.json_abi_str(), count),
+            GenericLengthArray(elem_ty, length) => {
+                format!("[{}; {}]", elem_ty.json_abi_str(), length)
+            }
+            ConstGenericValue(value) => value.to_string(),
             Storage { .. } => "contract storage".into(),
         }
     }
@@ -544,6 +572,41 @@ impl TypeInfo {
         }
     }
 
+    /// Like the `Display` implementation, but struct and enum names are qualified with the path
+    /// of the module which declares them, e.g. `a::Foo` rather than `Foo`. This is used in
+    /// diagnostics where two modules may declare types of the same name.
+    ///
+    /// `root` must be the root module of the namespace in which the type was declared.
+    pub(crate) fn display_with_path(&self, root: &namespace::Module) -> String {
+        match self {
+            TypeInfo::Enum {
+                name,
+                type_parameters,
+                ..
+            }
+            | TypeInfo::Struct {
+                name,
+                type_parameters,
+                ..
+            } => {
+                let name = match declaring_module_path(root, name) {
+                    Some(path) if !path.is_empty() => format!("{}::{}", path.join("::"), name),
+                    _ => name.to_string(),
+                };
+                let inner_types = type_parameters
+                    .iter()
+                    .map(|param| look_up_type_id(param.type_id).display_with_path(root))
+                    .collect::<Vec<_>>();
+                if inner_types.is_empty() {
+                    name
+                } else {
+                    format!("{}<{}>", name, inner_types.join(", "))
+                }
+            }
+            _ => self.to_string(),
+        }
+    }
+
     pub fn is_unit(&self) -> bool {
         match self {
             TypeInfo::Tuple(fields) => fields.is_empty(),
@@ -631,6 +694,29 @@ impl TypeInfo {
             TypeInfo::Array(ary_ty_id, count) => look_up_type_id(*ary_ty_id)
                 .matches_type_parameter(mapping)
                 .map(|matching_id| insert_type(TypeInfo::Array(matching_id, *count))),
+            TypeInfo::GenericLengthArray(ary_ty_id, length) => {
+                let new_ty_id = look_up_type_id(*ary_ty_id)
+                    .matches_type_parameter(mapping)
+                    .unwrap_or(*ary_ty_id);
+                // The length is only known once the parameter has been given a value.
+                let count = mapping.iter().find_map(|(param, ty_id)| {
+                    match (&param.kind, look_up_type_id(*ty_id)) {
+                        (TypeParameterKind::Const(_), ConstGenericValue(count))
+                            if param.name_ident == *length =>
+                        {
+                            Some(count)
+                        }
+                        _ => None,
+                    }
+                });
+                match count {
+                    Some(count) => Some(insert_type(TypeInfo::Array(new_ty_id, count as usize))),
+                    None if new_ty_id != *ary_ty_id => Some(insert_type(
+                        TypeInfo::GenericLengthArray(new_ty_id, length.clone()),
+                    )),
+                    None => None,
+                }
+            }
             TypeInfo::Tuple(fields) => {
                 let mut new_fields = Vec::new();
                 let mut index = 0;
@@ -682,6 +768,7 @@ impl TypeInfo {
             | SelfType
             | Byte
             | B256
+            | ConstGenericValue(..)
             | Numeric
             | Contract
             | Storage { .. }
@@ -718,6 +805,8 @@ impl TypeInfo {
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery
             | TypeInfo::Array(_, _)
+            | TypeInfo::GenericLengthArray(..)
+            | TypeInfo::ConstGenericValue(_)
             | TypeInfo::Storage { .. } => {
                 errors.push(CompileError::Unimplemented(
                     "matching on this type is unsupported right now",
@@ -777,7 +866,7 @@ impl TypeInfo {
                     all_nested_types.append(&mut nested_types);
                 }
             }
-            TypeInfo::Array(type_id, _) => {
+            TypeInfo::Array(type_id, _) | TypeInfo::GenericLengthArray(type_id, _) => {
                 let mut nested_types = check!(
                     look_up_type_id(type_id).extract_nested_types(span),
                     return err(warnings, errors),
@@ -805,6 +894,7 @@ impl TypeInfo {
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Byte
             | TypeInfo::B256
+            | TypeInfo::ConstGenericValue(_)
             | TypeInfo::Numeric
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery => {}
@@ -853,6 +943,8 @@ impl TypeInfo {
                             field_name: first.clone(),
                             struct_name: name.clone(),
                             available_fields: available_fields.join(", "),
+                            did_you_mean: find_closest_match(first.as_str(), available_fields)
+                                .map(str::to_string),
                         });
                         return err(warnings, errors);
                     }
@@ -938,6 +1030,29 @@ impl TypeInfo {
     }
 }
 
+/// Finds the path, relative to `module`, of the module which declares the struct or enum `name`.
+/// Imported items are not stored as symbols, so only the declaring module can match, and the
+/// span comparison tells apart distinct types which share a name.
+fn declaring_module_path(module: &namespace::Module, name: &Ident) -> Option<Vec<String>> {
+    let declared_here = match module.symbols().get(name) {
+        Some(TypedDeclaration::StructDeclaration(decl)) => decl.name.span() == name.span(),
+        Some(TypedDeclaration::EnumDeclaration(decl)) => decl.name.span() == name.span(),
+        _ => false,
+    };
+    if declared_here {
+        return Some(vec![]);
+    }
+    module
+        .submodules()
+        .iter()
+        .find_map(|(submodule_name, submodule)| {
+            declaring_module_path(submodule, name).map(|mut path| {
+                path.insert(0, submodule_name.clone());
+                path
+            })
+        })
+}
+
 fn print_inner_types(name: String, inner_types: impl Iterator<Item = TypeId>) -> String {
     let inner_types = inner_types.map(|x| x.to_string()).collect::<Vec<_>>();
     format!(
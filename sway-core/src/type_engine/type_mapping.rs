@@ -2,7 +2,7 @@ use crate::TypeParameter;
 
 use super::*;
 
-pub(crate) type TypeMapping = Vec<(TypeParameter, TypeId)>;
+pub type TypeMapping = Vec<(TypeParameter, TypeId)>;
 
 pub(crate) fn insert_type_parameters(type_parameters: &[TypeParameter]) -> TypeMapping {
     type_parameters
@@ -17,3 +17,15 @@ pub(crate) fn insert_type_parameters(type_parameters: &[TypeParameter]) -> TypeM
         })
         .collect()
 }
+
+/// Pairs the type parameters of a generic declaration with the types they were given when the
+/// declaration was monomorphized into `monomorphized`.
+pub(crate) fn monomorphized_type_mapping(
+    type_parameters: Vec<TypeParameter>,
+    monomorphized: &[TypeParameter],
+) -> TypeMapping {
+    type_parameters
+        .into_iter()
+        .zip(monomorphized.iter().map(|x| x.type_id))
+        .collect()
+}
@@ -14,6 +14,7 @@ pub enum E {
 }
 
 abi StorageAccess {
+    #[storage(read)]
     fn get_e() -> (E, E);
 }
 
@@ -23,6 +24,7 @@ storage {
 }
 
 impl StorageAccess for Contract {
+    #[storage(read)]
     fn get_e() -> (E, E) {
         (storage.e1, storage.e2)
     }
@@ -14,6 +14,7 @@ pub enum E {
 }
 
 abi StorageAccess {
+    #[storage(write)]
     fn set_e(s: S, u: u64);
 }
 
@@ -23,6 +24,7 @@ storage {
 }
 
 impl StorageAccess for Contract {
+    #[storage(write)]
     fn set_e(s: S, u: u64) {
         storage.e1 = E::A(s);
         storage.e2 = E::B(u);
@@ -2,7 +2,9 @@ contract;
 
 abi StorageAccess {
     // Setters
+    #[storage(write)]
     fn set_s(s: str[40]);
+    #[storage(read)]
     fn get_s() -> str[40];
 }
 
@@ -11,10 +13,12 @@ storage {
 }
 
 impl StorageAccess for Contract {
+    #[storage(write)]
     fn set_s(s: str[40]) {
         storage.s = s;
     }
 
+    #[storage(read)]
     fn get_s() -> str[40] {
         storage.s
     }
@@ -0,0 +1,16 @@
+script;
+
+fn main() -> bool {
+    let mut a = true;
+    while a {
+        if a && false {
+            continue;
+        }
+        a = false;
+        break;
+    }
+    while true {
+        break;
+    }
+    a
+}
@@ -192,6 +192,11 @@ impl Function {
         &context.functions[self.0].name
     }
 
+    /// Return the function return type.
+    pub fn get_return_type(&self, context: &Context) -> Type {
+        context.functions[self.0].return_type
+    }
+
     /// Return the function entry (i.e., the first) block.
     pub fn get_entry_block(&self, context: &Context) -> Block {
         context.functions[self.0].blocks[0]
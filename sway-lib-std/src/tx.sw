@@ -3,7 +3,6 @@
 library tx;
 
 use ::address::Address;
-use ::contract_id::ContractId;
 use ::intrinsics::is_reference_type;
 
 ////////////////////////////////////////
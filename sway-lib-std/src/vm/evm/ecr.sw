@@ -1,7 +1,6 @@
 library ecr;
 
 use ::b512::B512;
-use ::context::registers::error;
 use ::ecr::{EcRecoverError, ec_recover};
 use ::vm::evm::evm_address::EvmAddress;
 use ::hash::keccak256;
@@ -1,5 +1,6 @@
 use crate::core::{session::Session, token::Token, token_type::TokenType};
 use std::sync::Arc;
+use sway_core::TypedFunctionDeclaration;
 use tower_lsp::lsp_types::{
     CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse,
 };
@@ -9,9 +10,10 @@ pub fn get_completion(
     params: CompletionParams,
 ) -> Option<CompletionResponse> {
     let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
 
     session
-        .get_completion_items(&url)
+        .get_completion_items(&url, position)
         .map(CompletionResponse::Array)
 }
 
@@ -32,6 +34,17 @@ pub fn to_completion_items(tokens: &[Token]) -> Vec<CompletionItem> {
     completion_items
 }
 
+pub fn to_method_completion_items(methods: &[TypedFunctionDeclaration]) -> Vec<CompletionItem> {
+    methods
+        .iter()
+        .map(|method| CompletionItem {
+            label: method.name.as_str().to_string(),
+            kind: Some(CompletionItemKind::METHOD),
+            ..Default::default()
+        })
+        .collect()
+}
+
 fn get_kind(token_type: &TokenType) -> Option<CompletionItemKind> {
     match token_type {
         TokenType::VariableDeclaration(_) | TokenType::VariableExpression => {
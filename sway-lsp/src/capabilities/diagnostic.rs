@@ -1,6 +1,10 @@
-use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use tower_lsp::lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, Url,
+};
 
+use crate::utils::common::get_range_from_span;
 use sway_core::{CompileError, CompileWarning};
+use sway_types::Spanned;
 
 pub fn get_diagnostics(
     warnings: Vec<CompileWarning>,
@@ -9,7 +13,7 @@ pub fn get_diagnostics(
     let errors: Vec<Diagnostic> = errors
         .iter()
         .map(|error| {
-            let range = get_range(&WarningOrError::Error(error));
+            let range = get_range_from_span(&error.span());
             Diagnostic {
                 range,
                 severity: Some(DiagnosticSeverity::ERROR),
@@ -22,7 +26,7 @@ pub fn get_diagnostics(
     let warnings: Vec<Diagnostic> = warnings
         .iter()
         .map(|warning| {
-            let range = get_range(&WarningOrError::Warning(warning));
+            let range = get_range_from_span(&warning.span());
             Diagnostic {
                 range,
                 severity: Some(DiagnosticSeverity::WARNING),
@@ -35,25 +39,66 @@ pub fn get_diagnostics(
     vec![warnings, errors].into_iter().flatten().collect()
 }
 
-fn get_range(warning_or_error: &WarningOrError<'_>) -> Range {
-    let (start, end) = match warning_or_error {
-        WarningOrError::Error(error) => error.line_col(),
-        WarningOrError::Warning(warning) => warning.line_col(),
-    };
-
-    let start_line = start.line as u32 - 1;
-    let start_character = start.col as u32 - 1;
+/// Merge diagnostics of the same severity that share a range, so that the client shows a single
+/// diagnostic at that location. The first diagnostic is kept as is and the messages of the rest
+/// are attached to it as related information.
+pub fn group_diagnostics(uri: &Url, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    let mut grouped: Vec<Diagnostic> = Vec::with_capacity(diagnostics.len());
+    for diagnostic in diagnostics {
+        match grouped
+            .iter_mut()
+            .find(|d| d.range == diagnostic.range && d.severity == diagnostic.severity)
+        {
+            Some(primary) => {
+                if primary.message == diagnostic.message {
+                    continue;
+                }
+                primary
+                    .related_information
+                    .get_or_insert_with(Vec::new)
+                    .push(DiagnosticRelatedInformation {
+                        location: Location::new(uri.clone(), diagnostic.range),
+                        message: diagnostic.message,
+                    });
+            }
+            None => grouped.push(diagnostic),
+        }
+    }
+    grouped
+}
 
-    let end_line = end.line as u32 - 1;
-    let end_character = end.col as u32 - 1;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_lsp::lsp_types::{Position, Range};
 
-    Range {
-        start: Position::new(start_line, start_character),
-        end: Position::new(end_line, end_character),
+    fn error_at(line: u32, message: &str) -> Diagnostic {
+        Diagnostic {
+            range: Range::new(Position::new(line, 0), Position::new(line, 4)),
+            severity: Some(DiagnosticSeverity::ERROR),
+            message: message.into(),
+            ..Default::default()
+        }
     }
-}
 
-enum WarningOrError<'s> {
-    Warning(&'s CompileWarning),
-    Error(&'s CompileError),
+    #[test]
+    fn group_diagnostics_sharing_a_range() {
+        let uri = Url::parse("file:///main.sw").unwrap();
+        let diagnostics = vec![
+            error_at(1, "first"),
+            error_at(2, "other line"),
+            error_at(1, "second"),
+            error_at(1, "first"),
+        ];
+
+        let grouped = group_diagnostics(&uri, diagnostics);
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].message, "first");
+        let related = grouped[0].related_information.as_ref().unwrap();
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].message, "second");
+        assert_eq!(related[0].location.uri, uri);
+        assert_eq!(grouped[1].message, "other line");
+        assert!(grouped[1].related_information.is_none());
+    }
 }
@@ -42,6 +42,8 @@ fn get_kind(token_type: &TokenType) -> SymbolKind {
         TokenType::StructDeclaration(_) | TokenType::Struct => SymbolKind::STRUCT,
         TokenType::EnumDeclaration(_) | TokenType::EnumApplication => SymbolKind::ENUM,
         TokenType::ConstantDeclaration(_) => SymbolKind::CONSTANT,
+        TokenType::StorageDeclaration => SymbolKind::STRUCT,
+        TokenType::StorageFieldDeclaration(_) | TokenType::StorageAccess => SymbolKind::FIELD,
         TokenType::Library => SymbolKind::MODULE,
         TokenType::Reassignment => SymbolKind::OPERATOR,
         // currently we return `variable` type as default
@@ -0,0 +1,200 @@
+use crate::utils::common::get_range_from_span;
+use sway_core::{AstNode, AstNodeContent, CodeBlock, Declaration, Expression, FunctionDeclaration};
+use sway_types::{Span, Spanned};
+use tower_lsp::lsp_types::{FoldingRange, FoldingRangeKind};
+
+/// Collects the folding ranges for every multi-line declaration, block and comment in a file.
+///
+/// Only the outermost range starting on any given line is kept, as clients can only fold one
+/// range per line.
+pub fn get_folding_ranges(nodes: &[AstNode], text: &str) -> Vec<FoldingRange> {
+    let mut ranges = vec![];
+    for node in nodes {
+        handle_node(node, &mut ranges);
+    }
+    handle_comments(text, &mut ranges);
+
+    ranges.sort_by_key(|range| (range.start_line, std::cmp::Reverse(range.end_line)));
+    ranges.dedup_by_key(|range| range.start_line);
+    ranges
+}
+
+fn handle_node(node: &AstNode, ranges: &mut Vec<FoldingRange>) {
+    match &node.content {
+        AstNodeContent::Declaration(declaration) => {
+            push_region(&node.span, ranges);
+            handle_declaration(declaration, ranges);
+        }
+        AstNodeContent::Expression(exp)
+        | AstNodeContent::ImplicitReturnExpression(exp)
+        | AstNodeContent::ReturnStatement(sway_core::ReturnStatement { expr: exp }) => {
+            handle_expression(exp, ranges);
+        }
+        AstNodeContent::WhileLoop(while_loop) => {
+            push_region(&node.span, ranges);
+            handle_code_block(&while_loop.body, ranges);
+        }
+        AstNodeContent::UseStatement(_)
+        | AstNodeContent::IncludeStatement(_)
+        | AstNodeContent::Break(_)
+        | AstNodeContent::Continue(_) => {}
+    }
+}
+
+fn handle_declaration(declaration: &Declaration, ranges: &mut Vec<FoldingRange>) {
+    match declaration {
+        Declaration::VariableDeclaration(var_decl) => handle_expression(&var_decl.body, ranges),
+        Declaration::ConstantDeclaration(const_decl) => {
+            handle_expression(&const_decl.value, ranges)
+        }
+        Declaration::Reassignment(reassignment) => handle_expression(&reassignment.rhs, ranges),
+        Declaration::FunctionDeclaration(func) => handle_function(func, ranges),
+        Declaration::TraitDeclaration(trait_decl) => {
+            trait_decl
+                .methods
+                .iter()
+                .for_each(|func| handle_function(func, ranges));
+        }
+        Declaration::AbiDeclaration(abi_decl) => {
+            abi_decl
+                .methods
+                .iter()
+                .for_each(|func| handle_function(func, ranges));
+        }
+        Declaration::ImplTrait(impl_trait) => {
+            impl_trait
+                .functions
+                .iter()
+                .for_each(|func| handle_function(func, ranges));
+        }
+        Declaration::ImplSelf(impl_self) => {
+            impl_self
+                .functions
+                .iter()
+                .for_each(|func| handle_function(func, ranges));
+        }
+        Declaration::StructDeclaration(_)
+        | Declaration::EnumDeclaration(_)
+        | Declaration::StorageDeclaration(_) => {}
+    }
+}
+
+fn handle_function(func: &FunctionDeclaration, ranges: &mut Vec<FoldingRange>) {
+    push_region(&func.span, ranges);
+    handle_code_block(&func.body, ranges);
+}
+
+fn handle_code_block(code_block: &CodeBlock, ranges: &mut Vec<FoldingRange>) {
+    push_region(&code_block.span(), ranges);
+    for node in &code_block.contents {
+        handle_node(node, ranges);
+    }
+}
+
+fn handle_expression(exp: &Expression, ranges: &mut Vec<FoldingRange>) {
+    match exp {
+        Expression::CodeBlock { contents, .. } => handle_code_block(contents, ranges),
+        Expression::IfExp {
+            condition,
+            then,
+            r#else,
+            span,
+        } => {
+            push_region(span, ranges);
+            handle_expression(condition, ranges);
+            handle_expression(then, ranges);
+            if let Some(r#else) = r#else {
+                handle_expression(r#else, ranges);
+            }
+        }
+        Expression::IfLet {
+            expr,
+            then,
+            r#else,
+            span,
+            ..
+        } => {
+            push_region(span, ranges);
+            handle_expression(expr, ranges);
+            handle_expression(then, ranges);
+            if let Some(r#else) = r#else {
+                handle_expression(r#else, ranges);
+            }
+        }
+        Expression::MatchExp {
+            value,
+            branches,
+            span,
+        } => {
+            push_region(span, ranges);
+            handle_expression(value, ranges);
+            for branch in branches {
+                handle_expression(&branch.result, ranges);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn push_region(span: &Span, ranges: &mut Vec<FoldingRange>) {
+    let range = get_range_from_span(span);
+    push_range(
+        range.start.line,
+        range.end.line,
+        FoldingRangeKind::Region,
+        ranges,
+    );
+}
+
+fn push_range(
+    start_line: u32,
+    end_line: u32,
+    kind: FoldingRangeKind,
+    ranges: &mut Vec<FoldingRange>,
+) {
+    if end_line > start_line {
+        ranges.push(FoldingRange {
+            start_line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind: Some(kind),
+        });
+    }
+}
+
+/// Comments aren't kept in the parse tree, so we find multi-line block comments and runs of line
+/// comments by scanning the text directly.
+fn handle_comments(text: &str, ranges: &mut Vec<FoldingRange>) {
+    let mut line_comment_start = None;
+    let mut block_comment_start = None;
+    let mut last_line = 0;
+    for (line_ix, line) in text.lines().enumerate() {
+        let line_ix = line_ix as u32;
+        let trimmed = line.trim_start();
+        last_line = line_ix;
+
+        if let Some(start) = block_comment_start {
+            if trimmed.contains("*/") {
+                push_range(start, line_ix, FoldingRangeKind::Comment, ranges);
+                block_comment_start = None;
+            }
+            continue;
+        }
+
+        if trimmed.starts_with("//") {
+            line_comment_start.get_or_insert(line_ix);
+            continue;
+        }
+        if let Some(start) = line_comment_start.take() {
+            push_range(start, line_ix - 1, FoldingRangeKind::Comment, ranges);
+        }
+
+        if trimmed.starts_with("/*") && !trimmed.contains("*/") {
+            block_comment_start = Some(line_ix);
+        }
+    }
+    if let Some(start) = line_comment_start {
+        push_range(start, last_line, FoldingRangeKind::Comment, ranges);
+    }
+}
@@ -30,6 +30,33 @@ pub fn get_format_text_edits(text: Arc<str>, options: FormattingOptions) -> Opti
 
             Some(vec![main_edit])
         }
-        _ => None,
+        Err(errors) => {
+            tracing::warn!("failed to format document: {}", errors.join(", "));
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reformats_poorly_indented_function() {
+        let text = "script;\n\nfn main() {\n        let x = 1;\n  let y = 2;\n}\n";
+        let edits = get_format_text_edits(Arc::from(text), FormattingOptions::default())
+            .expect("valid code should be formatted");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start, Position::new(0, 0));
+        assert_eq!(
+            edits[0].new_text,
+            "script;\n\nfn main() {\n    let x = 1;\n    let y = 2;\n}\n"
+        );
+    }
+
+    #[test]
+    fn invalid_code_is_not_formatted() {
+        let text = "script;\n\nfn main( {\n";
+        assert!(get_format_text_edits(Arc::from(text), FormattingOptions::default()).is_none());
     }
 }
@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
-use crate::core::{session::Session, token::Token};
-use tower_lsp::lsp_types::{GotoDefinitionParams, GotoDefinitionResponse, Location, Url};
+use crate::core::{session::Session, token::Token, traverse_typed_tree::get_type_id};
+use sway_core::type_engine::{look_up_type_id, TypeInfo};
+use tower_lsp::lsp_types::{
+    request::{GotoTypeDefinitionParams, GotoTypeDefinitionResponse},
+    GotoDefinitionParams, GotoDefinitionResponse, Location, Url,
+};
 
 pub fn go_to_definition(
     session: Arc<Session>,
@@ -16,3 +20,22 @@ pub fn go_to_definition(
 pub fn to_definition_response(url: Url, token: &Token) -> GotoDefinitionResponse {
     GotoDefinitionResponse::Scalar(Location::new(url, token.range))
 }
+
+pub fn go_to_type_definition(
+    session: Arc<Session>,
+    params: GotoTypeDefinitionParams,
+) -> Option<GotoTypeDefinitionResponse> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+
+    session.get_token_type_definition_response(url, position)
+}
+
+/// The name of the struct or enum declaration that the type of `token` refers to, if any.
+pub fn declared_type_name(token: &Token) -> Option<String> {
+    let type_id = token.typed.as_ref().and_then(get_type_id)?;
+    match look_up_type_id(type_id) {
+        TypeInfo::Struct { name, .. } | TypeInfo::Enum { name, .. } => Some(name.to_string()),
+        _ => None,
+    }
+}
@@ -1,6 +1,6 @@
 use crate::core::session::Session;
 use std::sync::Arc;
-use tower_lsp::lsp_types::{DocumentHighlight, DocumentHighlightParams};
+use tower_lsp::lsp_types::{DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams};
 
 pub fn get_highlights(
     session: Arc<Session>,
@@ -9,10 +9,21 @@ pub fn get_highlights(
     let url = params.text_document_position_params.text_document.uri;
     let position = params.text_document_position_params.position;
 
-    session.get_token_ranges(&url, position).map(|ranges| {
-        ranges
-            .into_iter()
-            .map(|range| DocumentHighlight { range, kind: None })
-            .collect()
-    })
+    // Only occurrences within the current document are highlighted. As with rename, these are
+    // matched by name alone since a declaration and its uses have different token types.
+    let document = session.documents.get(url.path())?;
+    let token = document.get_token_at_position(position)?;
+    let highlights = document
+        .get_all_tokens_by_single_name(&token.name)?
+        .into_iter()
+        .map(|other| DocumentHighlight {
+            range: other.range,
+            kind: Some(if other.is_initial_declaration() {
+                DocumentHighlightKind::WRITE
+            } else {
+                DocumentHighlightKind::READ
+            }),
+        })
+        .collect();
+    Some(highlights)
 }
@@ -3,10 +3,12 @@ use crate::{
         session::{Documents, Session},
         token::Token,
         token_type::{TokenType, VarBody},
+        traverse_typed_tree::get_type_id,
     },
     utils::common::extract_visibility,
 };
 use std::sync::Arc;
+use sway_core::type_engine::{look_up_type_id, type_id_to_string, TypeId, TypeInfo};
 use tower_lsp::lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind};
 
 pub fn get_hover_data(session: Arc<Session>, params: HoverParams) -> Option<Hover> {
@@ -42,10 +44,13 @@ pub fn get_hover_data(session: Arc<Session>, params: HoverParams) -> Option<Hove
 fn get_hover_format(token: &Token, documents: &Documents) -> Hover {
     let value = match &token.token_type {
         TokenType::VariableDeclaration(var_details) => {
-            let var_type = match &var_details.var_body {
-                VarBody::FunctionCall(fn_name) => get_var_type_from_fn(fn_name, documents),
-                VarBody::Type(var_type) => var_type.clone(),
-                _ => "".into(),
+            let var_type = match inferred_type(token) {
+                Some(type_id) => type_id_to_string(type_id),
+                None => match &var_details.var_body {
+                    VarBody::FunctionCall(fn_name) => get_var_type_from_fn(fn_name, documents),
+                    VarBody::Type(var_type) => var_type.clone(),
+                    _ => "".into(),
+                },
             };
 
             format!(
@@ -71,18 +76,53 @@ fn get_hover_format(token: &Token, documents: &Documents) -> Hover {
             extract_visibility(&enum_details.visibility),
             &token.name
         ),
+        TokenType::ConstantDeclaration(const_details) => {
+            let const_type = inferred_type(token)
+                .map(type_id_to_string)
+                .or_else(|| const_details.type_name.clone())
+                .unwrap_or_default();
+            format!(
+                "{}const {}: {}",
+                extract_visibility(&const_details.visibility),
+                &token.name,
+                const_type
+            )
+        }
+        TokenType::StorageFieldDeclaration(storage_field_details) => {
+            format!("{}: {}", &token.name, storage_field_details.type_name)
+        }
         _ => token.name.clone(),
     };
 
+    let value = match &token.documentation {
+        Some(documentation) => format!("{}\n\n```sway\n{}\n```", documentation, value),
+        None => format!("```sway\n{}\n```", value),
+    };
+
     Hover {
         contents: HoverContents::Markup(MarkupContent {
-            value: format!("```sway\n{}\n```", value),
+            value,
             kind: MarkupKind::Markdown,
         }),
         range: Some(token.range),
     }
 }
 
+/// The type the type checker inferred for `token`, which is preferred over the one guessed from
+/// the parse tree.
+fn inferred_type(token: &Token) -> Option<TypeId> {
+    token
+        .typed
+        .as_ref()
+        .and_then(get_type_id)
+        .filter(|type_id| {
+            !matches!(
+                look_up_type_id(*type_id),
+                TypeInfo::Unknown | TypeInfo::Numeric | TypeInfo::ErrorRecovery
+            )
+        })
+}
+
 fn get_var_type_from_fn(fn_name: &str, documents: &Documents) -> String {
     for document_ref in documents {
         if let Some(declared_token) = document_ref.get_declared_token(fn_name) {
@@ -0,0 +1,435 @@
+use crate::{
+    core::session::Session, sway_config::InlayHintsConfig, utils::common::get_range_from_span,
+};
+use std::sync::Arc;
+use sway_core::{
+    semantic_analysis::ast_node::{
+        expression::{
+            typed_expression::TypedExpression, typed_expression_variant::TypedExpressionVariant,
+            TypedIntrinsicFunctionKind,
+        },
+        TypedAstNode, TypedAstNodeContent, TypedDeclaration, TypedImplTrait,
+    },
+    type_engine::{type_id_to_string, TypeMapping},
+};
+use sway_types::{Span, Spanned};
+use tower_lsp::lsp_types::{self, InlayHintLabel, InlayHintParams, Position};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlayKind {
+    ChainingHint,
+    GenericParamListHint,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlayHint {
+    pub position: Position,
+    pub kind: InlayKind,
+    pub label: String,
+}
+
+/// Answers a `textDocument/inlayHint` request with the hints that fall within its range.
+pub fn get_inlay_hints(
+    session: Arc<Session>,
+    params: InlayHintParams,
+) -> Option<Vec<lsp_types::InlayHint>> {
+    let range = params.range;
+    let hints = session
+        .get_inlay_hints(&params.text_document.uri)?
+        .into_iter()
+        .filter(|hint| range.start <= hint.position && hint.position <= range.end)
+        .map(|hint| lsp_types::InlayHint {
+            position: hint.position,
+            label: InlayHintLabel::String(hint.label),
+            kind: Some(lsp_types::InlayHintKind::TYPE),
+            text_edits: None,
+            tooltip: None,
+            // a chaining hint is set apart from the call it follows, while `::<T>` is attached
+            padding_left: Some(hint.kind == InlayKind::ChainingHint),
+            padding_right: None,
+        })
+        .collect();
+    Some(hints)
+}
+
+/// Collects the inlay hints for every function body in a type checked program.
+pub fn inlay_hints(nodes: &[TypedAstNode], config: &InlayHintsConfig) -> Vec<InlayHint> {
+    let mut hints = vec![];
+    for node in nodes {
+        handle_node(node, config, &mut hints);
+    }
+    hints.sort_by_key(|hint| (hint.position.line, hint.position.character));
+    hints
+}
+
+fn handle_node(node: &TypedAstNode, config: &InlayHintsConfig, hints: &mut Vec<InlayHint>) {
+    match &node.content {
+        TypedAstNodeContent::ReturnStatement(return_statement) => {
+            handle_expression(&return_statement.expr, config, hints)
+        }
+        TypedAstNodeContent::Declaration(declaration) => {
+            handle_declaration(declaration, config, hints)
+        }
+        TypedAstNodeContent::Expression(expression)
+        | TypedAstNodeContent::ImplicitReturnExpression(expression) => {
+            handle_expression(expression, config, hints)
+        }
+        TypedAstNodeContent::WhileLoop(while_loop) => {
+            handle_expression(&while_loop.condition, config, hints);
+            handle_nodes(&while_loop.body.contents, config, hints);
+        }
+        TypedAstNodeContent::Break(_)
+        | TypedAstNodeContent::Continue(_)
+        | TypedAstNodeContent::SideEffect => (),
+    }
+}
+
+fn handle_nodes(nodes: &[TypedAstNode], config: &InlayHintsConfig, hints: &mut Vec<InlayHint>) {
+    for node in nodes {
+        handle_node(node, config, hints);
+    }
+}
+
+fn handle_declaration(
+    declaration: &TypedDeclaration,
+    config: &InlayHintsConfig,
+    hints: &mut Vec<InlayHint>,
+) {
+    match declaration {
+        TypedDeclaration::VariableDeclaration(variable) => {
+            handle_expression(&variable.body, config, hints)
+        }
+        TypedDeclaration::ConstantDeclaration(const_decl) => {
+            handle_expression(&const_decl.value, config, hints)
+        }
+        TypedDeclaration::FunctionDeclaration(func) => {
+            handle_nodes(&func.body.contents, config, hints)
+        }
+        TypedDeclaration::Reassignment(reassignment) => {
+            handle_expression(&reassignment.rhs, config, hints)
+        }
+        TypedDeclaration::ImplTrait(TypedImplTrait { methods, .. }) => {
+            for method in methods {
+                handle_nodes(&method.body.contents, config, hints);
+            }
+        }
+        TypedDeclaration::StorageReassignment(storage_reassignment) => {
+            handle_expression(&storage_reassignment.rhs, config, hints)
+        }
+        TypedDeclaration::TraitDeclaration(_)
+        | TypedDeclaration::StructDeclaration(_)
+        | TypedDeclaration::EnumDeclaration(_)
+        | TypedDeclaration::AbiDeclaration(_)
+        | TypedDeclaration::GenericTypeForFunctionScope { .. }
+        | TypedDeclaration::ErrorRecovery
+        | TypedDeclaration::StorageDeclaration(_) => {}
+    }
+}
+
+fn handle_expression(
+    expression: &TypedExpression,
+    config: &InlayHintsConfig,
+    hints: &mut Vec<InlayHint>,
+) {
+    match &expression.expression {
+        TypedExpressionVariant::FunctionApplication {
+            call_path,
+            contract_call_params,
+            arguments,
+            type_mapping,
+            ..
+        } => {
+            handle_type_mapping(&call_path.span(), type_mapping, config, hints);
+            for exp in contract_call_params.values() {
+                handle_expression(exp, config, hints);
+            }
+            if method_receiver(expression).is_some() {
+                handle_method_chain(expression, config, hints);
+            } else {
+                for (_, exp) in arguments {
+                    handle_expression(exp, config, hints);
+                }
+            }
+        }
+        TypedExpressionVariant::LazyOperator { lhs, rhs, .. } => {
+            handle_expression(lhs, config, hints);
+            handle_expression(rhs, config, hints);
+        }
+        TypedExpressionVariant::Tuple { fields } => {
+            for exp in fields {
+                handle_expression(exp, config, hints);
+            }
+        }
+        TypedExpressionVariant::Array { contents } => {
+            for exp in contents {
+                handle_expression(exp, config, hints);
+            }
+        }
+        TypedExpressionVariant::ArrayIndex { prefix, index } => {
+            handle_expression(prefix, config, hints);
+            handle_expression(index, config, hints);
+        }
+        TypedExpressionVariant::StructExpression {
+            struct_name,
+            fields,
+            type_mapping,
+        } => {
+            handle_type_mapping(&struct_name.span(), type_mapping, config, hints);
+            for field in fields {
+                handle_expression(&field.value, config, hints);
+            }
+        }
+        TypedExpressionVariant::CodeBlock(code_block) => {
+            handle_nodes(&code_block.contents, config, hints);
+        }
+        TypedExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            handle_expression(condition, config, hints);
+            handle_expression(then, config, hints);
+            if let Some(r#else) = r#else {
+                handle_expression(r#else, config, hints);
+            }
+        }
+        TypedExpressionVariant::StructFieldAccess { prefix, .. }
+        | TypedExpressionVariant::TupleElemAccess { prefix, .. } => {
+            handle_expression(prefix, config, hints);
+        }
+        TypedExpressionVariant::AbiCast { address, .. } => {
+            handle_expression(address, config, hints);
+        }
+        TypedExpressionVariant::IntrinsicFunction(kind) => match kind {
+            TypedIntrinsicFunctionKind::SizeOfVal { exp } => handle_expression(exp, config, hints),
+            TypedIntrinsicFunctionKind::Revert { code } => handle_expression(code, config, hints),
+            TypedIntrinsicFunctionKind::SizeOfType { .. }
+            | TypedIntrinsicFunctionKind::IsRefType { .. }
+            | TypedIntrinsicFunctionKind::GetStorageKey => {}
+        },
+        TypedExpressionVariant::EnumTag { exp }
+        | TypedExpressionVariant::UnsafeDowncast { exp, .. }
+        | TypedExpressionVariant::Cast { exp, .. } => {
+            handle_expression(exp, config, hints);
+        }
+        TypedExpressionVariant::Literal { .. }
+        | TypedExpressionVariant::VariableExpression { .. }
+        | TypedExpressionVariant::FunctionParameter { .. }
+        | TypedExpressionVariant::AsmExpression { .. }
+        | TypedExpressionVariant::EnumInstantiation { .. }
+        | TypedExpressionVariant::StorageAccess(_)
+        | TypedExpressionVariant::AbiName { .. } => {}
+    }
+}
+
+/// Shows the type arguments inferred for a generic function or struct used at `name_span` without
+/// explicit ones, e.g. `::<u32>` after `identity` in `identity(5u32)`.
+fn handle_type_mapping(
+    name_span: &Span,
+    type_mapping: &TypeMapping,
+    config: &InlayHintsConfig,
+    hints: &mut Vec<InlayHint>,
+) {
+    if !config.generic_param_hints || type_mapping.is_empty() {
+        return;
+    }
+    let type_arguments = type_mapping
+        .iter()
+        .map(|(_, type_id)| type_id_to_string(*type_id))
+        .collect::<Vec<_>>()
+        .join(", ");
+    hints.push(InlayHint {
+        position: get_range_from_span(name_span).end,
+        kind: InlayKind::GenericParamListHint,
+        label: truncate_label(format!("::<{}>", type_arguments), config.max_length),
+    });
+}
+
+/// Walks a method call back through its receivers, e.g. `a.b().c()` yields `c` then `b`. Only
+/// chains of at least two calls get hints, as a single call's type is already visible in the
+/// surrounding expression.
+fn handle_method_chain(
+    expression: &TypedExpression,
+    config: &InlayHintsConfig,
+    hints: &mut Vec<InlayHint>,
+) {
+    let mut steps = vec![expression];
+    let mut receiver = method_receiver(expression);
+    while let Some(exp) = receiver {
+        receiver = method_receiver(exp);
+        if receiver.is_some() {
+            steps.push(exp);
+        } else {
+            handle_expression(exp, config, hints);
+        }
+    }
+
+    for step in &steps {
+        if let TypedExpressionVariant::FunctionApplication { arguments, .. } = &step.expression {
+            for (_, exp) in arguments.iter().skip(1) {
+                handle_expression(exp, config, hints);
+            }
+        }
+    }
+
+    if config.chaining_hints && steps.len() > 1 {
+        for step in steps {
+            let range = get_range_from_span(&step.span());
+            hints.push(InlayHint {
+                position: range.end,
+                kind: InlayKind::ChainingHint,
+                label: truncate_label(step.return_type.to_string(), config.max_length),
+            });
+        }
+    }
+}
+
+/// Returns the receiver of a method call, which is always passed as the `self` argument.
+fn method_receiver(expression: &TypedExpression) -> Option<&TypedExpression> {
+    match &expression.expression {
+        TypedExpressionVariant::FunctionApplication { arguments, .. } => arguments
+            .first()
+            .filter(|(name, _)| name.as_str() == "self")
+            .map(|(_, exp)| exp),
+        _ => None,
+    }
+}
+
+fn truncate_label(label: String, max_length: Option<usize>) -> String {
+    match max_length {
+        Some(max_length) if label.chars().count() > max_length => {
+            let mut truncated = label.chars().take(max_length).collect::<String>();
+            truncated.push('…');
+            truncated
+        }
+        _ => label,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use sway_core::{semantic_analysis::namespace, CompileAstResult};
+
+    fn typed_nodes(program: &str) -> Vec<TypedAstNode> {
+        match sway_core::compile_to_ast(Arc::from(program), namespace::Module::default(), None) {
+            CompileAstResult::Success { typed_program, .. } => typed_program.root.all_nodes,
+            CompileAstResult::Failure { errors, .. } => panic!("{:?}", errors),
+        }
+    }
+
+    const CHAINED_PROGRAM: &str = r#"script;
+
+struct Counter {
+    value: u64,
+}
+
+impl Counter {
+    fn reset(self) -> Counter {
+        Counter { value: 0 }
+    }
+
+    fn get(self) -> u64 {
+        self.value
+    }
+}
+
+fn main() -> u64 {
+    let counter = Counter { value: 1 };
+    counter.reset().get()
+}
+"#;
+
+    #[test]
+    fn chaining_hints() {
+        let hints = inlay_hints(&typed_nodes(CHAINED_PROGRAM), &InlayHintsConfig::default());
+        let found = hints
+            .iter()
+            .map(|hint| {
+                (
+                    hint.position.line,
+                    hint.position.character,
+                    hint.kind,
+                    hint.label.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                (18, 19, InlayKind::ChainingHint, "Counter"),
+                (18, 25, InlayKind::ChainingHint, "u64"),
+            ]
+        );
+    }
+
+    #[test]
+    fn chaining_hints_respect_config() {
+        let nodes = typed_nodes(CHAINED_PROGRAM);
+        let disabled = InlayHintsConfig {
+            chaining_hints: false,
+            generic_param_hints: true,
+            max_length: None,
+        };
+        assert!(inlay_hints(&nodes, &disabled).is_empty());
+
+        let truncated = InlayHintsConfig {
+            chaining_hints: true,
+            generic_param_hints: true,
+            max_length: Some(3),
+        };
+        let labels = inlay_hints(&nodes, &truncated)
+            .into_iter()
+            .map(|hint| hint.label)
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["Cou…", "u64"]);
+    }
+
+    #[test]
+    fn generic_param_hints() {
+        let program = r#"script;
+
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() -> u32 {
+    let explicit = identity::<bool>(true);
+    identity(5u32)
+}
+"#;
+        let nodes = typed_nodes(program);
+        let found = inlay_hints(&nodes, &InlayHintsConfig::default())
+            .into_iter()
+            .map(|hint| {
+                (
+                    hint.position.line,
+                    hint.position.character,
+                    hint.kind,
+                    hint.label,
+                )
+            })
+            .collect::<Vec<_>>();
+        // only the call without a turbofish gets a hint
+        assert_eq!(
+            found,
+            vec![(
+                8,
+                12,
+                InlayKind::GenericParamListHint,
+                "::<u32>".to_string()
+            )]
+        );
+
+        let truncated = InlayHintsConfig {
+            max_length: Some(4),
+            ..InlayHintsConfig::default()
+        };
+        let labels = inlay_hints(&nodes, &truncated)
+            .into_iter()
+            .map(|hint| hint.label)
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["::<u…"]);
+    }
+}
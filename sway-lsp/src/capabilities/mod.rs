@@ -2,10 +2,12 @@ pub mod completion;
 pub mod diagnostic;
 pub mod document_symbol;
 pub mod file_sync;
+pub mod folding_range;
 pub mod formatting;
 pub mod go_to;
 pub mod highlight;
 pub mod hover;
+pub mod inlay_hints;
 pub mod rename;
 pub mod semantic_tokens;
 pub mod text_sync;
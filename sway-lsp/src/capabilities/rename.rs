@@ -1,35 +1,70 @@
 use std::{collections::HashMap, sync::Arc};
 
-use tower_lsp::lsp_types::{
-    PrepareRenameResponse, RenameParams, TextDocumentPositionParams, TextEdit, WorkspaceEdit,
+use sway_parse::keywords::RESERVED_KEYWORDS;
+use tower_lsp::{
+    jsonrpc,
+    lsp_types::{
+        PrepareRenameResponse, RenameParams, TextDocumentPositionParams, TextEdit, Url,
+        WorkspaceEdit,
+    },
 };
 
 use crate::core::{session::Session, token::Token, token_type::TokenType};
 
-pub fn rename(session: Arc<Session>, params: RenameParams) -> Option<WorkspaceEdit> {
+pub fn rename(
+    session: Arc<Session>,
+    params: RenameParams,
+) -> jsonrpc::Result<Option<WorkspaceEdit>> {
     let new_name = params.new_name;
     let url = params.text_document_position.text_document.uri;
     let position = params.text_document_position.position;
 
-    match session.documents.get(url.path()) {
-        Some(ref document) => {
-            if let Some(token) = document.get_token_at_position(position) {
-                if let Some(tokens) = document.get_all_tokens_by_single_name(&token.name) {
-                    // todo: currently only supports single file rename
-                    let edits = prepare_token_rename(&tokens, new_name);
-                    let mut map_of_changes = HashMap::new();
-                    map_of_changes.insert(url.clone(), edits);
+    if let Err(reason) = validate_identifier(&new_name) {
+        return Err(jsonrpc::Error::invalid_params(format!(
+            "\"{}\" is not a valid name: {}",
+            new_name, reason
+        )));
+    }
+
+    let document = match session.documents.get(url.path()) {
+        Some(document) => document,
+        None => return Ok(None),
+    };
+    let token = match document.get_token_at_position(position) {
+        Some(token) => token,
+        None => return Ok(None),
+    };
+    if is_self_parameter(token) {
+        return Ok(None);
+    }
+
+    let mut map_of_changes = HashMap::new();
+    if let Some(tokens) = document.get_all_tokens_by_single_name(&token.name) {
+        map_of_changes.insert(url.clone(), prepare_token_rename(&tokens, &new_name));
+    }
 
-                    Some(WorkspaceEdit::new(map_of_changes))
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
+    // todo: as with hover, tokens in other files are matched by name and type only, so this
+    // may rename an unrelated symbol which happens to share both
+    for document_ref in &session.documents {
+        if document_ref.key() == url.path() {
+            continue;
+        }
+        let tokens = match document_ref.get_all_tokens_by_single_name(&token.name) {
+            Some(tokens) => tokens
+                .into_iter()
+                .filter(|other| other.is_same_type(token))
+                .collect::<Vec<_>>(),
+            None => continue,
+        };
+        if tokens.is_empty() {
+            continue;
+        }
+        if let Ok(other_url) = Url::from_file_path(document_ref.key()) {
+            map_of_changes.insert(other_url, prepare_token_rename(&tokens, &new_name));
         }
-        _ => None,
     }
+
+    Ok(Some(WorkspaceEdit::new(map_of_changes)))
 }
 
 pub fn prepare_rename(
@@ -37,28 +72,80 @@ pub fn prepare_rename(
     params: TextDocumentPositionParams,
 ) -> Option<PrepareRenameResponse> {
     let url = params.text_document.uri;
+    let document = session.documents.get(url.path())?;
+    let token = document.get_token_at_position(params.position)?;
 
-    match session.documents.get(url.path()) {
-        Some(ref document) => {
-            if let Some(token) = document.get_token_at_position(params.position) {
-                match token.token_type {
-                    TokenType::Library | TokenType::Reassignment => None,
-                    _ => Some(PrepareRenameResponse::RangeWithPlaceholder {
-                        range: token.range,
-                        placeholder: token.name.clone(),
-                    }),
-                }
-            } else {
-                None
-            }
+    // Only offer to rename a usage if we can see where it is declared, otherwise its declaration
+    // is likely to be in a dependency which we can't edit.
+    let is_renameable = if is_renameable_declaration(token) {
+        true
+    } else if token.is_initial_declaration() {
+        false
+    } else {
+        session.documents.iter().any(|document_ref| {
+            document_ref
+                .get_all_tokens_by_single_name(&token.name)
+                .map_or(false, |tokens| {
+                    tokens.into_iter().any(is_renameable_declaration)
+                })
+        })
+    };
+
+    if is_renameable {
+        Some(PrepareRenameResponse::RangeWithPlaceholder {
+            range: token.range,
+            placeholder: token.name.clone(),
+        })
+    } else {
+        None
+    }
+}
+
+fn is_renameable_declaration(token: &Token) -> bool {
+    !is_self_parameter(token)
+        && matches!(
+            token.token_type,
+            TokenType::VariableDeclaration(_)
+                | TokenType::FunctionDeclaration(_)
+                | TokenType::StructDeclaration(_)
+                | TokenType::EnumDeclaration(_)
+                | TokenType::TraitDeclaration(_)
+                | TokenType::FunctionParameter(_)
+        )
+}
+
+fn is_self_parameter(token: &Token) -> bool {
+    match &token.token_type {
+        TokenType::VariableDeclaration(details) | TokenType::FunctionParameter(details) => {
+            details.is_self_parameter
         }
-        _ => None,
+        _ => false,
     }
 }
 
-fn prepare_token_rename(tokens: &[&Token], new_name: String) -> Vec<TextEdit> {
+fn prepare_token_rename(tokens: &[&Token], new_name: &str) -> Vec<TextEdit> {
     tokens
         .iter()
-        .map(|token| TextEdit::new(token.range, new_name.clone()))
+        .map(|token| TextEdit::new(token.range, new_name.to_string()))
         .collect()
 }
+
+/// Checks that `name` could be parsed as a Sway identifier.
+fn validate_identifier(name: &str) -> Result<(), &'static str> {
+    let mut chars = name.chars();
+    match chars.next() {
+        None => return Err("names cannot be empty"),
+        Some(c) if c.is_ascii_digit() => return Err("names cannot start with a digit"),
+        Some(c) if !(c.is_alphabetic() || c == '_') => {
+            return Err("names must start with a letter or an underscore")
+        }
+        _ => (),
+    }
+    if !chars.all(|c| c.is_alphanumeric() || c == '_') {
+        return Err("names may only contain letters, digits and underscores");
+    }
+    if RESERVED_KEYWORDS.contains(&name) {
+        return Err("names cannot be a reserved keyword");
+    }
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::core::{session::Session, token::Token, token_type::TokenType};
 use std::sync::Arc;
 use tower_lsp::lsp_types::{
-    SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens,
+    Position, SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens,
     SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions, SemanticTokensParams,
     SemanticTokensResult, SemanticTokensServerCapabilities,
 };
@@ -29,38 +29,34 @@ pub fn get_semantic_tokens_full(
 }
 
 pub fn to_semantic_tokes(tokens: &[Token]) -> Vec<SemanticToken> {
-    if tokens.is_empty() {
-        return vec![];
-    }
-
-    let mut semantic_tokens: Vec<SemanticToken> = vec![create_semantic_token(&tokens[0], None)];
+    tokens.iter().map(create_semantic_token).collect()
+}
 
-    for i in 1..tokens.len() {
-        let semantic_token = create_semantic_token(&tokens[i], Some(&tokens[i - 1]));
-        semantic_tokens.push(semantic_token);
+/// Fill in `delta_from_prev` for each of the `tokens`, which must be sorted by their position in
+/// the document. The first token's delta is from the start of the document.
+pub fn compute_deltas(tokens: &mut [Token]) {
+    let mut prev_start = Position::default();
+    for token in tokens {
+        let start = token.range.start;
+        let delta_start = if start.line == prev_start.line {
+            start.character - prev_start.character
+        } else {
+            start.character
+        };
+        token.delta_from_prev = Some((start.line - prev_start.line, delta_start));
+        prev_start = start;
     }
-
-    semantic_tokens
 }
 
-fn create_semantic_token(next_token: &Token, prev_token: Option<&Token>) -> SemanticToken {
+fn create_semantic_token(token: &Token) -> SemanticToken {
     // TODO - improve with modifiers
     let token_modifiers_bitset = 0;
-    let token_type = get_type(&next_token.token_type);
-    let length = next_token.length;
+    let token_type = get_type(&token.token_type);
+    let length = token.length;
 
-    let next_token_start_char = next_token.range.start.character;
-
-    let (delta_line, delta_start) = if let Some(prev_token) = prev_token {
-        let delta_start = if next_token.line_start == prev_token.line_start {
-            next_token_start_char - prev_token.range.start.character
-        } else {
-            next_token_start_char
-        };
-        (next_token.line_start - prev_token.line_start, delta_start)
-    } else {
-        (next_token.line_start, next_token_start_char)
-    };
+    let (delta_line, delta_start) = token
+        .delta_from_prev
+        .unwrap_or((token.line_start, token.range.start.character));
 
     SemanticToken {
         token_modifiers_bitset,
@@ -75,6 +71,7 @@ fn create_semantic_token(next_token: &Token, prev_token: Option<&Token>) -> Sema
 #[repr(u32)]
 enum TokenTypeIndex {
     Function = 1,
+    Keyword = 2,
     Namespace = 3,
     Parameter = 5,
     Variable = 9,
@@ -89,10 +86,13 @@ fn get_type(token_type: &TokenType) -> u32 {
         | TokenType::FunctionApplication
         | TokenType::TraitFunction => TokenTypeIndex::Function as u32,
         TokenType::Library => TokenTypeIndex::Namespace as u32,
-        TokenType::FunctionParameter => TokenTypeIndex::Parameter as u32,
-        TokenType::VariableDeclaration(_) | TokenType::VariableExpression => {
-            TokenTypeIndex::Variable as u32
-        }
+        TokenType::FunctionParameter(_) => TokenTypeIndex::Parameter as u32,
+        TokenType::VariableDeclaration(_)
+        | TokenType::VariableExpression
+        | TokenType::ConstantDeclaration(_)
+        | TokenType::StorageFieldDeclaration(_)
+        | TokenType::StorageAccess => TokenTypeIndex::Variable as u32,
+        TokenType::StorageDeclaration => TokenTypeIndex::Keyword as u32,
         TokenType::EnumDeclaration(_) => TokenTypeIndex::Enum as u32,
         TokenType::StructDeclaration(_) | TokenType::Struct => TokenTypeIndex::Struct as u32,
         TokenType::TraitDeclaration(_) | TokenType::ImplTrait => TokenTypeIndex::Interface as u32,
@@ -148,3 +148,54 @@ pub fn get_semantic_tokens() -> Option<SemanticTokensServerCapabilities> {
         options,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::token::traverse_node;
+
+    const PROGRAM: &str = r#"script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+fn sum(p: Point) -> u64 {
+    p.x + p.y
+}
+
+fn main() -> u64 {
+    let p = Point { x: 1, y: 2 };
+    let q = Point { x: p.x, y: p.y }; sum(q)
+}
+"#;
+
+    #[test]
+    fn deltas_match_token_positions() {
+        let program = sway_core::parse(Arc::from(PROGRAM), None).value.unwrap();
+        let mut tokens = vec![];
+        for node in program.root.tree.root_nodes {
+            traverse_node(node, &mut tokens);
+        }
+        tokens.sort_by_key(|token| (token.range.start.line, token.range.start.character));
+        compute_deltas(&mut tokens);
+
+        // decoding the deltas must give back each token's own start position
+        let mut line = 0;
+        let mut character = 0;
+        for (token, semantic_token) in tokens.iter().zip(to_semantic_tokes(&tokens)) {
+            if semantic_token.delta_line > 0 {
+                character = 0;
+            }
+            line += semantic_token.delta_line;
+            character += semantic_token.delta_start;
+            assert_eq!(
+                Position::new(line, character),
+                token.range.start,
+                "{}",
+                token.name
+            );
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use tower_lsp::lsp_types::{
-    Diagnostic, DidChangeTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+    Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams, DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams,
 };
 
 use crate::core::{
@@ -9,9 +10,10 @@ use crate::core::{
     session::Session,
 };
 
-pub fn handle_open_file(
+pub async fn handle_open_file(
     session: Arc<Session>,
     params: &DidOpenTextDocumentParams,
+    compile_timeout: Option<Duration>,
 ) -> Vec<Diagnostic> {
     let path = params.text_document.uri.path();
 
@@ -21,31 +23,89 @@ pub fn handle_open_file(
         }
     }
 
-    parse_document(session, path)
+    parse_document(session, path, compile_timeout).await
 }
 
-pub fn handle_change_file(
+pub async fn handle_change_file(
     session: Arc<Session>,
     params: DidChangeTextDocumentParams,
+    compile_timeout: Option<Duration>,
 ) -> Vec<Diagnostic> {
     let path = params.text_document.uri.path();
     session.update_text_document(&params.text_document.uri, params.content_changes);
-    parse_document(session, path)
+    parse_document(session, path, compile_timeout).await
 }
 
-pub fn handle_save_file(
+pub async fn handle_save_file(
     session: Arc<Session>,
     params: &DidSaveTextDocumentParams,
+    compile_timeout: Option<Duration>,
 ) -> Vec<Diagnostic> {
     let path = params.text_document.uri.path();
-    parse_document(session, path)
+    parse_document(session, path, compile_timeout).await
 }
 
 // Parse the document and return diagnostics even if a DocumentError::FailedToParse error is encountered.
-fn parse_document(session: Arc<Session>, path: &str) -> Vec<Diagnostic> {
-    match session.parse_document(path) {
+//
+// If a compile timeout is set, the document is parsed on a copy in a blocking task so that a slow
+// compile doesn't hold onto the document. Once the timeout is exceeded we stop waiting on the task
+// and report an informational diagnostic instead, keeping the results of the previous parse. The
+// task itself can't be interrupted, so the compile still runs to completion and its stale result
+// is discarded.
+async fn parse_document(
+    session: Arc<Session>,
+    path: &str,
+    compile_timeout: Option<Duration>,
+) -> Vec<Diagnostic> {
+    let compile_timeout = match compile_timeout {
+        Some(compile_timeout) => compile_timeout,
+        None => return diagnostics_from_result(session.parse_document(path)),
+    };
+    let mut document = match session.documents.get(path) {
+        Some(document) => document.clone(),
+        None => return vec![],
+    };
+
+    let mut task = tokio::task::spawn_blocking(move || {
+        let result = document.parse();
+        (document, result)
+    });
+    match tokio::time::timeout(compile_timeout, &mut task).await {
+        Ok(Ok((document, result))) => {
+            // Only keep the parsed document if it wasn't edited while we were compiling it.
+            if let Some(mut stored) = session.documents.get_mut(path) {
+                if stored.get_text() == document.get_text() {
+                    *stored = document;
+                }
+            }
+            diagnostics_from_result(result)
+        }
+        Ok(Err(_)) => vec![],
+        Err(_) => {
+            // Blocking tasks can't be interrupted once they've started, so this only prevents a
+            // compile which is still queued from starting; a running one finishes in the
+            // background and its result is discarded.
+            task.abort();
+            vec![compile_timeout_diagnostic(compile_timeout)]
+        }
+    }
+}
+
+fn diagnostics_from_result(result: Result<Vec<Diagnostic>, DocumentError>) -> Vec<Diagnostic> {
+    match result {
         Ok(diagnostics) => diagnostics,
         Err(DocumentError::FailedToParse(diagnostics)) => diagnostics,
         _ => vec![],
     }
 }
+
+fn compile_timeout_diagnostic(compile_timeout: Duration) -> Diagnostic {
+    Diagnostic {
+        severity: Some(DiagnosticSeverity::INFORMATION),
+        message: format!(
+            "Compilation took longer than {}ms. Results may be out of date.",
+            compile_timeout.as_millis()
+        ),
+        ..Default::default()
+    }
+}
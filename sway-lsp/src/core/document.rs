@@ -5,18 +5,30 @@ use super::token_type::TokenType;
 use super::traverse_typed_tree;
 use super::typed_token_type::TokenMap;
 
-use crate::{capabilities, core::token::traverse_node, utils};
+use crate::{
+    capabilities::{self, inlay_hints::InlayHint},
+    core::token::traverse_node,
+    sway_config::InlayHintsConfig,
+    utils,
+};
 use ropey::Rope;
 use std::collections::HashMap;
 use std::sync::Arc;
 use sway_core::{
     parse,
-    semantic_analysis::{ast_node::TypedAstNode, namespace},
-    CompileAstResult, TreeType,
+    semantic_analysis::{
+        namespace::{self, Namespace},
+        TypedModule,
+    },
+    CompileAstResult, TreeType, TypedFunctionDeclaration,
+};
+use tower_lsp::lsp_types::{
+    Diagnostic, FoldingRange, Position, Range, TextDocumentContentChangeEvent,
 };
-use tower_lsp::lsp_types::{Diagnostic, Position, Range, TextDocumentContentChangeEvent};
 
-#[derive(Debug)]
+const TYPE_CHECK_STACK_SIZE: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
 pub struct TextDocument {
     #[allow(dead_code)]
     language_id: String,
@@ -28,6 +40,8 @@ pub struct TextDocument {
     lines: HashMap<u32, Vec<usize>>,
     values: HashMap<String, Vec<usize>>,
     token_map: TokenMap,
+    folding_ranges: Vec<FoldingRange>,
+    namespace: Option<namespace::Module>,
 }
 
 impl TextDocument {
@@ -42,6 +56,8 @@ impl TextDocument {
                 lines: HashMap::new(),
                 values: HashMap::new(),
                 token_map: HashMap::new(),
+                folding_ranges: vec![],
+                namespace: None,
             }),
             Err(_) => Err(DocumentError::DocumentNotFound),
         }
@@ -90,6 +106,42 @@ impl TextDocument {
         &self.tokens
     }
 
+    pub fn get_folding_ranges(&self) -> &Vec<FoldingRange> {
+        &self.folding_ranges
+    }
+
+    pub fn get_inlay_hints(&self, config: &InlayHintsConfig) -> Vec<InlayHint> {
+        match self.parse_typed_tokens_from_text() {
+            Some(module) => capabilities::inlay_hints::inlay_hints(&module.all_nodes, config),
+            None => vec![],
+        }
+    }
+
+    /// The methods callable on the receiver of a method call being typed, i.e. on the type of the
+    /// token ending just before a `.` at `position`.
+    pub fn get_methods_for_receiver(
+        &self,
+        position: Position,
+    ) -> Option<Vec<TypedFunctionDeclaration>> {
+        let dot_position = Position::new(position.line, position.character.checked_sub(1)?);
+        if self
+            .content
+            .get_char(self.position_to_index(dot_position))?
+            != '.'
+        {
+            return None;
+        }
+        let receiver = self
+            .lines
+            .get(&position.line)?
+            .iter()
+            .map(|index| &self.tokens[*index])
+            .find(|token| token.range.end == dot_position)?;
+        let type_id = traverse_typed_tree::get_type_id(receiver.typed.as_ref()?)?;
+        let namespace = Namespace::init_root(self.namespace.clone()?);
+        Some(namespace.all_methods_for_type(type_id))
+    }
+
     pub fn get_uri(&self) -> &str {
         &self.uri
     }
@@ -101,8 +153,10 @@ impl TextDocument {
         //self.test_typed_parse();
 
         match self.parse_tokens_from_text() {
-            Ok((tokens, diagnostics)) => {
+            Ok((tokens, folding_ranges, diagnostics)) => {
                 self.store_tokens(tokens);
+                self.folding_ranges = folding_ranges;
+                self.attach_typed_tokens();
                 Ok(diagnostics)
             }
             Err(diagnostics) => Err(DocumentError::FailedToParse(diagnostics)),
@@ -121,8 +175,8 @@ impl TextDocument {
     }
 
     pub fn test_typed_parse(&mut self) {
-        if let Some(all_nodes) = self.parse_typed_tokens_from_text() {
-            for node in &all_nodes {
+        if let Some(module) = self.parse_typed_tokens_from_text() {
+            for node in &module.all_nodes {
                 traverse_typed_tree::traverse_node(node, &mut self.token_map);
             }
         }
@@ -159,19 +213,31 @@ impl TextDocument {
 
 // private methods
 impl TextDocument {
-    fn parse_typed_tokens_from_text(&self) -> Option<Vec<TypedAstNode>> {
+    fn parse_typed_tokens_from_text(&self) -> Option<TypedModule> {
         let text = Arc::from(self.get_text());
-        let namespace = namespace::Module::default();
-        let ast_res = sway_core::compile_to_ast(text, namespace, None);
-        match ast_res {
-            CompileAstResult::Failure { .. } => None,
-            CompileAstResult::Success { typed_program, .. } => Some(typed_program.root.all_nodes),
-        }
+        // Type checking a document with many declarations needs more stack than the threads we're
+        // usually called from provide, so it's done on a thread of its own.
+        std::thread::Builder::new()
+            .stack_size(TYPE_CHECK_STACK_SIZE)
+            .spawn(move || {
+                let namespace = namespace::Module::default();
+                let ast_res = sway_core::compile_to_ast(text, namespace, None);
+                match ast_res {
+                    CompileAstResult::Failure { .. } => None,
+                    CompileAstResult::Success { typed_program, .. } => Some(typed_program.root),
+                }
+            })
+            .ok()?
+            .join()
+            .ok()?
     }
 
-    fn parse_tokens_from_text(&self) -> Result<(Vec<Token>, Vec<Diagnostic>), Vec<Diagnostic>> {
-        let text = Arc::from(self.get_text());
-        let parsed_result = parse(text, None);
+    #[allow(clippy::type_complexity)]
+    fn parse_tokens_from_text(
+        &self,
+    ) -> Result<(Vec<Token>, Vec<FoldingRange>, Vec<Diagnostic>), Vec<Diagnostic>> {
+        let text: Arc<str> = Arc::from(self.get_text());
+        let parsed_result = parse(text.clone(), None);
         match parsed_result.value {
             None => Err(capabilities::diagnostic::get_diagnostics(
                 parsed_result.warnings,
@@ -186,12 +252,17 @@ impl TextDocument {
                     let token = Token::from_ident(&name, TokenType::Library);
                     tokens.push(token);
                 };
+                let folding_ranges = capabilities::folding_range::get_folding_ranges(
+                    &parse_program.root.tree.root_nodes,
+                    &text,
+                );
                 for node in parse_program.root.tree.root_nodes {
                     traverse_node(node, &mut tokens);
                 }
 
                 Ok((
                     tokens,
+                    folding_ranges,
                     capabilities::diagnostic::get_diagnostics(
                         parsed_result.warnings,
                         parsed_result.errors,
@@ -201,7 +272,10 @@ impl TextDocument {
         }
     }
 
-    fn store_tokens(&mut self, tokens: Vec<Token>) {
+    fn store_tokens(&mut self, mut tokens: Vec<Token>) {
+        // semantic tokens are encoded relative to one another, so they must be in document order
+        tokens.sort_by_key(|token| (token.range.start.line, token.range.start.character));
+        capabilities::semantic_tokens::compute_deltas(&mut tokens);
         self.tokens = Vec::with_capacity(tokens.len());
 
         for (index, token) in tokens.into_iter().enumerate() {
@@ -233,7 +307,31 @@ impl TextDocument {
         }
     }
 
+    /// Type checks the document and pairs each parsed token with the typed token collected for the
+    /// same identifier, so that requests can make use of the inferred types.
+    fn attach_typed_tokens(&mut self) {
+        if let Some(module) = self.parse_typed_tokens_from_text() {
+            for node in &module.all_nodes {
+                traverse_typed_tree::traverse_node(node, &mut self.token_map);
+            }
+            self.namespace = Some(module.namespace);
+        }
+
+        for ((ident, span), typed_token) in &self.token_map {
+            let range = utils::common::get_range_from_span(span);
+            if let Some(indices) = self.lines.get(&range.start.line) {
+                for index in indices {
+                    let token = &mut self.tokens[*index];
+                    if token.range == range && token.name == ident.as_str() {
+                        token.typed = Some(typed_token.clone());
+                    }
+                }
+            }
+        }
+    }
+
     fn clear_hash_maps(&mut self) {
+        self.namespace = None;
         self.lines = HashMap::new();
         self.values = HashMap::new();
         self.token_map = HashMap::new();
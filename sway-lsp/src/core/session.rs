@@ -1,13 +1,16 @@
-use super::document::{DocumentError, TextDocument};
+use super::{
+    document::{DocumentError, TextDocument},
+    token_type::TokenType,
+};
 use crate::{
-    capabilities::{self, formatting::get_format_text_edits},
+    capabilities::{self, formatting::get_format_text_edits, inlay_hints::InlayHint},
     sway_config::SwayConfig,
 };
 use dashmap::DashMap;
 use serde_json::Value;
 use std::sync::{Arc, LockResult, RwLock};
 use tower_lsp::lsp_types::{
-    CompletionItem, Diagnostic, GotoDefinitionResponse, Position, Range, SemanticToken,
+    CompletionItem, Diagnostic, FoldingRange, GotoDefinitionResponse, Position, SemanticToken,
     SymbolInformation, TextDocumentContentChangeEvent, TextEdit, Url,
 };
 
@@ -72,23 +75,6 @@ impl Session {
     }
 
     // Token
-    pub fn get_token_ranges(&self, url: &Url, position: Position) -> Option<Vec<Range>> {
-        if let Some(document) = self.documents.get(url.path()) {
-            if let Some(token) = document.get_token_at_position(position) {
-                let result = document
-                    .get_all_tokens_by_single_name(&token.name)
-                    .unwrap()
-                    .iter()
-                    .map(|token| token.range)
-                    .collect();
-
-                return Some(result);
-            }
-        }
-
-        None
-    }
-
     pub fn get_token_definition_response(
         &self,
         url: Url,
@@ -119,11 +105,48 @@ impl Session {
         None
     }
 
-    pub fn get_completion_items(&self, url: &Url) -> Option<Vec<CompletionItem>> {
+    pub fn get_token_type_definition_response(
+        &self,
+        url: Url,
+        position: Position,
+    ) -> Option<GotoDefinitionResponse> {
+        let type_name = {
+            let document = self.documents.get(url.path())?;
+            let token = document.get_token_at_position(position)?;
+            capabilities::go_to::declared_type_name(token)?
+        };
+
+        for document_ref in &self.documents {
+            let declared_token = document_ref
+                .get_all_tokens_by_single_name(&type_name)
+                .and_then(|tokens| {
+                    tokens.into_iter().find(|token| {
+                        matches!(
+                            token.token_type,
+                            TokenType::StructDeclaration(_) | TokenType::EnumDeclaration(_)
+                        )
+                    })
+                });
+            if let Some(declared_token) = declared_token {
+                return Url::from_file_path(document_ref.key())
+                    .ok()
+                    .map(|url| capabilities::go_to::to_definition_response(url, declared_token));
+            }
+        }
+
+        None
+    }
+
+    pub fn get_completion_items(
+        &self,
+        url: &Url,
+        position: Position,
+    ) -> Option<Vec<CompletionItem>> {
         if let Some(document) = self.documents.get(url.path()) {
-            return Some(capabilities::completion::to_completion_items(
-                document.get_tokens(),
-            ));
+            return Some(match document.get_methods_for_receiver(position) {
+                Some(methods) => capabilities::completion::to_method_completion_items(&methods),
+                None => capabilities::completion::to_completion_items(document.get_tokens()),
+            });
         }
 
         None
@@ -150,6 +173,20 @@ impl Session {
         None
     }
 
+    pub fn get_folding_ranges(&self, url: &Url) -> Option<Vec<FoldingRange>> {
+        self.documents
+            .get(url.path())
+            .map(|document| document.get_folding_ranges().clone())
+    }
+
+    pub fn get_inlay_hints(&self, url: &Url) -> Option<Vec<InlayHint>> {
+        let document = self.documents.get(url.path())?;
+        match self.config.read() {
+            LockResult::Ok(config) => Some(document.get_inlay_hints(&config.inlay_hints)),
+            _ => None,
+        }
+    }
+
     pub fn format_text(&self, url: &Url) -> Option<Vec<TextEdit>> {
         if let Some(document) = self.documents.get(url.path()) {
             match self.config.read() {
@@ -1,10 +1,15 @@
 use crate::{
-    core::token_type::{
-        get_const_details, get_enum_details, get_function_details, get_struct_details,
-        get_struct_field_details, get_trait_details, TokenType, VariableDetails,
+    core::{
+        token_type::{
+            get_const_details, get_enum_details, get_function_details, get_storage_field_details,
+            get_struct_details, get_struct_field_details, get_trait_details, TokenType, VarBody,
+            VariableDetails,
+        },
+        typed_token_type::TokenType as TypedTokenType,
     },
-    utils::common::{extract_var_body, get_range_from_span},
+    utils::common::{extract_doc_comment, extract_var_body, get_range_from_span},
 };
+use serde::Serialize;
 use sway_core::{
     constants::TUPLE_NAME_PREFIX, parse_tree::MethodName, type_engine::TypeInfo, AstNode,
     AstNodeContent, Declaration, Expression, FunctionDeclaration, FunctionParameter,
@@ -13,13 +18,22 @@ use sway_core::{
 use sway_types::{ident::Ident, span::Span, Spanned};
 use tower_lsp::lsp_types::Range;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Token {
     pub range: Range,
     pub token_type: TokenType,
     pub name: String,
     pub line_start: u32,
     pub length: u32,
+    #[serde(skip)]
+    pub documentation: Option<String>,
+    /// The token collected from the typed AST for the same identifier, if the document type checks.
+    #[serde(skip)]
+    pub typed: Option<TypedTokenType>,
+    /// The `(line, character)` offset of this token from the start of the token before it, as
+    /// used by the semantic tokens encoding. Filled in once the document's tokens are sorted.
+    #[serde(skip)]
+    pub delta_from_prev: Option<(u32, u32)>,
 }
 
 impl Token {
@@ -31,7 +45,10 @@ impl Token {
             name,
             token_type,
             line_start: range.start.line,
-            length: range.end.character - range.start.character,
+            length: range.end.character.saturating_sub(range.start.character),
+            documentation: None,
+            typed: None,
+            delta_from_prev: None,
         }
     }
 
@@ -52,6 +69,18 @@ impl Token {
                 ) | (
                     TokenType::FunctionDeclaration(_),
                     TokenType::FunctionApplication
+                ) | (
+                    TokenType::VariableExpression,
+                    TokenType::ConstantDeclaration(_)
+                ) | (
+                    TokenType::ConstantDeclaration(_),
+                    TokenType::VariableExpression
+                ) | (
+                    TokenType::StorageAccess,
+                    TokenType::StorageFieldDeclaration(_)
+                ) | (
+                    TokenType::StorageFieldDeclaration(_),
+                    TokenType::StorageAccess
                 ),
             )
         }
@@ -72,6 +101,7 @@ impl Token {
             TokenType::VariableDeclaration(VariableDetails {
                 is_mutable: var_dec.is_mutable,
                 var_body,
+                is_self_parameter: false,
             }),
         )
     }
@@ -84,6 +114,12 @@ impl Token {
         Token::new(&span, span.as_str().into(), token_type)
     }
 
+    /// Attaches the doc comment written above the declaration named by `ident`, if there is one.
+    pub fn with_documentation(mut self, ident: &Ident) -> Self {
+        self.documentation = extract_doc_comment(&ident.span());
+        self
+    }
+
     pub fn is_initial_declaration(&self) -> bool {
         matches!(
             self.token_type,
@@ -94,7 +130,8 @@ impl Token {
                 | TokenType::EnumDeclaration(_)
                 | TokenType::AbiDeclaration
                 | TokenType::ConstantDeclaration(_)
-                | TokenType::StorageFieldDeclaration
+                | TokenType::StorageDeclaration
+                | TokenType::StorageFieldDeclaration(_)
         )
     }
 }
@@ -121,7 +158,11 @@ fn handle_function_parameter(parameter: &FunctionParameter, tokens: &mut Vec<Tok
     tokens.push(Token::new(
         &ident.span(),
         name.into(),
-        TokenType::FunctionParameter,
+        TokenType::FunctionParameter(VariableDetails {
+            is_mutable: false,
+            var_body: VarBody::Other,
+            is_self_parameter: name == "self",
+        }),
     ));
 }
 
@@ -133,7 +174,8 @@ fn handle_function_declation(function_declaration: FunctionDeclaration, tokens:
             &function_declaration.span,
             function_declaration.visibility,
         )),
-    );
+    )
+    .with_documentation(ident);
     tokens.push(token);
 
     for param in function_declaration.parameters {
@@ -185,7 +227,8 @@ fn handle_declaration(declaration: Declaration, tokens: &mut Vec<Token>) {
             let token = Token::from_ident(
                 ident,
                 TokenType::TraitDeclaration(get_trait_details(&trait_dec)),
-            );
+            )
+            .with_documentation(ident);
             tokens.push(token);
 
             for func_dec in trait_dec.methods {
@@ -197,7 +240,8 @@ fn handle_declaration(declaration: Declaration, tokens: &mut Vec<Token>) {
             let token = Token::from_ident(
                 ident,
                 TokenType::StructDeclaration(get_struct_details(&struct_dec)),
-            );
+            )
+            .with_documentation(ident);
             tokens.push(token);
 
             for field in struct_dec.fields {
@@ -213,7 +257,8 @@ fn handle_declaration(declaration: Declaration, tokens: &mut Vec<Token>) {
             let token = Token::from_ident(
                 ident,
                 TokenType::EnumDeclaration(get_enum_details(&enum_dec)),
-            );
+            )
+            .with_documentation(ident);
             tokens.push(token);
 
             for variant in enum_dec.variants {
@@ -275,9 +320,26 @@ fn handle_declaration(declaration: Declaration, tokens: &mut Vec<Token>) {
             tokens.push(token);
         }
         Declaration::StorageDeclaration(storage_dec) => {
+            // the declaration's span starts at the `storage` keyword
+            let span = &storage_dec.span;
+            if let Some(keyword_span) = Span::new(
+                span.src().clone(),
+                span.start(),
+                span.start() + "storage".len(),
+                span.path().cloned(),
+            ) {
+                tokens.push(Token::from_span(
+                    keyword_span,
+                    TokenType::StorageDeclaration,
+                ));
+            }
+
             for field in storage_dec.fields {
                 let ident = &field.name;
-                let token = Token::from_ident(ident, TokenType::StorageFieldDeclaration);
+                let token = Token::from_ident(
+                    ident,
+                    TokenType::StorageFieldDeclaration(get_storage_field_details(&field)),
+                );
                 tokens.push(token);
             }
         }
@@ -357,12 +419,25 @@ fn handle_expression(exp: Expression, tokens: &mut Vec<Token>) {
                 handle_expression(*r#else, tokens);
             }
         }
+        Expression::IfLet {
+            expr, then, r#else, ..
+        } => {
+            // TODO: handle_scrutinee(scrutinee, tokens);
+            handle_expression(*expr, tokens);
+            handle_expression(*then, tokens);
+            if let Some(r#else) = r#else {
+                handle_expression(*r#else, tokens);
+            }
+        }
         Expression::MatchExp {
             value, branches, ..
         } => {
             handle_expression(*value, tokens);
             for branch in branches {
                 // TODO: handle_scrutinee(branch.scrutinee, tokens);
+                if let Some(guard) = branch.guard {
+                    handle_expression(guard, tokens);
+                }
                 handle_expression(branch.result, tokens);
             }
         }
@@ -431,6 +506,9 @@ fn handle_expression(exp: Expression, tokens: &mut Vec<Token>) {
 
             handle_expression(*address, tokens);
         }
+        Expression::Cast { expr, .. } => {
+            handle_expression(*expr, tokens);
+        }
         Expression::ArrayIndex { prefix, index, .. } => {
             handle_expression(*prefix, tokens);
             handle_expression(*index, tokens);
@@ -455,6 +533,9 @@ fn handle_intrinsic_function(kind: IntrinsicFunctionKind, tokens: &mut Vec<Token
         IntrinsicFunctionKind::SizeOfType { .. } => {}
         IntrinsicFunctionKind::IsRefType { .. } => {}
         IntrinsicFunctionKind::GetStorageKey => {}
+        IntrinsicFunctionKind::Revert { code } => {
+            handle_expression(*code, tokens);
+        }
     }
 }
 
@@ -476,3 +557,58 @@ fn desugared_op(method_name: &MethodName) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn parsed_tokens(program: &str) -> Vec<Token> {
+        let parse_program = sway_core::parse(Arc::from(program), None)
+            .value
+            .expect("program should parse");
+        let mut tokens = vec![];
+        for node in parse_program.root.tree.root_nodes {
+            traverse_node(node, &mut tokens);
+        }
+        tokens
+    }
+
+    #[test]
+    fn method_application_tokens_survive_argument_count_mismatch() {
+        // `foo` takes a single argument but is called with two; the parsed traversal should
+        // still produce tokens for the method name and every argument.
+        let tokens = parsed_tokens(
+            r#"script;
+
+struct A {}
+
+impl A {
+    fn foo(self, b: u64) -> u64 {
+        b
+    }
+}
+
+fn main() {
+    let a = A {};
+    let b = 1;
+    let c = 2;
+    a.foo(b, c);
+}
+"#,
+        );
+        let find = |name: &str, line: u32| {
+            tokens
+                .iter()
+                .find(|token| token.name == name && token.line_start == line)
+                .map(|token| &token.token_type)
+        };
+        assert!(matches!(
+            find("foo", 14),
+            Some(TokenType::MethodApplication)
+        ));
+        assert!(matches!(find("a", 14), Some(TokenType::VariableExpression)));
+        assert!(matches!(find("b", 14), Some(TokenType::VariableExpression)));
+        assert!(matches!(find("c", 14), Some(TokenType::VariableExpression)));
+    }
+}
@@ -1,10 +1,12 @@
 use crate::utils::function::extract_fn_signature;
+use serde::{Serialize, Serializer};
 use sway_core::{
-    ConstantDeclaration, EnumDeclaration, StructDeclaration, TraitDeclaration, Visibility,
+    type_engine::TypeInfo, ConstantDeclaration, EnumDeclaration, StorageField, StructDeclaration,
+    TraitDeclaration, Visibility,
 };
 use sway_types::{Ident, Span};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum TokenType {
     Library,
 
@@ -19,7 +21,8 @@ pub enum TokenType {
     ConstantDeclaration(ConstDetails),
     TraitFunction,
     EnumVariant,
-    StorageFieldDeclaration,
+    StorageDeclaration,
+    StorageFieldDeclaration(StorageFieldDetails),
 
     FunctionApplication,
     VariableExpression,
@@ -31,7 +34,7 @@ pub enum TokenType {
     EnumApplication,
     StructField(StructFieldDetails),
     StructExpressionField(StructFieldDetails),
-    FunctionParameter,
+    FunctionParameter(VariableDetails),
     Unknown,
 }
 
@@ -70,10 +73,20 @@ pub fn get_enum_details(enum_dec: &EnumDeclaration) -> EnumDetails {
 pub fn get_const_details(const_dec: &ConstantDeclaration) -> ConstDetails {
     ConstDetails {
         visibility: const_dec.visibility,
+        type_name: match const_dec.type_ascription {
+            TypeInfo::Unknown => None,
+            ref type_ascription => Some(type_ascription.to_string()),
+        },
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+pub fn get_storage_field_details(field: &StorageField) -> StorageFieldDetails {
+    StorageFieldDetails {
+        type_name: field.type_info.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct FunctionDetails {
     pub signature: String,
     pub visibility: Visibility,
@@ -88,39 +101,53 @@ impl FunctionDetails {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct StructDetails {
     pub visibility: Visibility,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct TraitDetails {
     pub visibility: Visibility,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct EnumDetails {
     pub visibility: Visibility,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ConstDetails {
     pub visibility: Visibility,
+    /// The type written after the constant's name, if it was annotated.
+    pub type_name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StorageFieldDetails {
+    pub type_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct VariableDetails {
     pub is_mutable: bool,
     pub var_body: VarBody,
+    /// Whether this is the implicit `self` parameter of a method, which can't be renamed.
+    pub is_self_parameter: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct StructFieldDetails {
     // Used for looking up the parent struct that the field is a part of
+    #[serde(serialize_with = "serialize_ident")]
     pub parent_ident: Ident,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+fn serialize_ident<S: Serializer>(ident: &Ident, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(ident.as_str())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum VarBody {
     FunctionCall(String),
     Type(String),
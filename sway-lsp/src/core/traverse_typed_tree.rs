@@ -23,7 +23,9 @@ pub fn traverse_node(node: &TypedAstNode, tokens: &mut TokenMap) {
             handle_expression(expression, tokens)
         }
         TypedAstNodeContent::WhileLoop(while_loop) => handle_while_loop(while_loop, tokens),
-        TypedAstNodeContent::SideEffect => (),
+        TypedAstNodeContent::Break(_)
+        | TypedAstNodeContent::Continue(_)
+        | TypedAstNodeContent::SideEffect => (),
     };
 }
 
@@ -246,6 +248,7 @@ fn handle_expression(expression: &TypedExpression, tokens: &mut TokenMap) {
         TypedExpressionVariant::StructExpression {
             ref struct_name,
             ref fields,
+            ..
         } => {
             tokens.insert(
                 to_ident_key(struct_name),
@@ -329,6 +332,9 @@ fn handle_expression(expression: &TypedExpression, tokens: &mut TokenMap) {
                 TokenType::TypedExpression(expression.clone()),
             );
         }
+        TypedExpressionVariant::Cast { exp, .. } => {
+            handle_expression(exp, tokens);
+        }
     }
 }
 
@@ -340,6 +346,9 @@ fn handle_intrinsic_function(kind: &TypedIntrinsicFunctionKind, tokens: &mut Tok
         TypedIntrinsicFunctionKind::SizeOfType { .. } => {}
         TypedIntrinsicFunctionKind::IsRefType { .. } => {}
         TypedIntrinsicFunctionKind::GetStorageKey => {}
+        TypedIntrinsicFunctionKind::Revert { code } => {
+            handle_expression(code, tokens);
+        }
     }
 }
 
@@ -1,4 +1,7 @@
-use tower_lsp::{LspService, Server};
+// Proving the session is `Sync` walks the whole typed namespace each document keeps around.
+#![recursion_limit = "256"]
+
+use tower_lsp::Server;
 
 mod capabilities;
 mod core;
@@ -12,6 +15,6 @@ pub async fn start(config: DebugFlags) {
     let stdin = tokio::io::stdin();
     let stdout = tokio::io::stdout();
 
-    let (service, socket) = LspService::new(|client| Backend::new(client, config));
+    let (service, socket) = Backend::service(config);
     Server::new(stdin, stdout, socket).serve(service).await;
 }
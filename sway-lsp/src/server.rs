@@ -5,16 +5,26 @@ use crate::core::{
 };
 use crate::utils::debug::{self, DebugFlags};
 use forc_util::find_manifest_dir;
-use std::sync::Arc;
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use sway_utils::helpers::get_sway_files;
 use tower_lsp::lsp_types::*;
-use tower_lsp::{jsonrpc, Client, LanguageServer};
+use tower_lsp::{jsonrpc, Client, ClientSocket, LanguageServer, LspService};
+
+const COMPILE_PROGRESS_TOKEN: &str = "sway-lsp/compile";
 
 #[derive(Debug)]
 pub struct Backend {
     pub client: Client,
     session: Arc<Session>,
     config: DebugFlags,
+    work_done_progress: AtomicBool,
 }
 
 impl Backend {
@@ -24,31 +34,115 @@ impl Backend {
             client,
             session,
             config,
+            work_done_progress: AtomicBool::new(false),
         }
     }
 
+    /// Builds the service which serves `Backend`, including the requests that [LanguageServer]
+    /// has no method for yet.
+    pub fn service(config: DebugFlags) -> (LspService<Backend>, ClientSocket) {
+        LspService::build(|client| Backend::new(client, config))
+            .custom_method("textDocument/inlayHint", Backend::inlay_hints)
+            .finish()
+    }
+
+    fn compile_timeout(&self) -> Option<Duration> {
+        self.config.compile_timeout_ms.map(Duration::from_millis)
+    }
+
     async fn log_info_message(&self, message: &str) {
         self.client.log_message(MessageType::INFO, message).await;
     }
 
-    fn parse_and_store_sway_files(&self) -> Result<(), DocumentError> {
+    async fn parse_and_store_sway_files(&self) -> Result<(), DocumentError> {
         let curr_dir = std::env::current_dir().unwrap();
 
         if let Some(path) = find_manifest_dir(&curr_dir) {
             let files = get_sway_files(path);
+            self.parse_and_store_files(&files).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn parse_and_store_files(&self, files: &[PathBuf]) -> Result<(), DocumentError> {
+        let progress = self.begin_progress("Compiling Sway files").await;
 
-            for file_path in files {
-                if let Some(path) = file_path.to_str() {
-                    // store the document
-                    let text_document = TextDocument::build_from_path(path)?;
-                    self.session.store_document(text_document)?;
-                    // parse the document for tokens
-                    let _ = self.session.parse_document(path);
+        let mut result = Ok(());
+        for (index, file_path) in files.iter().enumerate() {
+            if let Some(path) = file_path.to_str() {
+                if let Some(token) = &progress {
+                    let percentage = (index * 100 / files.len()) as u32;
+                    self.report_progress(token, path, percentage).await;
+                }
+                // store the document
+                result = TextDocument::build_from_path(path)
+                    .and_then(|text_document| self.session.store_document(text_document));
+                if result.is_err() {
+                    break;
                 }
+                // parse the document for tokens
+                let _ = self.session.parse_document(path);
             }
         }
 
-        Ok(())
+        if let Some(token) = progress {
+            self.end_progress(token).await;
+        }
+        result
+    }
+
+    // Asks the client to create a progress indicator, returning its token if the client supports
+    // work done progress and accepted the request.
+    async fn begin_progress(&self, title: &str) -> Option<ProgressToken> {
+        if !self.work_done_progress.load(Ordering::Relaxed) {
+            return None;
+        }
+        let token = ProgressToken::String(COMPILE_PROGRESS_TOKEN.into());
+        self.client
+            .send_request::<request::WorkDoneProgressCreate>(WorkDoneProgressCreateParams {
+                token: token.clone(),
+            })
+            .await
+            .ok()?;
+        self.send_progress(
+            token.clone(),
+            WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                title: title.into(),
+                percentage: Some(0),
+                ..Default::default()
+            }),
+        )
+        .await;
+        Some(token)
+    }
+
+    async fn report_progress(&self, token: &ProgressToken, path: &str, percentage: u32) {
+        // the path of each file is only included when asked for, as it makes for a noisy status bar
+        let message = self.config.verbose_progress.then(|| path.to_string());
+        self.send_progress(
+            token.clone(),
+            WorkDoneProgress::Report(WorkDoneProgressReport {
+                message,
+                percentage: Some(percentage),
+                ..Default::default()
+            }),
+        )
+        .await;
+    }
+
+    async fn end_progress(&self, token: ProgressToken) {
+        self.send_progress(token, WorkDoneProgress::End(WorkDoneProgressEnd::default()))
+            .await;
+    }
+
+    async fn send_progress(&self, token: ProgressToken, progress: WorkDoneProgress) {
+        self.client
+            .send_notification::<notification::Progress>(ProgressParams {
+                token,
+                value: ProgressParamsValue::WorkDone(progress),
+            })
+            .await;
     }
 }
 
@@ -58,12 +152,13 @@ fn capabilities() -> ServerCapabilities {
             TextDocumentSyncKind::INCREMENTAL,
         )),
         definition_provider: Some(OneOf::Left(true)),
+        type_definition_provider: Some(TypeDefinitionProviderCapability::Simple(true)),
         semantic_tokens_provider: capabilities::semantic_tokens::get_semantic_tokens(),
         document_symbol_provider: Some(OneOf::Left(true)),
         hover_provider: Some(HoverProviderCapability::Simple(true)),
         completion_provider: Some(CompletionOptions {
             resolve_provider: Some(false),
-            trigger_characters: None,
+            trigger_characters: Some(vec![".".to_string()]),
             ..Default::default()
         }),
         execute_command_provider: Some(ExecuteCommandOptions {
@@ -72,12 +167,24 @@ fn capabilities() -> ServerCapabilities {
         }),
         document_highlight_provider: Some(OneOf::Left(true)),
         document_formatting_provider: Some(OneOf::Left(true)),
+        folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+        inlay_hint_provider: Some(OneOf::Left(true)),
+        rename_provider: Some(OneOf::Right(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: Some(false),
+            },
+        })),
         ..ServerCapabilities::default()
     }
 }
 
 impl Backend {
     async fn publish_diagnostics(&self, uri: Url, diagnostics: Vec<Diagnostic>) {
+        if let Some(dump_path) = &self.config.dump_tokens {
+            self.dump_tokens(&uri, dump_path).await;
+        }
+
         // If parsed_tokens_as_warnings is true, take over the normal error and warning display behavior
         // and instead show the parsed tokens as warnings.
         // This is useful for debugging the lsp parser.
@@ -92,11 +199,38 @@ impl Backend {
         } else {
             // Note: Even if the computed diagnostics vec is empty, we still have to push the empty Vec
             // in order to clear former diagnostics. Newly pushed diagnostics always replace previously pushed diagnostics.
+            let diagnostics = capabilities::diagnostic::group_diagnostics(&uri, diagnostics);
             self.client
                 .publish_diagnostics(uri, diagnostics, None)
                 .await;
         }
     }
+
+    async fn inlay_hints(
+        &self,
+        params: InlayHintParams,
+    ) -> jsonrpc::Result<Option<Vec<InlayHint>>> {
+        Ok(capabilities::inlay_hints::get_inlay_hints(
+            self.session.clone(),
+            params,
+        ))
+    }
+
+    // Writes out the tokens collected from the document so that they can be attached to bug reports.
+    async fn dump_tokens(&self, uri: &Url, dump_path: &Path) {
+        let result = match self.session.documents.get(uri.path()) {
+            Some(document) => debug::dump_tokens_as_json(dump_path, document.get_tokens()),
+            None => return,
+        };
+        if let Err(err) = result {
+            self.client
+                .log_message(
+                    MessageType::ERROR,
+                    format!("Failed to dump tokens to {}: {}", dump_path.display(), err),
+                )
+                .await;
+        }
+    }
 }
 
 #[tower_lsp::async_trait]
@@ -105,22 +239,31 @@ impl LanguageServer for Backend {
         if let Some(options) = params.initialization_options {
             self.session.update_config(options);
         }
+        let work_done_progress = params
+            .capabilities
+            .window
+            .and_then(|window| window.work_done_progress)
+            .unwrap_or(false);
+        self.work_done_progress
+            .store(work_done_progress, Ordering::Relaxed);
 
         self.client
             .log_message(MessageType::INFO, "Initializing the Sway Language Server")
             .await;
 
-        // iterate over the project dir, parse all sway files
-        let _ = self.parse_and_store_sway_files();
-
         Ok(InitializeResult {
             server_info: None,
             capabilities: capabilities(),
+            offset_encoding: None,
         })
     }
 
     // LSP-Server Lifecycle
     async fn initialized(&self, _: InitializedParams) {
+        // iterate over the project dir, parse all sway files
+        // this happens once initialized so that progress can be reported to the client
+        let _ = self.parse_and_store_sway_files().await;
+
         self.log_info_message("Sway Language Server Initialized")
             .await;
     }
@@ -134,19 +277,34 @@ impl LanguageServer for Backend {
     // Document Handlers
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
         let uri = params.text_document.uri.clone();
-        let diagnostics = capabilities::text_sync::handle_open_file(self.session.clone(), &params);
+        let diagnostics = capabilities::text_sync::handle_open_file(
+            self.session.clone(),
+            &params,
+            self.compile_timeout(),
+        )
+        .await;
         self.publish_diagnostics(uri, diagnostics).await;
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
         let uri = params.text_document.uri.clone();
-        let diagnostics = capabilities::text_sync::handle_change_file(self.session.clone(), params);
+        let diagnostics = capabilities::text_sync::handle_change_file(
+            self.session.clone(),
+            params,
+            self.compile_timeout(),
+        )
+        .await;
         self.publish_diagnostics(uri, diagnostics).await;
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
         let uri = params.text_document.uri.clone();
-        let diagnostics = capabilities::text_sync::handle_save_file(self.session.clone(), &params);
+        let diagnostics = capabilities::text_sync::handle_save_file(
+            self.session.clone(),
+            &params,
+            self.compile_timeout(),
+        )
+        .await;
         self.publish_diagnostics(uri, diagnostics).await;
     }
 
@@ -214,6 +372,16 @@ impl LanguageServer for Backend {
         ))
     }
 
+    async fn goto_type_definition(
+        &self,
+        params: request::GotoTypeDefinitionParams,
+    ) -> jsonrpc::Result<Option<request::GotoTypeDefinitionResponse>> {
+        Ok(capabilities::go_to::go_to_type_definition(
+            self.session.clone(),
+            params,
+        ))
+    }
+
     async fn formatting(
         &self,
         params: DocumentFormattingParams,
@@ -224,8 +392,15 @@ impl LanguageServer for Backend {
         ))
     }
 
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> jsonrpc::Result<Option<Vec<FoldingRange>>> {
+        Ok(self.session.get_folding_ranges(&params.text_document.uri))
+    }
+
     async fn rename(&self, params: RenameParams) -> jsonrpc::Result<Option<WorkspaceEdit>> {
-        Ok(capabilities::rename::rename(self.session.clone(), params))
+        capabilities::rename::rename(self.session.clone(), params)
     }
 
     async fn prepare_rename(
@@ -246,9 +421,8 @@ mod tests {
     use tower::{Service, ServiceExt};
 
     use super::*;
-    use futures::stream::StreamExt;
+    use futures::{sink::SinkExt, stream::StreamExt};
     use tower_lsp::jsonrpc::{self, Request, Response};
-    use tower_lsp::LspService;
 
     // Simple sway script used for testing LSP capabilites
     const SWAY_PROGRAM: &str = r#"script;
@@ -285,7 +459,10 @@ fn main() {
 "#;
 
     fn load_test_sway_file(sway_file: &str) -> Url {
-        let file_name = "tmp_sway_test_file.sw";
+        load_named_test_sway_file("tmp_sway_test_file.sw", sway_file)
+    }
+
+    fn load_named_test_sway_file(file_name: &str, sway_file: &str) -> Url {
         let dir = env::temp_dir().join(file_name);
         let mut file = File::create(&dir).unwrap();
         file.write_all(sway_file.as_bytes()).unwrap();
@@ -357,7 +534,7 @@ fn main() {
 
     #[tokio::test]
     async fn initialize() {
-        let (mut service, _) = LspService::new(|client| Backend::new(client, config()));
+        let (mut service, _) = Backend::service(config());
 
         // send "initialize" request
         let _ = initialize_request(&mut service).await;
@@ -365,7 +542,7 @@ fn main() {
 
     #[tokio::test]
     async fn initialized() {
-        let (mut service, _) = LspService::new(|client| Backend::new(client, config()));
+        let (mut service, _) = Backend::service(config());
 
         // send "initialize" request
         let _ = initialize_request(&mut service).await;
@@ -376,7 +553,7 @@ fn main() {
 
     #[tokio::test]
     async fn initializes_only_once() {
-        let (mut service, _) = LspService::new(|client| Backend::new(client, config()));
+        let (mut service, _) = Backend::service(config());
 
         // send "initialize" request
         let initialize = initialize_request(&mut service).await;
@@ -392,7 +569,7 @@ fn main() {
 
     #[tokio::test]
     async fn shutdown() {
-        let (mut service, _) = LspService::new(|client| Backend::new(client, config()));
+        let (mut service, _) = Backend::service(config());
 
         // send "initialize" request
         let _ = initialize_request(&mut service).await;
@@ -414,7 +591,7 @@ fn main() {
 
     #[tokio::test]
     async fn refuses_requests_after_shutdown() {
-        let (mut service, _) = LspService::new(|client| Backend::new(client, config()));
+        let (mut service, _) = Backend::service(config());
 
         // send "initialize" request
         let _ = initialize_request(&mut service).await;
@@ -429,7 +606,7 @@ fn main() {
 
     #[tokio::test]
     async fn did_open() {
-        let (mut service, mut messages) = LspService::new(|client| Backend::new(client, config()));
+        let (mut service, mut messages) = Backend::service(config());
 
         // send "initialize" request
         let _ = initialize_request(&mut service).await;
@@ -457,7 +634,7 @@ fn main() {
 
     #[tokio::test]
     async fn did_close() {
-        let (mut service, _) = LspService::new(|client| Backend::new(client, config()));
+        let (mut service, _) = Backend::service(config());
 
         // send "initialize" request
         let _ = initialize_request(&mut service).await;
@@ -482,7 +659,7 @@ fn main() {
 
     #[tokio::test]
     async fn did_change() {
-        let (mut service, _) = LspService::new(|client| Backend::new(client, config()));
+        let (mut service, _) = Backend::service(config());
 
         // send "initialize" request
         let _ = initialize_request(&mut service).await;
@@ -546,4 +723,887 @@ fn main() {
         // send "exit" request
         exit_notification(&mut service).await;
     }
+
+    const RENAME_PROGRAM: &str = r#"script;
+
+fn foo() -> u64 {
+    1
+}
+
+fn main() {
+    let a = foo();
+    let b = foo();
+}
+"#;
+
+    async fn rename_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+        line: u32,
+        character: u32,
+        new_name: &str,
+    ) -> Option<Response> {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+            "position": {
+                "line": line,
+                "character": character,
+            },
+            "newName": new_name,
+        });
+        let rename = Request::build("textDocument/rename")
+            .params(params)
+            .id(1)
+            .finish();
+        service.ready().await.unwrap().call(rename).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn rename() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let uri = load_named_test_sway_file("tmp_sway_rename_test_file.sw", RENAME_PROGRAM);
+        did_open_notification(&mut service, &uri, RENAME_PROGRAM).await;
+
+        // rename `foo` from its first call site
+        let response = rename_request(&mut service, &uri, 7, 13, "bar").await;
+        let (_, result) = response.unwrap().into_parts();
+        let edit: WorkspaceEdit = serde_json::from_value(result.unwrap()).unwrap();
+        let mut edits = edit.changes.unwrap().remove(&uri).unwrap();
+        edits.sort_by_key(|edit| edit.range.start.line);
+        assert!(edits.iter().all(|edit| edit.new_text == "bar"));
+        let lines = edits
+            .iter()
+            .map(|edit| edit.range.start.line)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec![2, 7, 8]);
+
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
+
+    #[tokio::test]
+    async fn rename_rejects_invalid_names() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let uri = load_named_test_sway_file("tmp_sway_rename_invalid_test_file.sw", RENAME_PROGRAM);
+        did_open_notification(&mut service, &uri, RENAME_PROGRAM).await;
+
+        for new_name in ["1foo", "fn", "foo-bar", ""] {
+            let response = rename_request(&mut service, &uri, 7, 13, new_name).await;
+            let (_, result) = response.unwrap().into_parts();
+            assert!(result.is_err(), "\"{}\" should be rejected", new_name);
+        }
+
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
+
+    async fn prepare_rename_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+        line: u32,
+        character: u32,
+    ) -> Option<Response> {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+            "position": {
+                "line": line,
+                "character": character,
+            },
+        });
+        let prepare_rename = Request::build("textDocument/prepareRename")
+            .params(params)
+            .id(1)
+            .finish();
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call(prepare_rename)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn prepare_rename() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let program = r#"script;
+
+fn foo() -> u64 {
+    1
+}
+
+fn main() {
+    let a = foo();
+    let b = dependency_fn();
+}
+"#;
+        let uri = load_named_test_sway_file("tmp_sway_prepare_rename_test_file.sw", program);
+        did_open_notification(&mut service, &uri, program).await;
+
+        // `foo` is declared in an open document, so can be renamed from its call site
+        let response = prepare_rename_request(&mut service, &uri, 7, 13).await;
+        let (_, result) = response.unwrap().into_parts();
+        let prepared: Option<PrepareRenameResponse> =
+            serde_json::from_value(result.unwrap()).unwrap();
+        assert!(matches!(
+            prepared,
+            Some(PrepareRenameResponse::RangeWithPlaceholder { placeholder, .. })
+                if placeholder == "foo"
+        ));
+
+        // `dependency_fn` isn't declared anywhere that we can see
+        let response = prepare_rename_request(&mut service, &uri, 8, 13).await;
+        let (_, result) = response.unwrap().into_parts();
+        assert_eq!(result.unwrap(), json!(null));
+
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
+
+    #[tokio::test]
+    async fn prepare_rename_rejects_self() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let program = r#"script;
+
+struct A {}
+
+impl A {
+    fn foo(self, b: u64) -> u64 {
+        b
+    }
+}
+"#;
+        let uri = load_named_test_sway_file("tmp_sway_prepare_rename_self_test_file.sw", program);
+        did_open_notification(&mut service, &uri, program).await;
+
+        // the implicit `self` parameter can't be renamed
+        let response = prepare_rename_request(&mut service, &uri, 5, 12).await;
+        let (_, result) = response.unwrap().into_parts();
+        assert_eq!(result.unwrap(), json!(null));
+
+        // but an ordinary parameter can
+        let response = prepare_rename_request(&mut service, &uri, 5, 17).await;
+        let (_, result) = response.unwrap().into_parts();
+        let prepared: Option<PrepareRenameResponse> =
+            serde_json::from_value(result.unwrap()).unwrap();
+        assert!(matches!(
+            prepared,
+            Some(PrepareRenameResponse::RangeWithPlaceholder { placeholder, .. })
+                if placeholder == "b"
+        ));
+
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
+
+    #[tokio::test]
+    async fn folding_range() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let program = r#"script;
+
+// A point
+// in space
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+impl Point {
+    fn sum(self) -> u64 {
+        if self.x > 0 {
+            self.x + self.y
+        } else {
+            self.y
+        }
+    }
+}
+
+fn main() {
+    let p = Point { x: 1, y: 2 };
+    let s = p.sum();
+}
+"#;
+        let uri = load_named_test_sway_file("tmp_sway_folding_range_test_file.sw", program);
+        did_open_notification(&mut service, &uri, program).await;
+
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+        });
+        let folding_range = Request::build("textDocument/foldingRange")
+            .params(params)
+            .id(1)
+            .finish();
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(folding_range)
+            .await
+            .unwrap();
+        let (_, result) = response.unwrap().into_parts();
+        let ranges: Vec<FoldingRange> = serde_json::from_value(result.unwrap()).unwrap();
+        let lines = ranges
+            .iter()
+            .map(|range| (range.start_line, range.end_line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                (2, 3),
+                (4, 7),
+                (9, 17),
+                (10, 16),
+                (11, 15),
+                (13, 15),
+                (19, 22)
+            ]
+        );
+        assert_eq!(ranges[0].kind, Some(FoldingRangeKind::Comment));
+        assert!(ranges[1..]
+            .iter()
+            .all(|range| range.kind == Some(FoldingRangeKind::Region)));
+
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
+
+    #[tokio::test]
+    async fn document_highlight() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let program = r#"script;
+
+fn main() -> u64 {
+    let count = 1;
+    let twice = count + count;
+    twice
+}
+"#;
+        let uri = load_named_test_sway_file("tmp_sway_document_highlight_test_file.sw", program);
+        did_open_notification(&mut service, &uri, program).await;
+
+        // highlight from the first use of `count`
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+            "position": {
+                "line": 4,
+                "character": 17,
+            },
+        });
+        let highlight = Request::build("textDocument/documentHighlight")
+            .params(params)
+            .id(1)
+            .finish();
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(highlight)
+            .await
+            .unwrap();
+        let (_, result) = response.unwrap().into_parts();
+        let mut highlights: Vec<DocumentHighlight> =
+            serde_json::from_value(result.unwrap()).unwrap();
+        highlights
+            .sort_by_key(|highlight| (highlight.range.start.line, highlight.range.start.character));
+        let found = highlights
+            .iter()
+            .map(|highlight| {
+                (
+                    highlight.range.start.line,
+                    highlight.range.start.character,
+                    highlight.kind,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                (3, 8, Some(DocumentHighlightKind::WRITE)),
+                (4, 16, Some(DocumentHighlightKind::READ)),
+                (4, 24, Some(DocumentHighlightKind::READ)),
+            ]
+        );
+
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
+
+    // Opens `program` and returns the diagnostics which were published for it.
+    async fn did_open_and_wait_for_diagnostics(
+        service: &mut LspService<Backend>,
+        socket: tower_lsp::ClientSocket,
+        file_name: &str,
+        program: &str,
+    ) -> (Url, Vec<Diagnostic>) {
+        // drain the client socket so that the server never blocks on sending us a message
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(socket.for_each(move |request| {
+            let _ = tx.send(request);
+            async {}
+        }));
+
+        let _ = initialize_request(service).await;
+        initialized_notification(service).await;
+
+        let uri = load_named_test_sway_file(file_name, program);
+        did_open_notification(service, &uri, program).await;
+
+        while let Some(request) = rx.recv().await {
+            if request.method() == "textDocument/publishDiagnostics" {
+                let params: PublishDiagnosticsParams =
+                    serde_json::from_value(request.params().unwrap().clone()).unwrap();
+                assert_eq!(params.uri, uri);
+                return (uri, params.diagnostics);
+            }
+        }
+        panic!("no diagnostics were published");
+    }
+
+    #[tokio::test]
+    async fn compile_timeout_reports_slow_compile() {
+        let (mut service, socket) = LspService::new(|client| {
+            Backend::new(
+                client,
+                DebugFlags {
+                    compile_timeout_ms: Some(1),
+                    ..Default::default()
+                },
+            )
+        });
+
+        let mut program = "script;\n".to_string();
+        for i in 0..500 {
+            program.push_str(&format!(
+                "fn f{i}(a: u64) -> u64 {{\n    let b = a + {i};\n    let c = b * 2;\n    c\n}}\n"
+            ));
+        }
+        program.push_str("fn main() {\n    let x = f0(1);\n}\n");
+
+        let (_, diagnostics) = did_open_and_wait_for_diagnostics(
+            &mut service,
+            socket,
+            "tmp_sway_compile_timeout_test_file.sw",
+            &program,
+        )
+        .await;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].severity,
+            Some(DiagnosticSeverity::INFORMATION)
+        );
+
+        // the server should still answer requests while the compile finishes in the background
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
+
+    #[tokio::test]
+    async fn compile_within_timeout() {
+        let (mut service, socket) = LspService::new(|client| {
+            Backend::new(
+                client,
+                DebugFlags {
+                    compile_timeout_ms: Some(60_000),
+                    ..Default::default()
+                },
+            )
+        });
+
+        let (uri, diagnostics) = did_open_and_wait_for_diagnostics(
+            &mut service,
+            socket,
+            "tmp_sway_compile_within_timeout_test_file.sw",
+            RENAME_PROGRAM,
+        )
+        .await;
+        assert!(diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.severity != Some(DiagnosticSeverity::INFORMATION)));
+
+        // the parsed document is kept, so `foo` can be found from its call site
+        let response = prepare_rename_request(&mut service, &uri, 7, 13).await;
+        let (_, result) = response.unwrap().into_parts();
+        assert_ne!(result.unwrap(), json!(null));
+
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
+
+    #[tokio::test]
+    async fn dump_tokens_writes_json() {
+        let dump_path = env::temp_dir().join("tmp_sway_dump_tokens.jsonl");
+        let _ = std::fs::remove_file(&dump_path);
+        let dump_tokens = Some(dump_path.clone());
+        let (mut service, socket) = LspService::new(|client| {
+            Backend::new(
+                client,
+                DebugFlags {
+                    dump_tokens,
+                    ..Default::default()
+                },
+            )
+        });
+
+        // the tokens are written out before the diagnostics are published
+        let _ = did_open_and_wait_for_diagnostics(
+            &mut service,
+            socket,
+            "tmp_sway_dump_tokens_test_file.sw",
+            SWAY_PROGRAM,
+        )
+        .await;
+
+        let dump = std::fs::read_to_string(&dump_path).unwrap();
+        let tokens: Vec<serde_json::Value> = dump
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(!tokens.is_empty());
+        let particle = tokens
+            .iter()
+            .find(|token| token["name"] == "Particle")
+            .unwrap();
+        assert_eq!(
+            particle["token_type"],
+            json!({ "StructDeclaration": { "visibility": "Private" } })
+        );
+        assert_eq!(particle["line_start"], json!(5));
+        assert_eq!(particle["length"], json!(8));
+        assert_eq!(
+            particle["range"]["start"],
+            json!({ "line": 5, "character": 7 })
+        );
+
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
+
+    #[tokio::test]
+    async fn compile_progress_lifecycle() {
+        let mut client = None;
+        let (mut service, socket) = LspService::new(|c| {
+            client = Some(c.clone());
+            Backend::new(c, config())
+        });
+        let backend = Backend::new(
+            client.unwrap(),
+            DebugFlags {
+                verbose_progress: true,
+                ..Default::default()
+            },
+        );
+
+        // accept every request the server makes and forward everything it sends us
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let (mut requests, mut responses) = socket.split();
+        tokio::spawn(async move {
+            while let Some(request) = requests.next().await {
+                if let Some(id) = request.id() {
+                    let _ = responses
+                        .send(Response::from_ok(id.clone(), json!(null)))
+                        .await;
+                }
+                let _ = tx.send(request);
+            }
+        });
+
+        let _ = initialize_request(&mut service).await;
+        let params = serde_json::from_value(json!({
+            "capabilities": { "window": { "workDoneProgress": true } },
+        }))
+        .unwrap();
+        let _ = backend.initialize(params).await.unwrap();
+
+        let files = [
+            "tmp_sway_progress_test_file_a.sw",
+            "tmp_sway_progress_test_file_b.sw",
+        ]
+        .iter()
+        .map(|file_name| {
+            load_named_test_sway_file(file_name, SWAY_PROGRAM)
+                .to_file_path()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+        backend.parse_and_store_files(&files).await.unwrap();
+
+        let mut progress = vec![];
+        while let Some(request) = rx.recv().await {
+            match request.method() {
+                "window/workDoneProgress/create" => {
+                    assert!(
+                        progress.is_empty(),
+                        "progress was reported before being created"
+                    );
+                    let params: WorkDoneProgressCreateParams =
+                        serde_json::from_value(request.params().unwrap().clone()).unwrap();
+                    progress.push((params.token, None));
+                }
+                "$/progress" => {
+                    let params: ProgressParams =
+                        serde_json::from_value(request.params().unwrap().clone()).unwrap();
+                    let ProgressParamsValue::WorkDone(value) = params.value;
+                    let is_end = matches!(value, WorkDoneProgress::End(_));
+                    progress.push((params.token, Some(value)));
+                    if is_end {
+                        break;
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        // every notification uses the token that was created
+        let token = ProgressToken::String(COMPILE_PROGRESS_TOKEN.into());
+        assert!(progress.iter().all(|(t, _)| *t == token));
+        let values = progress
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect::<Vec<_>>();
+        assert!(values[0].is_none());
+        assert!(matches!(values[1], Some(WorkDoneProgress::Begin(_))));
+        let reports = values[2..values.len() - 1]
+            .iter()
+            .map(|value| match value {
+                Some(WorkDoneProgress::Report(report)) => {
+                    (report.message.clone().unwrap(), report.percentage.unwrap())
+                }
+                _ => panic!("expected a progress report, found {:?}", value),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reports,
+            vec![
+                (files[0].to_str().unwrap().to_string(), 0),
+                (files[1].to_str().unwrap().to_string(), 50),
+            ]
+        );
+        assert!(matches!(
+            values.last(),
+            Some(Some(WorkDoneProgress::End(_)))
+        ));
+    }
+
+    async fn hover_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+        line: u32,
+        character: u32,
+    ) -> Hover {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+            "position": {
+                "line": line,
+                "character": character,
+            },
+        });
+        let hover = Request::build("textDocument/hover")
+            .params(params)
+            .id(1)
+            .finish();
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(hover)
+            .await
+            .unwrap()
+            .unwrap();
+        let (_, result) = response.into_parts();
+        serde_json::from_value(result.unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn hover_shows_documentation() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let program = r#"script;
+
+/// Adds one to `x`.
+///
+/// Wraps on overflow.
+#[inline(never)]
+fn add_one(x: u64) -> u64 {
+    x + 1
+}
+
+// Not documentation.
+fn main() {
+    let y = add_one(1);
+}
+"#;
+        let uri = load_named_test_sway_file("tmp_sway_hover_test_file.sw", program);
+        did_open_notification(&mut service, &uri, program).await;
+
+        let expected =
+            "Adds one to `x`.\n\nWraps on overflow.\n\n```sway\nfn add_one(x: u64) -> u64\n```";
+        // hovering over the declaration and over a call site both show the doc comment
+        for (line, character) in [(6, 5), (12, 14)] {
+            let hover = hover_request(&mut service, &uri, line, character).await;
+            match hover.contents {
+                HoverContents::Markup(markup) => assert_eq!(markup.value, expected),
+                contents => panic!("expected markup, found {:?}", contents),
+            }
+        }
+
+        let hover = hover_request(&mut service, &uri, 11, 4).await;
+        match hover.contents {
+            HoverContents::Markup(markup) => assert_eq!(markup.value, "```sway\nfn main()\n```"),
+            contents => panic!("expected markup, found {:?}", contents),
+        }
+    }
+
+    #[tokio::test]
+    async fn hover_shows_inferred_variable_type() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let program = r#"script;
+
+fn foo<T>(value: T) -> T {
+    value
+}
+
+fn main() {
+    let x = foo(true);
+}
+"#;
+        let uri = load_named_test_sway_file("tmp_sway_hover_type_test_file.sw", program);
+        did_open_notification(&mut service, &uri, program).await;
+
+        // the signature of `foo` only says `T`, the type checker knows it's a `bool` here
+        let hover = hover_request(&mut service, &uri, 7, 8).await;
+        match hover.contents {
+            HoverContents::Markup(markup) => {
+                assert_eq!(markup.value, "```sway\nlet x: bool\n```")
+            }
+            contents => panic!("expected markup, found {:?}", contents),
+        }
+    }
+
+    #[tokio::test]
+    async fn hover_shows_constant_and_storage_field_types() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let program = r#"contract;
+
+const LIMIT: u64 = 10;
+
+storage {
+    counter: u64,
+}
+
+abi Counter {
+    #[storage(read)]
+    fn get() -> u64;
+}
+
+impl Counter for Contract {
+    #[storage(read)]
+    fn get() -> u64 {
+        storage.counter + LIMIT
+    }
+}
+"#;
+        let uri = load_named_test_sway_file("tmp_sway_hover_storage_test_file.sw", program);
+        did_open_notification(&mut service, &uri, program).await;
+
+        for (line, character, expected) in [
+            (2, 7, "pub const LIMIT: u64"),
+            (16, 27, "pub const LIMIT: u64"),
+            (4, 2, "storage"),
+            (5, 5, "counter: u64"),
+            (16, 17, "counter: u64"),
+        ] {
+            let hover = hover_request(&mut service, &uri, line, character).await;
+            match hover.contents {
+                HoverContents::Markup(markup) => {
+                    assert_eq!(markup.value, format!("```sway\n{}\n```", expected))
+                }
+                contents => panic!("expected markup, found {:?}", contents),
+            }
+        }
+    }
+
+    async fn type_definition_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+        line: u32,
+        character: u32,
+    ) -> Option<GotoDefinitionResponse> {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+            "position": {
+                "line": line,
+                "character": character,
+            },
+        });
+        let type_definition = Request::build("textDocument/typeDefinition")
+            .params(params)
+            .id(1)
+            .finish();
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(type_definition)
+            .await
+            .unwrap()
+            .unwrap();
+        let (_, result) = response.into_parts();
+        serde_json::from_value(result.unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn goto_type_definition() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let program = r#"script;
+
+struct Point {
+    x: u64,
+}
+
+fn main() {
+    let p = Point { x: 1 };
+    let n = p.x;
+}
+"#;
+        let uri = load_named_test_sway_file("tmp_sway_type_definition_test_file.sw", program);
+        did_open_notification(&mut service, &uri, program).await;
+
+        // a variable of a struct type leads to the struct declaration
+        let response = type_definition_request(&mut service, &uri, 7, 8).await;
+        match response {
+            Some(GotoDefinitionResponse::Scalar(location)) => {
+                assert_eq!(location.uri, uri);
+                assert_eq!(
+                    location.range,
+                    Range::new(Position::new(2, 7), Position::new(2, 12))
+                );
+            }
+            response => panic!("expected a single location, found {:?}", response),
+        }
+
+        // primitive types have no declaration to go to
+        let response = type_definition_request(&mut service, &uri, 8, 8).await;
+        assert_eq!(response, None);
+    }
+
+    async fn inlay_hint_request(
+        service: &mut LspService<Backend>,
+        uri: &Url,
+        end_line: u32,
+    ) -> Vec<InlayHint> {
+        let params = json!({
+            "textDocument": {
+                "uri": uri,
+            },
+            "range": {
+                "start": { "line": 0, "character": 0 },
+                "end": { "line": end_line, "character": 0 },
+            },
+        });
+        let inlay_hint = Request::build("textDocument/inlayHint")
+            .params(params)
+            .id(1)
+            .finish();
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(inlay_hint)
+            .await
+            .unwrap()
+            .unwrap();
+        let (_, result) = response.into_parts();
+        serde_json::from_value(result.unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn inlay_hints() {
+        let (mut service, _) = Backend::service(config());
+        let _ = initialize_request(&mut service).await;
+        initialized_notification(&mut service).await;
+
+        let program = r#"script;
+
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() -> u32 {
+    let first = identity(5u32);
+    identity(first)
+}
+"#;
+        let uri = load_named_test_sway_file("tmp_sway_inlay_hint_test_file.sw", program);
+        did_open_notification(&mut service, &uri, program).await;
+
+        let found = |hints: Vec<InlayHint>| {
+            hints
+                .into_iter()
+                .map(|hint| {
+                    let label = match hint.label {
+                        InlayHintLabel::String(label) => label,
+                        InlayHintLabel::LabelParts(parts) => panic!("{:?}", parts),
+                    };
+                    (hint.position.line, hint.position.character, label)
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            found(inlay_hint_request(&mut service, &uri, 10).await),
+            vec![
+                (7, 24, "::<u32>".to_string()),
+                (8, 12, "::<u32>".to_string())
+            ]
+        );
+
+        // only the hints within the requested range are returned
+        assert_eq!(
+            found(inlay_hint_request(&mut service, &uri, 8).await),
+            vec![(7, 24, "::<u32>".to_string())]
+        );
+
+        let _ = shutdown_request(&mut service).await;
+        exit_notification(&mut service).await;
+    }
 }
@@ -3,13 +3,41 @@ use sway_fmt::FormattingOptions;
 
 const ALIGN_FIELDS_FIELD: &str = "alignFields";
 const TAB_SIZE_FIELD: &str = "tabSize";
+const INLAY_HINTS_FIELD: &str = "inlayHints";
+const CHAINING_HINTS_FIELD: &str = "chainingHints";
+const GENERIC_PARAM_HINTS_FIELD: &str = "genericParamHints";
+const MAX_LENGTH_FIELD: &str = "maxLength";
 const TAB_SIZE: u64 = 4;
 const ALIGN_FIELDS: bool = true;
+const CHAINING_HINTS: bool = true;
+const GENERIC_PARAM_HINTS: bool = true;
 
 #[derive(Debug, Clone, Copy)]
 pub struct SwayConfig {
     tab_size: u64,
     align_fields: bool,
+    pub inlay_hints: InlayHintsConfig,
+}
+
+/// Controls which inlay hints are shown and how they are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlayHintsConfig {
+    /// Show the intermediate type after each step of a method chain.
+    pub chaining_hints: bool,
+    /// Show the inferred type arguments of generic functions and structs used without them.
+    pub generic_param_hints: bool,
+    /// Hint labels longer than this many characters are truncated.
+    pub max_length: Option<usize>,
+}
+
+impl Default for InlayHintsConfig {
+    fn default() -> Self {
+        Self {
+            chaining_hints: CHAINING_HINTS,
+            generic_param_hints: GENERIC_PARAM_HINTS,
+            max_length: None,
+        }
+    }
 }
 
 impl SwayConfig {
@@ -17,16 +45,19 @@ impl SwayConfig {
         Self {
             align_fields: ALIGN_FIELDS,
             tab_size: TAB_SIZE,
+            inlay_hints: InlayHintsConfig::default(),
         }
     }
 
     pub fn with_options(options: Value) -> Self {
         let align_fields = extract_align_fields(&options);
         let tab_size = extract_tab_size(&options);
+        let inlay_hints = extract_inlay_hints(&options);
 
         Self {
             align_fields,
             tab_size,
+            inlay_hints,
         }
     }
 }
@@ -74,3 +105,32 @@ fn extract_tab_size(options: &Value) -> u64 {
         TAB_SIZE
     }
 }
+
+fn extract_inlay_hints(options: &Value) -> InlayHintsConfig {
+    let inlay_hints_object = match options {
+        Value::Object(options_object) => match options_object.get(INLAY_HINTS_FIELD) {
+            Some(Value::Object(inlay_hints_object)) => inlay_hints_object,
+            _ => return InlayHintsConfig::default(),
+        },
+        _ => return InlayHintsConfig::default(),
+    };
+
+    let chaining_hints = match inlay_hints_object.get(CHAINING_HINTS_FIELD) {
+        Some(Value::Bool(value)) => *value,
+        _ => CHAINING_HINTS,
+    };
+    let generic_param_hints = match inlay_hints_object.get(GENERIC_PARAM_HINTS_FIELD) {
+        Some(Value::Bool(value)) => *value,
+        _ => GENERIC_PARAM_HINTS,
+    };
+    let max_length = match inlay_hints_object.get(MAX_LENGTH_FIELD) {
+        Some(Value::Number(value)) => value.as_u64().map(|value| value as usize),
+        _ => None,
+    };
+
+    InlayHintsConfig {
+        chaining_hints,
+        generic_param_hints,
+        max_length,
+    }
+}
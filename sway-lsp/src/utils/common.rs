@@ -34,6 +34,29 @@ pub(crate) fn extract_var_body(var_dec: &VariableDeclaration) -> VarBody {
     }
 }
 
+/// Collects the `///` comment lines directly above the line `span` starts on, skipping over any
+/// attributes in between, with the comment markers stripped.
+pub(crate) fn extract_doc_comment(span: &Span) -> Option<String> {
+    let src = span.src();
+    let line_start = src[..span.start()].rfind('\n').map_or(0, |index| index + 1);
+
+    let mut lines = vec![];
+    for line in src[..line_start].lines().rev() {
+        let line = line.trim();
+        if let Some(doc) = line.strip_prefix("///") {
+            lines.push(doc.strip_prefix(' ').unwrap_or(doc));
+        } else if !line.starts_with("#[") {
+            break;
+        }
+    }
+
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
 pub(crate) fn ident_and_span_at_position(
     cursor_position: Position,
     tokens: &TokenMap,
@@ -48,17 +71,63 @@ pub(crate) fn ident_and_span_at_position(
 }
 
 pub(crate) fn get_range_from_span(span: &Span) -> Range {
-    let start = span.start_pos().line_col();
-    let end = span.end_pos().line_col();
+    let src = span.src();
+    Range {
+        start: get_position_from_byte_offset(src, span.start()),
+        end: get_position_from_byte_offset(src, span.end()),
+    }
+}
 
-    let start_line = start.0 as u32 - 1;
-    let start_character = start.1 as u32 - 1;
+/// LSP positions are zero-based and count characters in UTF-16 code units, so we can't use the
+/// one-based, per-`char` columns returned by `sway_types::span::Position::line_col` directly.
+fn get_position_from_byte_offset(src: &str, offset: usize) -> Position {
+    let preceding = &src[..offset];
+    let line = preceding.matches('\n').count();
+    let line_start = preceding.rfind('\n').map_or(0, |ix| ix + 1);
+    let character = preceding[line_start..].encode_utf16().count();
+    Position::new(line as u32, character as u32)
+}
 
-    let end_line = end.0 as u32 - 1;
-    let end_character = end.1 as u32 - 1;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
 
-    Range {
-        start: Position::new(start_line, start_character),
-        end: Position::new(end_line, end_character),
+    fn span_of(src: &str, ident: &str) -> Span {
+        let start = src.find(ident).unwrap();
+        Span::new(Arc::from(src), start, start + ident.len(), None).unwrap()
+    }
+
+    #[test]
+    fn range_from_span_counts_utf16_code_units() {
+        // The crab is 4 bytes in UTF-8, 1 `char`, but 2 UTF-16 code units.
+        let src = "script;\n/* 🦀 */ let foo = 1;";
+        let range = get_range_from_span(&span_of(src, "foo"));
+        assert_eq!(range.start, Position::new(1, 13));
+        assert_eq!(range.end, Position::new(1, 16));
+    }
+
+    #[test]
+    fn range_from_span_at_start_of_file() {
+        let src = "foo";
+        let range = get_range_from_span(&span_of(src, "foo"));
+        assert_eq!(range.start, Position::new(0, 0));
+        assert_eq!(range.end, Position::new(0, 3));
+
+        let range = get_range_from_span(&Span::dummy());
+        assert_eq!(range.start, Position::new(0, 0));
+        assert_eq!(range.end, Position::new(0, 0));
+    }
+
+    #[test]
+    fn doc_comment_above_declaration() {
+        let src = "// not docs\n/// first\n///second\n#[storage(read)]\nfn foo() {}\n";
+        assert_eq!(
+            extract_doc_comment(&span_of(src, "foo")),
+            Some("first\nsecond".into())
+        );
+
+        let src = "/// docs\nfn bar() {}\n\nfn foo() {}\n";
+        assert_eq!(extract_doc_comment(&span_of(src, "foo")), None);
     }
 }
@@ -4,6 +4,11 @@ use crate::core::{
     typed_token_type::{TokenMap, TokenType},
 };
 use crate::utils::common::get_range_from_span;
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
 use sway_types::{Ident, Spanned};
 use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
 
@@ -13,6 +18,16 @@ pub struct DebugFlags {
     /// Instructs the client to draw squiggly lines
     /// under all of the tokens that our server managed to parse
     pub parsed_tokens_as_warnings: bool,
+    /// Stops waiting on any compilation which takes longer than this many milliseconds,
+    /// so that a pathological input can't hang the server. The compilation itself can't be
+    /// interrupted, it finishes in the background and its now stale result is discarded
+    pub compile_timeout_ms: Option<u64>,
+    /// Includes the path of each file in the progress reported
+    /// while compiling the workspace
+    pub verbose_progress: bool,
+    /// Writes the tokens collected from a document to this file
+    /// as newline-delimited JSON every time it's compiled
+    pub dump_tokens: Option<PathBuf>,
 }
 
 pub fn generate_warnings_for_parsed_tokens(tokens: &[Token]) -> Vec<Diagnostic> {
@@ -29,6 +44,16 @@ pub fn generate_warnings_for_parsed_tokens(tokens: &[Token]) -> Vec<Diagnostic>
     warnings
 }
 
+/// Writes `tokens` to `path` with one JSON object per line, replacing the file's previous contents.
+pub fn dump_tokens_as_json(path: &Path, tokens: &[Token]) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for token in tokens {
+        serde_json::to_writer(&mut writer, token)?;
+        writeln!(writer)?;
+    }
+    writer.flush()
+}
+
 pub fn generate_warnings_for_typed_tokens(tokens: &TokenMap) -> Vec<Diagnostic> {
     let warnings = tokens
         .keys()
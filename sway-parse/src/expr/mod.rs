@@ -24,6 +24,14 @@ pub enum Expr {
         return_token: ReturnToken,
         expr_opt: Option<Box<Expr>>,
     },
+    Break {
+        break_token: BreakToken,
+        label_opt: Option<LoopLabel>,
+    },
+    Continue {
+        continue_token: ContinueToken,
+        label_opt: Option<LoopLabel>,
+    },
     If(IfExpr),
     Match {
         match_token: MatchToken,
@@ -31,6 +39,7 @@ pub enum Expr {
         branches: Braces<Vec<MatchBranch>>,
     },
     While {
+        label_opt: Option<(LoopLabel, ColonToken)>,
         while_token: WhileToken,
         condition: Box<Expr>,
         block: Braces<CodeBlockContents>,
@@ -73,6 +82,15 @@ pub enum Expr {
         bang_token: BangToken,
         expr: Box<Expr>,
     },
+    Neg {
+        sub_token: SubToken,
+        expr: Box<Expr>,
+    },
+    Cast {
+        expr: Box<Expr>,
+        as_token: AsToken,
+        ty: Ty,
+    },
     Mul {
         lhs: Box<Expr>,
         star_token: StarToken,
@@ -193,6 +211,20 @@ impl Spanned for Expr {
                 };
                 Span::join(start, end)
             }
+            Expr::Break {
+                break_token,
+                label_opt,
+            } => match label_opt {
+                Some(label) => Span::join(break_token.span(), label.span()),
+                None => break_token.span(),
+            },
+            Expr::Continue {
+                continue_token,
+                label_opt,
+            } => match label_opt {
+                Some(label) => Span::join(continue_token.span(), label.span()),
+                None => continue_token.span(),
+            },
             Expr::If(if_expr) => if_expr.span(),
             Expr::Match {
                 match_token,
@@ -200,8 +232,17 @@ impl Spanned for Expr {
                 ..
             } => Span::join(match_token.span(), branches.span()),
             Expr::While {
-                while_token, block, ..
-            } => Span::join(while_token.span(), block.span()),
+                label_opt,
+                while_token,
+                block,
+                ..
+            } => {
+                let start = match label_opt {
+                    Some((label, _colon_token)) => label.span(),
+                    None => while_token.span(),
+                };
+                Span::join(start, block.span())
+            }
             Expr::FuncApp { func, args } => Span::join(func.span(), args.span()),
             Expr::Index { target, arg } => Span::join(target.span(), arg.span()),
             Expr::MethodCall { target, args, .. } => Span::join(target.span(), args.span()),
@@ -212,6 +253,8 @@ impl Spanned for Expr {
             Expr::Ref { ref_token, expr } => Span::join(ref_token.span(), expr.span()),
             Expr::Deref { deref_token, expr } => Span::join(deref_token.span(), expr.span()),
             Expr::Not { bang_token, expr } => Span::join(bang_token.span(), expr.span()),
+            Expr::Neg { sub_token, expr } => Span::join(sub_token.span(), expr.span()),
+            Expr::Cast { expr, ty, .. } => Span::join(expr.span(), ty.span()),
             Expr::Mul { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::Div { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
             Expr::Modulo { lhs, rhs, .. } => Span::join(lhs.span(), rhs.span()),
@@ -405,6 +448,7 @@ pub enum ExprArrayDescriptor {
 #[derive(Clone, Debug)]
 pub struct MatchBranch {
     pub pattern: Pattern,
+    pub guard_opt: Option<(IfToken, Box<Expr>)>,
     pub fat_right_arrow_token: FatRightArrowToken,
     pub kind: MatchBranchKind,
 }
@@ -428,6 +472,30 @@ pub enum MatchBranchKind {
     },
 }
 
+/// A loop label, such as the `'outer` in `'outer: while ...` or `break 'outer`.
+#[derive(Clone, Debug)]
+pub struct LoopLabel {
+    pub apostrophe_token: ApostropheToken,
+    pub name: Ident,
+}
+
+impl Spanned for LoopLabel {
+    fn span(&self) -> Span {
+        Span::join(self.apostrophe_token.span(), self.name.span())
+    }
+}
+
+impl Parse for LoopLabel {
+    fn parse(parser: &mut Parser) -> ParseResult<LoopLabel> {
+        let apostrophe_token = parser.parse()?;
+        let name = parser.parse()?;
+        Ok(LoopLabel {
+            apostrophe_token,
+            name,
+        })
+    }
+}
+
 impl Spanned for MatchBranchKind {
     fn span(&self) -> Span {
         match self {
@@ -873,14 +941,14 @@ fn parse_add(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
 }
 
 fn parse_mul(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
-    let mut expr = parse_unary_op(parser, ctx)?;
+    let mut expr = parse_cast(parser, ctx)?;
     if expr.is_control_flow() && ctx.at_start_of_statement {
         return Ok(expr);
     }
     loop {
         if let Some(star_token) = parser.take() {
             let lhs = Box::new(expr);
-            let rhs = Box::new(parse_unary_op(parser, ctx.not_statement())?);
+            let rhs = Box::new(parse_cast(parser, ctx.not_statement())?);
             expr = Expr::Mul {
                 lhs,
                 star_token,
@@ -890,7 +958,7 @@ fn parse_mul(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
         }
         if let Some(forward_slash_token) = parser.take() {
             let lhs = Box::new(expr);
-            let rhs = Box::new(parse_unary_op(parser, ctx.not_statement())?);
+            let rhs = Box::new(parse_cast(parser, ctx.not_statement())?);
             expr = Expr::Div {
                 lhs,
                 forward_slash_token,
@@ -900,7 +968,7 @@ fn parse_mul(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
         }
         if let Some(percent_token) = parser.take() {
             let lhs = Box::new(expr);
-            let rhs = Box::new(parse_unary_op(parser, ctx.not_statement())?);
+            let rhs = Box::new(parse_cast(parser, ctx.not_statement())?);
             expr = Expr::Modulo {
                 lhs,
                 percent_token,
@@ -912,6 +980,22 @@ fn parse_mul(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     }
 }
 
+fn parse_cast(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
+    let mut expr = parse_unary_op(parser, ctx)?;
+    if expr.is_control_flow() && ctx.at_start_of_statement {
+        return Ok(expr);
+    }
+    while let Some(as_token) = parser.take() {
+        let ty = parser.parse()?;
+        expr = Expr::Cast {
+            expr: Box::new(expr),
+            as_token,
+            ty,
+        };
+    }
+    Ok(expr)
+}
+
 fn parse_unary_op(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     if let Some(ref_token) = parser.take() {
         let expr = Box::new(parse_unary_op(parser, ctx.not_statement())?);
@@ -925,6 +1009,10 @@ fn parse_unary_op(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
         let expr = Box::new(parse_unary_op(parser, ctx.not_statement())?);
         return Ok(Expr::Not { bang_token, expr });
     }
+    if let Some(sub_token) = parser.take() {
+        let expr = Box::new(parse_unary_op(parser, ctx.not_statement())?);
+        return Ok(Expr::Neg { sub_token, expr });
+    }
     parse_projection(parser, ctx)
 }
 
@@ -1023,6 +1111,13 @@ fn parse_func_app(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     }
 }
 
+fn parse_loop_label_opt(parser: &mut Parser) -> ParseResult<Option<LoopLabel>> {
+    if parser.peek::<ApostropheToken>().is_some() {
+        return Ok(Some(parser.parse()?));
+    }
+    Ok(None)
+}
+
 fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     if let Some(code_block_inner) = Braces::try_parse(parser)? {
         return Ok(Expr::Block(code_block_inner));
@@ -1094,6 +1189,20 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             expr_opt: Some(expr),
         });
     }
+    if let Some(break_token) = parser.take() {
+        let label_opt = parse_loop_label_opt(parser)?;
+        return Ok(Expr::Break {
+            break_token,
+            label_opt,
+        });
+    }
+    if let Some(continue_token) = parser.take() {
+        let label_opt = parse_loop_label_opt(parser)?;
+        return Ok(Expr::Continue {
+            continue_token,
+            label_opt,
+        });
+    }
     if parser.peek::<IfToken>().is_some() {
         let if_expr = parser.parse()?;
         return Ok(Expr::If(if_expr));
@@ -1107,10 +1216,23 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             branches,
         });
     }
+    if let Some(label) = parse_loop_label_opt(parser)? {
+        let colon_token = parser.parse()?;
+        let while_token = parser.parse()?;
+        let condition = Box::new(parse_condition(parser)?);
+        let block = parser.parse()?;
+        return Ok(Expr::While {
+            label_opt: Some((label, colon_token)),
+            while_token,
+            condition,
+            block,
+        });
+    }
     if let Some(while_token) = parser.take() {
         let condition = Box::new(parse_condition(parser)?);
         let block = parser.parse()?;
         return Ok(Expr::While {
+            label_opt: None,
             while_token,
             condition,
             block,
@@ -1218,10 +1340,15 @@ impl ParseToEnd for ExprArrayDescriptor {
 impl Parse for MatchBranch {
     fn parse(parser: &mut Parser) -> ParseResult<MatchBranch> {
         let pattern = parser.parse()?;
+        let guard_opt = match parser.take() {
+            Some(if_token) => Some((if_token, parser.parse()?)),
+            None => None,
+        };
         let fat_right_arrow_token = parser.parse()?;
         let kind = parser.parse()?;
         Ok(MatchBranch {
             pattern,
+            guard_opt,
             fat_right_arrow_token,
             kind,
         })
@@ -1,8 +1,49 @@
 use crate::priv_prelude::*;
 
+#[derive(Clone, Debug)]
+pub enum GenericParam {
+    Type(Ident),
+    Const {
+        const_token: ConstToken,
+        name: Ident,
+        colon_token: ColonToken,
+        ty: Ty,
+    },
+}
+
+impl Spanned for GenericParam {
+    fn span(&self) -> Span {
+        match self {
+            GenericParam::Type(name) => name.span(),
+            GenericParam::Const {
+                const_token, ty, ..
+            } => Span::join(const_token.span(), ty.span()),
+        }
+    }
+}
+
+impl Parse for GenericParam {
+    fn parse(parser: &mut Parser) -> ParseResult<GenericParam> {
+        match parser.take() {
+            Some(const_token) => {
+                let name = parser.parse()?;
+                let colon_token = parser.parse()?;
+                let ty = parser.parse()?;
+                Ok(GenericParam::Const {
+                    const_token,
+                    name,
+                    colon_token,
+                    ty,
+                })
+            }
+            None => Ok(GenericParam::Type(parser.parse()?)),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct GenericParams {
-    pub parameters: AngleBrackets<Punctuated<Ident, CommaToken>>,
+    pub parameters: AngleBrackets<Punctuated<GenericParam, CommaToken>>,
 }
 
 impl Parse for GenericParams {
@@ -13,12 +54,12 @@ impl Parse for GenericParams {
             if let Some(close_angle_bracket_token) = parser.take() {
                 break (None, close_angle_bracket_token);
             };
-            let ident = parser.parse()?;
+            let param = parser.parse()?;
             if let Some(close_angle_bracket_token) = parser.take() {
-                break (Some(Box::new(ident)), close_angle_bracket_token);
+                break (Some(Box::new(param)), close_angle_bracket_token);
             };
             let comma_token = parser.parse()?;
-            value_separator_pairs.push((ident, comma_token));
+            value_separator_pairs.push((param, comma_token));
         };
         let punctuated = Punctuated {
             value_separator_pairs,
@@ -33,9 +74,33 @@ impl Parse for GenericParams {
     }
 }
 
+#[derive(Clone, Debug)]
+pub enum GenericArg {
+    Type(Ty),
+    Const(Literal),
+}
+
+impl Spanned for GenericArg {
+    fn span(&self) -> Span {
+        match self {
+            GenericArg::Type(ty) => ty.span(),
+            GenericArg::Const(literal) => literal.span(),
+        }
+    }
+}
+
+impl Parse for GenericArg {
+    fn parse(parser: &mut Parser) -> ParseResult<GenericArg> {
+        match parser.take() {
+            Some(literal) => Ok(GenericArg::Const(literal)),
+            None => Ok(GenericArg::Type(parser.parse()?)),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct GenericArgs {
-    pub parameters: AngleBrackets<Punctuated<Ty, CommaToken>>,
+    pub parameters: AngleBrackets<Punctuated<GenericArg, CommaToken>>,
 }
 
 impl Spanned for GenericArgs {
@@ -52,12 +117,12 @@ impl Parse for GenericArgs {
             if let Some(close_angle_bracket_token) = parser.take() {
                 break (None, close_angle_bracket_token);
             };
-            let ty = parser.parse()?;
+            let arg = parser.parse()?;
             if let Some(close_angle_bracket_token) = parser.take() {
-                break (Some(Box::new(ty)), close_angle_bracket_token);
+                break (Some(Box::new(arg)), close_angle_bracket_token);
             };
             let comma_token = parser.parse()?;
-            value_separator_pairs.push((ty, comma_token));
+            value_separator_pairs.push((arg, comma_token));
         };
         let punctuated = Punctuated {
             value_separator_pairs,
@@ -2,6 +2,7 @@
 pub enum Intrinsic {
     GetStorageKey,
     IsReferenceType,
+    Revert,
     SizeOf,
     SizeOfVal,
 }
@@ -12,6 +13,7 @@ impl Intrinsic {
         Some(match raw {
             "__get_storage_key" => GetStorageKey,
             "__is_reference_type" => IsReferenceType,
+            "__revert" => Revert,
             "__size_of" => SizeOf,
             "__size_of_val" => SizeOfVal,
             _ => return None,
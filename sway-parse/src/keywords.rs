@@ -37,38 +37,52 @@ macro_rules! define_keyword (
     };
 );
 
-define_keyword!(ScriptToken, "script");
-define_keyword!(ContractToken, "contract");
-define_keyword!(PredicateToken, "predicate");
-define_keyword!(LibraryToken, "library");
-define_keyword!(DepToken, "dep");
-define_keyword!(PubToken, "pub");
-define_keyword!(UseToken, "use");
-define_keyword!(AsToken, "as");
-define_keyword!(StructToken, "struct");
-define_keyword!(EnumToken, "enum");
-define_keyword!(SelfToken, "self");
-define_keyword!(FnToken, "fn");
-define_keyword!(TraitToken, "trait");
-define_keyword!(ImplToken, "impl");
-define_keyword!(ForToken, "for");
-define_keyword!(AbiToken, "abi");
-define_keyword!(ConstToken, "const");
-define_keyword!(StorageToken, "storage");
-define_keyword!(StrToken, "str");
-define_keyword!(AsmToken, "asm");
-define_keyword!(ReturnToken, "return");
-define_keyword!(IfToken, "if");
-define_keyword!(ElseToken, "else");
-define_keyword!(MatchToken, "match");
-define_keyword!(MutToken, "mut");
-define_keyword!(LetToken, "let");
-define_keyword!(WhileToken, "while");
-define_keyword!(WhereToken, "where");
-define_keyword!(RefToken, "ref");
-define_keyword!(DerefToken, "deref");
-define_keyword!(TrueToken, "true");
-define_keyword!(FalseToken, "false");
+// Defines a token type for each keyword, along with `RESERVED_KEYWORDS` listing all of them.
+macro_rules! define_keywords (
+    ($($ty_name:ident: $keyword:literal,)*) => {
+        $(define_keyword!($ty_name, $keyword);)*
+
+        /// All of the keywords defined in this module. These can't be used as identifiers.
+        pub const RESERVED_KEYWORDS: &[&str] = &[$($keyword),*];
+    };
+);
+
+define_keywords! {
+    ScriptToken: "script",
+    ContractToken: "contract",
+    PredicateToken: "predicate",
+    LibraryToken: "library",
+    DepToken: "dep",
+    PubToken: "pub",
+    UseToken: "use",
+    AsToken: "as",
+    StructToken: "struct",
+    EnumToken: "enum",
+    SelfToken: "self",
+    FnToken: "fn",
+    TraitToken: "trait",
+    ImplToken: "impl",
+    ForToken: "for",
+    AbiToken: "abi",
+    ConstToken: "const",
+    StorageToken: "storage",
+    StrToken: "str",
+    AsmToken: "asm",
+    ReturnToken: "return",
+    IfToken: "if",
+    ElseToken: "else",
+    MatchToken: "match",
+    MutToken: "mut",
+    LetToken: "let",
+    WhileToken: "while",
+    BreakToken: "break",
+    ContinueToken: "continue",
+    WhereToken: "where",
+    RefToken: "ref",
+    DerefToken: "deref",
+    TrueToken: "true",
+    FalseToken: "false",
+}
 
 macro_rules! define_token (
     ($ty_name:ident, $description:literal, [$($punct_kinds:ident),*], [$($not_followed_by:ident),*]) => {
@@ -207,3 +221,4 @@ define_token!(
 define_token!(DoublePipeToken, "`||`", [Pipe, Pipe], [Pipe]);
 define_token!(UnderscoreToken, "`_`", [Underscore], [Underscore]);
 define_token!(HashToken, "`#`", [Sharp], []);
+define_token!(ApostropheToken, "`'`", [Apostrophe], []);
@@ -31,9 +31,9 @@ pub use crate::{
         asm::{AsmBlock, AsmRegisterDeclaration},
         op_code::Instruction,
         AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
+        ExprTupleDescriptor, IfCondition, IfExpr, LoopLabel, MatchBranch, MatchBranchKind,
     },
-    generics::{GenericArgs, GenericParams},
+    generics::{GenericArg, GenericArgs, GenericParam, GenericParams},
     intrinsics::*,
     item::{
         item_abi::ItemAbi,
@@ -10,7 +10,7 @@ pub use {
             op_code::{parse_instruction, Instruction},
             CodeBlockContents, Expr,
         },
-        generics::{GenericArgs, GenericParams},
+        generics::{GenericArg, GenericArgs, GenericParam, GenericParams},
         intrinsics::*,
         item::{
             item_abi::ItemAbi,
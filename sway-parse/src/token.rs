@@ -27,6 +27,7 @@ pub enum PunctKind {
     Tilde,
     Underscore,
     Sharp,
+    Apostrophe,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
@@ -64,6 +65,7 @@ impl PunctKind {
             PunctKind::Tilde => '~',
             PunctKind::Underscore => '_',
             PunctKind::Sharp => '#',
+            PunctKind::Apostrophe => '\'',
         }
     }
 }
@@ -510,6 +512,25 @@ pub fn lex(
             continue;
         }
         if character == '\'' {
+            // A quote followed by an identifier which isn't closed by another quote, such as the
+            // `'outer` in `'outer: while ...`, starts a loop label rather than a char literal.
+            let mut label_chars = src[index + character.len_utf8()..].chars();
+            let is_label = match (label_chars.next(), label_chars.next()) {
+                (Some(first), second) => {
+                    (first.is_xid_start() || first == '_') && second != Some('\'')
+                }
+                (None, _) => false,
+            };
+            if is_label {
+                let span = span_until(src, index, &mut char_indices, &path);
+                let punct = Punct {
+                    kind: PunctKind::Apostrophe,
+                    spacing: Spacing::Joint,
+                    span,
+                };
+                token_trees.push(TokenTree::Punct(punct));
+                continue;
+            }
             let next_character = match char_indices.next() {
                 Some((_, next_character)) => next_character,
                 None => {
@@ -210,6 +210,11 @@ pub fn run(locked: bool, filter_regex: Option<regex::Regex>) {
             "should_pass/language/abort_control_flow_good",
             ProgramState::Revert(42),
         ),
+        (
+            "should_pass/language/revert_intrinsic",
+            ProgramState::Revert(42),
+        ),
+        ("should_pass/language/as_cast", ProgramState::Return(42)),
         (
             "should_pass/language/array_generics",
             ProgramState::Return(1), // true
@@ -332,6 +337,11 @@ pub fn run(locked: bool, filter_regex: Option<regex::Regex>) {
             "should_pass/language/nested_while_and_if",
             ProgramState::Return(1),
         ),
+        (
+            "should_pass/language/break_and_continue",
+            ProgramState::Return(1),
+        ),
+        ("should_pass/language/match_guards", ProgramState::Return(1)),
         (
             "should_pass/language/is_reference_type",
             ProgramState::Return(1),
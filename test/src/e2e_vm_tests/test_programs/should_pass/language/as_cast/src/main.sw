@@ -0,0 +1,17 @@
+script;
+
+fn narrow(x: u64) -> u8 {
+    x as u8
+}
+
+fn main() -> u64 {
+    // 298 doesn't fit in a `u8` and wraps around to 42.
+    let truncated = narrow(298);
+    let widened = truncated as u64;
+
+    // 65536 doesn't fit in a `u16` and wraps around to 0.
+    let x: u32 = 65536;
+    let y = x as u16;
+
+    widened + y as u64
+}
@@ -0,0 +1,90 @@
+script;
+
+use core::*;
+use std::assert::assert;
+
+// Sums the odd numbers below `n`, stopping early once the sum exceeds `limit`.
+fn sum_odd(n: u64, limit: u64) -> u64 {
+    let mut index = 0;
+    let mut sum = 0;
+    while true {
+        index = index + 1;
+        if index >= n {
+            break;
+        }
+        if index % 2 == 0 {
+            continue;
+        }
+        sum = sum + index;
+        if sum > limit {
+            break;
+        }
+    }
+    sum
+}
+
+// Finds the smallest factor of `target` above one, leaving both loops as soon as it's found.
+fn smallest_factor(target: u64) -> u64 {
+    let mut found = 0;
+    let mut a = 2;
+    'outer: while a <= target {
+        let mut b = 1;
+        while b <= target {
+            if a * b == target {
+                found = a;
+                break 'outer;
+            }
+            b = b + 1;
+        }
+        a = a + 1;
+    }
+    found
+}
+
+// Counts the rows of an `n` by `n` multiplication table with no entry above `limit`, moving on to
+// the next row as soon as one is found.
+fn count_small_rows(n: u64, limit: u64) -> u64 {
+    let mut rows = 0;
+    let mut row = 0;
+    'rows: while row < n {
+        row = row + 1;
+        let mut column = 0;
+        while column < n {
+            column = column + 1;
+            if row * column > limit {
+                continue 'rows;
+            }
+        }
+        rows = rows + 1;
+    }
+    rows
+}
+
+fn main() -> bool {
+    assert(smallest_factor(15) == 3);
+    assert(smallest_factor(7) == 7);
+    assert(count_small_rows(4, 6) == 1);
+    assert(count_small_rows(3, 9) == 3);
+
+    assert(sum_odd(10, 100) == 25);
+    assert(sum_odd(10, 5) == 9);
+
+    let mut outer = 0;
+    let mut inner_total = 0;
+    while outer < 3 {
+        outer = outer + 1;
+        let mut inner = 0;
+        while true {
+            inner = inner + 1;
+            if inner > outer {
+                break;
+            }
+            inner_total = inner_total + 1;
+        }
+        if outer == 2 {
+            continue;
+        }
+    }
+    assert(inner_total == 6);
+    true
+}
@@ -0,0 +1,34 @@
+script;
+
+use core::*;
+use std::assert::assert;
+
+enum Shape {
+    Circle: u64,
+    Rectangle: (u64, u64),
+}
+
+fn classify(shape: Shape) -> u64 {
+    match shape {
+        Shape::Circle(radius) if radius == 0 => 0,
+        Shape::Circle(radius) => 1,
+        Shape::Rectangle((width, height)) if width == height => 2,
+        Shape::Rectangle(_) => 3,
+    }
+}
+
+fn main() -> bool {
+    assert(classify(Shape::Circle(0)) == 0);
+    assert(classify(Shape::Circle(5)) == 1);
+    assert(classify(Shape::Rectangle((4, 4))) == 2);
+    assert(classify(Shape::Rectangle((4, 2))) == 3);
+
+    let x = 7;
+    let y = match x {
+        n if n > 10 => n - 10,
+        n if n > 5 => n - 5,
+        _ => 0,
+    };
+    assert(y == 2);
+    true
+}
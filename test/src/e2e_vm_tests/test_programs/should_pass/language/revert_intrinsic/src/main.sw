@@ -0,0 +1,25 @@
+script;
+
+struct Pair {
+    a: u64,
+    b: u64,
+}
+
+// Every path reverts, so neither function needs to produce a value.
+fn fail(code: u64) -> u64 {
+    __revert(code)
+}
+
+fn fail_with_pair(code: u64) -> Pair {
+    __revert(code)
+}
+
+fn main() -> u64 {
+    let pair = if false {
+        fail_with_pair(1)
+    } else {
+        Pair { a: 1, b: 2 }
+    };
+    let x = fail(40 + pair.b);
+    x
+}
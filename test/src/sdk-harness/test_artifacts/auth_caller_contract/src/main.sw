@@ -6,12 +6,14 @@ use std::chain::auth::*;
 use std::result::*;
 
 abi AuthCaller {
+    #[storage(read, write)]
     fn call_auth_contract(auth_id: ContractId, expected_id: ContractId) -> bool;
 }
 
 impl AuthCaller for Contract {
     // TODO: improve this to return the ContractId itself.
     // This is a workaround for the MissingData("cannot parse custom type with no components") error
+    #[storage(read, write)]
     fn call_auth_contract(auth_id: ContractId, expected_id: ContractId) -> bool {
         let auth_contract = abi(AuthTesting, ~ContractId::into(auth_id));
         auth_contract.returns_msg_sender(expected_id)
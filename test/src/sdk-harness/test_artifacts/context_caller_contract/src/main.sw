@@ -6,17 +6,25 @@ use std::token::mint;
 use std::contract_id::ContractId;
 
 abi ContextCaller {
+    #[storage(read, write)]
     fn call_get_this_balance_with_coins(send_amount: u64, context_id: ContractId) -> u64;
+    #[storage(read, write)]
     fn call_get_balance_of_contract_with_coins(send_amount: u64, context_id: ContractId) -> u64;
+    #[storage(read, write)]
     fn call_get_amount_with_coins(send_amount: u64, context_id: ContractId) -> u64;
+    #[storage(read, write)]
     fn call_get_asset_id_with_coins(send_amount: u64, context_id: ContractId) -> ContractId;
+    #[storage(read, write)]
     fn call_get_gas_with_coins(send_amount: u64, context_id: ContractId) -> u64;
+    #[storage(read, write)]
     fn call_get_global_gas_with_coins(send_amount: u64, context_id: ContractId) -> u64;
+    #[storage(read, write)]
     fn call_receive_coins(send_amount: u64, target: ContractId);
     fn mint_coins(mint_amount: u64);
 }
 
 impl ContextCaller for Contract {
+    #[storage(read, write)]
     fn call_get_this_balance_with_coins(send_amount: u64, target: ContractId) -> u64 {
         let id = target.value;
         let context_contract = abi(ContextTesting, id);
@@ -27,6 +35,7 @@ impl ContextCaller for Contract {
         (contract_id())
     }
 
+    #[storage(read, write)]
     fn call_get_balance_of_contract_with_coins(send_amount: u64, target: ContractId) -> u64 {
         let id = target.value;
         let context_contract = abi(ContextTesting, id);
@@ -37,6 +46,7 @@ impl ContextCaller for Contract {
         (contract_id(), target)
     }
 
+    #[storage(read, write)]
     fn call_get_amount_with_coins(send_amount: u64, target: ContractId) -> u64 {
         let id = target.value;
         let context_contract = abi(ContextTesting, id);
@@ -47,6 +57,7 @@ impl ContextCaller for Contract {
         ()
     }
 
+    #[storage(read, write)]
     fn call_get_asset_id_with_coins(send_amount: u64, target: ContractId) -> ContractId {
         let id = target.value;
         let context_contract = abi(ContextTesting, id);
@@ -57,6 +68,7 @@ impl ContextCaller for Contract {
         ()
     }
 
+    #[storage(read, write)]
     fn call_get_gas_with_coins(send_amount: u64, target: ContractId) -> u64 {
         let id = target.value;
         let context_contract = abi(ContextTesting, id);
@@ -67,6 +79,7 @@ impl ContextCaller for Contract {
         ()
     }
 
+    #[storage(read, write)]
     fn call_get_global_gas_with_coins(send_amount: u64, target: ContractId) -> u64 {
         let id = target.value;
         let context_contract = abi(ContextTesting, id);
@@ -77,6 +90,7 @@ impl ContextCaller for Contract {
         ()
     }
 
+    #[storage(read, write)]
     fn call_receive_coins(send_amount: u64, target: ContractId) {
         let id = target.value;
         let context_contract = abi(ContextTesting, id);
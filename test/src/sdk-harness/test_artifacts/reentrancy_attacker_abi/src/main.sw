@@ -3,12 +3,19 @@ library reentrancy_attacker_abi;
 use std::contract_id::ContractId;
 
 abi Attacker {
+    #[storage(read, write)]
     fn launch_attack(target: ContractId) -> bool;
+    #[storage(read, write)]
     fn launch_thwarted_attack_1(target: ContractId);
+    #[storage(read, write)]
     fn launch_thwarted_attack_2(target: ContractId);
+    #[storage(read, write)]
     fn innocent_call(target: ContractId) -> bool;
+    #[storage(read, write)]
     fn evil_callback_1() -> bool;
+    #[storage(read, write)]
     fn evil_callback_2() -> bool;
+    #[storage(read, write)]
     fn evil_callback_3() -> bool;
     fn innocent_callback();
 }
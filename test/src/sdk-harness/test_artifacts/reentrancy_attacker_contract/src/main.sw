@@ -19,30 +19,35 @@ fn get_msg_sender_id_or_panic(result: Result<Identity, AuthError>) -> ContractId
 }
 
 impl Attacker for Contract {
+    #[storage(read, write)]
     fn launch_attack(target: ContractId) -> bool {
         let id = target.value;
         let target = abi(Target, id);
         target.reentrancy_detected()
     }
 
+    #[storage(read, write)]
     fn launch_thwarted_attack_1(target: ContractId) {
         let id = target.value;
         let target = abi(Target, id);
         target.intra_contract_call();
     }
 
+    #[storage(read, write)]
     fn launch_thwarted_attack_2(target: ContractId) {
         let id = target.value;
         let target = abi(Target, id);
         target.cross_function_reentrance_denied();
     }
 
+    #[storage(read, write)]
     fn innocent_call(target: ContractId) -> bool {
         let id = target.value;
         let target = abi(Target, id);
         target.guarded_function_is_callable()
     }
 
+    #[storage(read, write)]
     fn evil_callback_1() -> bool {
         let result: Result<Identity, AuthError> = msg_sender();
         let id = get_msg_sender_id_or_panic(result);
@@ -51,6 +56,7 @@ impl Attacker for Contract {
         attacker.launch_attack(id)
     }
 
+    #[storage(read, write)]
     fn evil_callback_2() -> bool {
         let result: Result<Identity, AuthError> = msg_sender();
         let id = get_msg_sender_id_or_panic(result);
@@ -60,6 +66,7 @@ impl Attacker for Contract {
         true
     }
 
+    #[storage(read, write)]
     fn evil_callback_3() -> bool {
         let result: Result<Identity, AuthError> = msg_sender();
         let id = get_msg_sender_id_or_panic(result);
@@ -1,9 +1,13 @@
 library reentrancy_target_abi;
 
 abi Target {
+    #[storage(read, write)]
     fn reentrancy_detected() -> bool;
+    #[storage(read, write)]
     fn reentrance_denied();
+    #[storage(read, write)]
     fn cross_function_reentrance_denied();
+    #[storage(read, write)]
     fn intra_contract_call();
     fn guarded_function_is_callable() -> bool;
 }
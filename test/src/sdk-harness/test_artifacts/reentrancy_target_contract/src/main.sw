@@ -19,6 +19,7 @@ fn get_msg_sender_id_or_panic(result: Result<Identity, AuthError>) -> ContractId
 }
 
 impl Target for Contract {
+    #[storage(read, write)]
     fn reentrancy_detected() -> bool {
         if is_reentrant() {
             true
@@ -34,6 +35,7 @@ impl Target for Contract {
         }
     }
 
+    #[storage(read, write)]
     fn reentrance_denied() {
         // panic if reentrancy detected
         reentrancy_guard();
@@ -47,6 +49,7 @@ impl Target for Contract {
         let return_value = caller.evil_callback_2();
     }
 
+    #[storage(read, write)]
     fn cross_function_reentrance_denied() {
         // panic if reentrancy detected
         reentrancy_guard();
@@ -60,6 +63,7 @@ impl Target for Contract {
         let return_value = caller.evil_callback_3();
     }
 
+    #[storage(read, write)]
     fn intra_contract_call() {
         let this = abi(Target, ~ContractId::into(contract_id()));
         this.cross_function_reentrance_denied();